@@ -3,7 +3,7 @@
 from __future__ import annotations
 
 from enum import Enum
-from typing import Dict, Optional, Union
+from typing import Dict, List, Optional, Union
 
 from pydantic import BaseModel, Field, ConfigDict
 
@@ -20,17 +20,79 @@ class EventKind(str, Enum):
     RESPAWN = "respawn"
     PHASE_CHANGE = "phaseChange"
     HEARTBEAT = "heartbeat"
+    ITEM_MOVED = "itemMoved"
+    ITEM_STACK_CHANGED = "itemStackChanged"
+    ECONOMY_SNAPSHOT = "economySnapshot"
+    WIN_PROBABILITY = "winProbability"
+    LANE_ASSIGNMENT = "laneAssignment"
+    BOUNTY_UPDATED = "bountyUpdated"
+    SOUL_POINT = "soulPoint"
+    SOUL_TAKEN = "soulTaken"
+    ELDER_BUFF = "elderBuff"
+    TEAM_WIPED = "teamWiped"
+    FARM_RATE = "farmRate"
+    TEAM_INFO = "teamInfo"
+    GAME_ENDED = "gameEnded"
+    SCORE_ADJUSTED = "scoreAdjusted"
+    DIAGNOSTIC = "diagnostic"
+    SURRENDER_VOTE_STARTED = "surrenderVoteStarted"
+    SURRENDER_VOTE_PASSED = "surrenderVotePassed"
+    SURRENDER_VOTE_FAILED = "surrenderVoteFailed"
+    REMAKE = "remake"
+    LOBBY_CHAT = "lobbyChat"
+    RUNE_PAGE_LOCKED = "runePageLocked"
+    SKIN_SELECTED = "skinSelected"
+    QUEUE_ENTERED = "queueEntered"
+    QUEUE_TIME_UPDATE = "queueTimeUpdate"
+    MATCH_FOUND = "matchFound"
+    FRIEND_PRESENCE = "friendPresence"
+    GAMEFLOW_SESSION = "gameflowSession"
+    PICK_TIMER_WARNING = "pickTimerWarning"
+    CHAMPION_KILL = "championKill"
+    MULTIKILL = "multikill"
+    FIRST_BLOOD = "firstBlood"
+    SHUTDOWN = "shutdown"
+    OBJECTIVE_KILL = "objectiveKill"
+    STRUCTURE_DESTROYED = "structureDestroyed"
+    CS_DELTA = "csDelta"
+    VISION_SCORE_DELTA = "visionScoreDelta"
+    ACTIVE_PLAYER_RUNES = "activePlayerRunes"
+    ACTIVE_PLAYER_RESOURCE = "activePlayerResource"
+    GAME_METADATA = "gameMetadata"
+    RESOURCE_CHANGED = "resourceChanged"
+
+
+class EventSource(str, Enum):
+    DAEMON = "daemon"
+    LIVE_CLIENT = "liveClient"
+    LCU = "lcu"
+    SYNTHETIC = "synthetic"
+    REPLAY = "replay"
+
+
+class PlayerIdentity(BaseModel):
+    display_name: str = Field(..., alias="displayName")
+    team_tag: Optional[str] = Field(default=None, alias="teamTag")
+    portrait_url: Optional[str] = Field(default=None, alias="portraitUrl")
+    socials: Dict[str, str] = Field(default_factory=dict)
 
 
 class PlayerRef(BaseModel):
     summoner_name: str = Field(..., alias="summonerName")
     team: str
     slot: int
+    lane: Optional[str] = None
+    identity: Optional[PlayerIdentity] = None
+    active_player: bool = Field(default=False, alias="activePlayer")
+    riot_id: Optional[str] = Field(None, alias="riotId")
+    champion_name: Optional[str] = Field(None, alias="championName")
+    skin_id: Optional[int] = Field(None, alias="skinId")
 
 
 class PlayerEvent(BaseModel):
     payload_kind: str = Field("player", alias="payloadKind")
     player: PlayerRef
+    bounty: Optional[int] = None
 
 
 class ItemEvent(BaseModel):
@@ -38,6 +100,10 @@ class ItemEvent(BaseModel):
     player: PlayerRef
     item_id: int = Field(..., alias="itemId")
     item_name: Optional[str] = Field(default=None, alias="itemName")
+    slot: Optional[int] = None
+    count: Optional[int] = None
+    previous_slot: Optional[int] = Field(default=None, alias="previousSlot")
+    price: Optional[int] = None
 
 
 class LevelEvent(BaseModel):
@@ -60,6 +126,239 @@ class GoldEvent(BaseModel):
     total: int
 
 
+class ScoreEvent(BaseModel):
+    payload_kind: str = Field("playerScore", alias="payloadKind")
+    player: PlayerRef
+    delta: float
+    total: float
+
+
+class EconomyEvent(BaseModel):
+    payload_kind: str = Field("playerEconomy", alias="payloadKind")
+    player: PlayerRef
+    earned: int
+    spent: int
+    current: int
+
+
+class WinProbabilityEvent(BaseModel):
+    payload_kind: str = Field("winProbability", alias="payloadKind")
+    order: float
+    chaos: float
+
+
+class LaneAssignmentEvent(BaseModel):
+    payload_kind: str = Field("laneAssignment", alias="payloadKind")
+    players: List[PlayerRef]
+
+
+class BountyEvent(BaseModel):
+    payload_kind: str = Field("playerBounty", alias="payloadKind")
+    player: PlayerRef
+    bounty: int
+    kill_streak: int = Field(..., alias="killStreak")
+    death_streak: int = Field(..., alias="deathStreak")
+
+
+class DragonEvent(BaseModel):
+    payload_kind: str = Field("dragon", alias="payloadKind")
+    team: str
+    dragon_type: str = Field(..., alias="dragonType")
+    soul_points: int = Field(..., alias="soulPoints")
+    soul_type: Optional[str] = Field(default=None, alias="soulType")
+    expires_at: Optional[int] = Field(default=None, alias="expiresAt")
+
+
+class ObjectiveKillEvent(BaseModel):
+    payload_kind: str = Field("objectiveKill", alias="payloadKind")
+    objective: str
+    dragon_type: Optional[str] = Field(default=None, alias="dragonType")
+    stolen: bool = False
+    steal_suspected: bool = Field(False, alias="stealSuspected")
+    team: Optional[str] = None
+    killer: Optional[PlayerRef] = None
+
+
+class StructureEvent(BaseModel):
+    payload_kind: str = Field("structure", alias="payloadKind")
+    structure: str
+    id: str
+    team: str
+    lane: Optional[str] = None
+    tier: Optional[str] = None
+    killer: Optional[PlayerRef] = None
+
+
+class TeamWipeEvent(BaseModel):
+    payload_kind: str = Field("teamWipe", alias="payloadKind")
+    team: str
+    duration_estimate_ms: int = Field(..., alias="durationEstimateMs")
+
+
+class FarmEvent(BaseModel):
+    payload_kind: str = Field("playerFarm", alias="payloadKind")
+    player: PlayerRef
+    creep_score: int = Field(..., alias="creepScore")
+    cs_per_minute: float = Field(..., alias="csPerMinute")
+    cs_last_minute: int = Field(..., alias="csLastMinute")
+    jungle_efficiency: Optional[float] = Field(default=None, alias="jungleEfficiency")
+
+
+class TeamMetadata(BaseModel):
+    name: str
+    tag: Optional[str] = None
+    color: Optional[str] = None
+    logo_url: Optional[str] = Field(default=None, alias="logoUrl")
+
+
+class SeriesInfo(BaseModel):
+    best_of: int = Field(..., alias="bestOf")
+    game_number: int = Field(..., alias="gameNumber")
+    order_wins: int = Field(..., alias="orderWins")
+    chaos_wins: int = Field(..., alias="chaosWins")
+    sides_swapped: bool = Field(False, alias="sidesSwapped")
+
+
+class TeamInfoEvent(BaseModel):
+    payload_kind: str = Field("teamInfo", alias="payloadKind")
+    order: Optional[TeamMetadata] = None
+    chaos: Optional[TeamMetadata] = None
+    series: Optional[SeriesInfo] = None
+
+
+class GameResultEvent(BaseModel):
+    payload_kind: str = Field("gameResult", alias="payloadKind")
+    winner: Optional[str] = None
+    series: Optional[SeriesInfo] = None
+
+
+class ScoreAdjustmentEvent(BaseModel):
+    payload_kind: str = Field("scoreAdjustment", alias="payloadKind")
+    team: str
+    kills: int
+
+
+class SurrenderVoteEvent(BaseModel):
+    payload_kind: str = Field("surrenderVote", alias="payloadKind")
+    team: Optional[str] = None
+    remake: bool = False
+    votes_for: int = Field(0, alias="votesFor")
+    votes_against: int = Field(0, alias="votesAgainst")
+
+
+class LobbyChatEvent(BaseModel):
+    payload_kind: str = Field("lobbyChat", alias="payloadKind")
+    channel: str
+    sender: str
+    body: str
+
+
+class ActiveRunesEvent(BaseModel):
+    payload_kind: str = Field("activeRunes", alias="payloadKind")
+    player: PlayerRef
+    primary_style_id: int = Field(..., alias="primaryStyleId")
+    sub_style_id: int = Field(..., alias="subStyleId")
+    perk_ids: List[int] = Field(default_factory=list, alias="perkIds")
+
+
+class ResourceEvent(BaseModel):
+    payload_kind: str = Field("resource", alias="payloadKind")
+    player: PlayerRef
+    resource: str
+
+
+class ResourceChangedEvent(BaseModel):
+    payload_kind: str = Field("resourceChanged", alias="payloadKind")
+    player: PlayerRef
+    health: float
+    max_health: float = Field(..., alias="maxHealth")
+    resource: float
+    max_resource: float = Field(..., alias="maxResource")
+    low_health: bool = Field(False, alias="lowHealth")
+
+
+class GameMetadataEvent(BaseModel):
+    payload_kind: str = Field("gameMetadata", alias="payloadKind")
+    game_mode: str = Field(..., alias="gameMode")
+    map_number: int = Field(..., alias="mapNumber")
+    map_name: str = Field(..., alias="mapName")
+    map_terrain: Optional[str] = Field(None, alias="mapTerrain")
+    game_time_ms: int = Field(..., alias="gameTimeMs")
+
+
+class RunePageEvent(BaseModel):
+    payload_kind: str = Field("runePage", alias="payloadKind")
+    name: str = ""
+    primary_style_id: int = Field(..., alias="primaryStyleId")
+    sub_style_id: int = Field(..., alias="subStyleId")
+    perk_ids: List[int] = Field(default_factory=list, alias="perkIds")
+
+
+class SkinSelectedEvent(BaseModel):
+    payload_kind: str = Field("skinSelected", alias="payloadKind")
+    cell_id: int = Field(..., alias="cellId")
+    riot_id: Optional[str] = Field(None, alias="riotId")
+    champion_id: int = Field(..., alias="championId")
+    skin_id: int = Field(..., alias="skinId")
+
+
+class QueueEvent(BaseModel):
+    payload_kind: str = Field("queue", alias="payloadKind")
+    time_in_queue_secs: int = Field(0, alias="timeInQueueSecs")
+    estimated_queue_secs: int = Field(0, alias="estimatedQueueSecs")
+
+
+class FriendPresenceEvent(BaseModel):
+    payload_kind: str = Field("friendPresence", alias="payloadKind")
+    riot_id: str = Field(..., alias="riotId")
+    puuid: str
+    availability: str
+    game_status: Optional[str] = Field(None, alias="gameStatus")
+    queue_type: Optional[str] = Field(None, alias="queueType")
+    champion_id: Optional[int] = Field(None, alias="championId")
+
+
+class SessionMember(BaseModel):
+    puuid: str
+    riot_id: Optional[str] = Field(None, alias="riotId")
+    team: str
+    champion_id: Optional[int] = Field(None, alias="championId")
+
+
+class GameflowSessionEvent(BaseModel):
+    payload_kind: str = Field("gameflowSession", alias="payloadKind")
+    phase: str
+    game_id: Optional[int] = Field(None, alias="gameId")
+    queue_id: Optional[int] = Field(None, alias="queueId")
+    queue_type: Optional[str] = Field(None, alias="queueType")
+    map_id: Optional[int] = Field(None, alias="mapId")
+    map_name: Optional[str] = Field(None, alias="mapName")
+    members: List[SessionMember] = Field(default_factory=list)
+
+
+class PickTimerWarningEvent(BaseModel):
+    payload_kind: str = Field("pickTimerWarning", alias="payloadKind")
+    action: str
+    seconds_left: int = Field(..., alias="secondsLeft")
+    champion_id: Optional[int] = Field(None, alias="championId")
+
+
+class KillDetailEvent(BaseModel):
+    payload_kind: str = Field("killDetail", alias="payloadKind")
+    killer: Optional[PlayerRef] = None
+    victim: PlayerRef
+    assisters: List[PlayerRef] = Field(default_factory=list)
+    bounty: Optional[int] = None
+
+
+class SpecialKillEvent(BaseModel):
+    payload_kind: str = Field("specialKill", alias="payloadKind")
+    killer: PlayerRef
+    victim: Optional[PlayerRef] = None
+    kill_type: str = Field(..., alias="killType")
+    streak: int
+
+
 class PhaseEvent(BaseModel):
     payload_kind: str = Field("phase", alias="payloadKind")
     phase: str
@@ -68,6 +367,19 @@ class PhaseEvent(BaseModel):
 class HeartbeatEvent(BaseModel):
     payload_kind: str = Field("heartbeat", alias="payloadKind")
     seq: int
+    uptime_ms: Optional[int] = Field(None, alias="uptimeMs")
+    sources_connected: List[str] = Field(default_factory=list, alias="sourcesConnected")
+    sources_disabled: List[str] = Field(default_factory=list, alias="sourcesDisabled")
+    last_poll_latency_ms: Optional[int] = Field(None, alias="lastPollLatencyMs")
+    dropped_events: Optional[int] = Field(None, alias="droppedEvents")
+    over_budget_polls: Optional[int] = Field(None, alias="overBudgetPolls")
+    version: Optional[str] = None
+
+
+class DiagnosticEvent(BaseModel):
+    payload_kind: str = Field("diagnostic", alias="payloadKind")
+    source: str
+    message: str
 
 
 class CustomEvent(BaseModel):
@@ -81,15 +393,51 @@ EventPayload = Union[
     LevelEvent,
     SkillLevelEvent,
     GoldEvent,
+    ScoreEvent,
+    EconomyEvent,
+    WinProbabilityEvent,
+    LaneAssignmentEvent,
+    BountyEvent,
+    DragonEvent,
+    ObjectiveKillEvent,
+    StructureEvent,
+    TeamWipeEvent,
+    FarmEvent,
+    TeamInfoEvent,
+    GameResultEvent,
+    ScoreAdjustmentEvent,
+    SurrenderVoteEvent,
+    LobbyChatEvent,
+    RunePageEvent,
+    ActiveRunesEvent,
+    ResourceEvent,
+    ResourceChangedEvent,
+    GameMetadataEvent,
+    SkinSelectedEvent,
+    QueueEvent,
+    FriendPresenceEvent,
+    GameflowSessionEvent,
+    PickTimerWarningEvent,
+    KillDetailEvent,
+    SpecialKillEvent,
     PhaseEvent,
     HeartbeatEvent,
+    DiagnosticEvent,
     CustomEvent,
 ]
 
 
 class Event(BaseModel):
+    schema_version: int = Field(1, alias="schemaVersion")
+    id: Optional[str] = None
+    seq: int = 0
     kind: EventKind
     ts: int
     payload: EventPayload
+    source: EventSource = EventSource.DAEMON
+    game_id: Optional[str] = Field(None, alias="gameId")
+    manual: bool = False
+    stale: bool = False
+    signature: Optional[str] = None
 
     model_config = ConfigDict(populate_by_name=True)
@@ -0,0 +1,23 @@
+"""Checks the models against the taxonomy exported by the daemon.
+
+Regenerate it with `cargo run -p levents-model --bin event-taxonomy -- ../bindings/taxonomy.json`.
+"""
+
+import json
+from pathlib import Path
+from typing import get_args
+
+from levents.models import EventKind, EventPayload
+
+TAXONOMY = json.loads((Path(__file__).resolve().parents[2] / "taxonomy.json").read_text())
+
+
+def test_event_kind_lists_every_kind():
+    names = {kind["name"] for kind in TAXONOMY["kinds"]}
+    assert {kind.value for kind in EventKind} == names
+
+
+def test_every_payload_has_a_model():
+    models = {model.model_fields["payload_kind"].default for model in get_args(EventPayload)}
+    missing = {kind["payload"] for kind in TAXONOMY["kinds"]} - models
+    assert not missing
@@ -14,7 +14,21 @@ import {
 } from "@grpc/grpc-js";
 import * as grpc from "@grpc/grpc-js";
 import * as protoLoader from "@grpc/proto-loader";
-import type { Event, EventKind, EventPayload, PlayerRef } from "./types.js";
+import type {
+  Event,
+  EventKind,
+  EventPayload,
+  EventSource,
+  KillType,
+  Lane,
+  Objective,
+  PlayerIdentity,
+  PlayerRef,
+  SeriesInfo,
+  StructureType,
+  TeamMetadata,
+  TurretTier,
+} from "./types.js";
 
 // Resolve proto path - handle both ts-node (src/) and built (dist/) contexts
 function resolveProtoPath(): string {
@@ -41,6 +55,9 @@ function resolveProtoPath(): string {
 
 const PROTO_PATH = resolveProtoPath();
 
+/** Subscribe のレスポンスヘッダーで送られる、resume token 用のセッション ID */
+const SESSION_HEADER = "levents-session";
+
 const LOADER_OPTIONS: protoLoader.Options = {
   keepCase: false,
   longs: String,
@@ -94,6 +111,13 @@ export interface ClientOptions {
   daemonEnv?: Record<string, string>;
   /** 自動起動後に起動完了を待つ上限（ms）。既定: 10000 */
   daemonReadyTimeoutMs?: number;
+  /**
+   * イベントを受け取るまでの遅延（ms）。配信ディレイに合わせる場合に指定。
+   * 既定: デーモン側の LEVENTS_STREAM_DELAY_MS。0 で常にライブ。
+   */
+  delayMs?: number;
+  /** デーモンで API キーが設定されている場合に送るキー（x-api-key ヘッダー） */
+  apiKey?: string;
 }
 
 type EventHandler<T extends EventPayload = EventPayload> = (
@@ -123,6 +147,8 @@ export interface ResolvedClientOptions {
   daemonArgs: string[];
   daemonEnv: Record<string, string>;
   daemonReadyTimeoutMs: number;
+  delayMs?: number;
+  apiKey?: string;
 }
 
 interface ProtoGrpcType {
@@ -153,22 +179,43 @@ type EventServiceClientConstructor = new (
 
 interface SubscribeRequest {
   kinds?: Array<number | string>;
+  backfill?: number;
+  delayMs?: number;
+  resumeToken?: string;
+}
+
+interface GrpcPlayerIdentity {
+  displayName?: string;
+  teamTag?: string | null;
+  portraitUrl?: string | null;
+  socials?: Record<string, string>;
 }
 
 interface GrpcPlayerRef {
   summonerName?: string;
   team?: string | number;
   slot?: number | string;
+  lane?: string | number;
+  identity?: GrpcPlayerIdentity | null;
+  activePlayer?: boolean;
+  riotId?: string | null;
+  championName?: string | null;
+  skinId?: number | string | null;
 }
 
 interface GrpcPlayerEvent {
   player?: GrpcPlayerRef;
+  bounty?: number | string | null;
 }
 
 interface GrpcItemEvent {
   player?: GrpcPlayerRef;
   itemId?: number | string;
   itemName?: string | null;
+  slot?: number | string | null;
+  count?: number | string | null;
+  previousSlot?: number | string | null;
+  price?: number | string | null;
 }
 
 interface GrpcLevelEvent {
@@ -188,19 +235,245 @@ interface GrpcGoldEvent {
   total?: number | string;
 }
 
+interface GrpcScoreEvent {
+  player?: GrpcPlayerRef;
+  delta?: number;
+  total?: number;
+}
+
+interface GrpcEconomyEvent {
+  player?: GrpcPlayerRef;
+  earned?: number | string;
+  spent?: number | string;
+  current?: number | string;
+}
+
+interface GrpcWinProbabilityEvent {
+  order?: number;
+  chaos?: number;
+}
+
+interface GrpcLaneAssignmentEvent {
+  players?: GrpcPlayerRef[];
+}
+
+interface GrpcBountyEvent {
+  player?: GrpcPlayerRef;
+  bounty?: number | string;
+  killStreak?: number | string;
+  deathStreak?: number | string;
+}
+
+interface GrpcDragonEvent {
+  team?: string | number;
+  dragonType?: string;
+  soulPoints?: number | string;
+  soulType?: string | null;
+  expiresAt?: number | string | null;
+}
+
+interface GrpcObjectiveKillEvent {
+  objective?: string | number;
+  dragonType?: string | null;
+  stolen?: boolean;
+  stealSuspected?: boolean;
+  team?: string | number;
+  killer?: GrpcPlayerRef | null;
+}
+
+interface GrpcStructureEvent {
+  structure?: string | number;
+  id?: string;
+  team?: string | number;
+  lane?: string | number;
+  tier?: string | number;
+  killer?: GrpcPlayerRef | null;
+}
+
+interface GrpcTeamWipeEvent {
+  team?: string | number;
+  durationEstimateMs?: number | string;
+}
+
+interface GrpcFarmEvent {
+  player?: GrpcPlayerRef;
+  creepScore?: number | string;
+  csPerMinute?: number | string;
+  csLastMinute?: number | string;
+  jungleEfficiency?: number | string | null;
+}
+
+interface GrpcTeamMetadata {
+  name?: string;
+  tag?: string | null;
+  color?: string | null;
+  logoUrl?: string | null;
+}
+
+interface GrpcSeriesInfo {
+  bestOf?: number | string;
+  gameNumber?: number | string;
+  orderWins?: number | string;
+  chaosWins?: number | string;
+  sidesSwapped?: boolean;
+}
+
+interface GrpcTeamInfoEvent {
+  order?: GrpcTeamMetadata | null;
+  chaos?: GrpcTeamMetadata | null;
+  series?: GrpcSeriesInfo | null;
+}
+
+interface GrpcGameResultEvent {
+  winner?: string | number;
+  series?: GrpcSeriesInfo | null;
+}
+
+interface GrpcScoreAdjustmentEvent {
+  team?: string | number;
+  kills?: number | string;
+}
+
+interface GrpcSurrenderVoteEvent {
+  team?: string | number;
+  remake?: boolean;
+  votesFor?: number | string;
+  votesAgainst?: number | string;
+}
+
+interface GrpcLobbyChatEvent {
+  channel?: string;
+  sender?: string;
+  body?: string;
+}
+
+interface GrpcRunePageEvent {
+  name?: string;
+  primaryStyleId?: number | string;
+  subStyleId?: number | string;
+  perkIds?: Array<number | string>;
+}
+
+interface GrpcActiveRunesEvent {
+  player?: GrpcPlayerRef;
+  primaryStyleId?: number | string;
+  subStyleId?: number | string;
+  perkIds?: Array<number | string>;
+}
+
+interface GrpcResourceEvent {
+  player?: GrpcPlayerRef;
+  resource?: string;
+}
+
+interface GrpcResourceChangedEvent {
+  player?: GrpcPlayerRef;
+  health?: number;
+  maxHealth?: number;
+  resource?: number;
+  maxResource?: number;
+  lowHealth?: boolean;
+}
+
+interface GrpcGameMetadataEvent {
+  gameMode?: string;
+  mapNumber?: number | string;
+  mapName?: string;
+  mapTerrain?: string;
+  gameTimeMs?: number | string;
+}
+
+interface GrpcSkinSelectedEvent {
+  cellId?: number | string;
+  riotId?: string;
+  championId?: number | string;
+  skinId?: number | string;
+}
+
+interface GrpcQueueEvent {
+  timeInQueueSecs?: number | string;
+  estimatedQueueSecs?: number | string;
+}
+
+interface GrpcFriendPresenceEvent {
+  riotId?: string;
+  puuid?: string;
+  availability?: string;
+  gameStatus?: string;
+  queueType?: string;
+  championId?: number | string;
+}
+
+interface GrpcSessionMember {
+  puuid?: string;
+  riotId?: string;
+  team?: string | number;
+  championId?: number | string;
+}
+
+interface GrpcGameflowSessionEvent {
+  phase?: string;
+  gameId?: number | string;
+  queueId?: number | string;
+  queueType?: string;
+  mapId?: number | string;
+  mapName?: string;
+  members?: GrpcSessionMember[];
+}
+
+interface GrpcPickTimerWarningEvent {
+  action?: string;
+  secondsLeft?: number | string;
+  championId?: number | string;
+}
+
+interface GrpcKillDetailEvent {
+  killer?: GrpcPlayerRef | null;
+  victim?: GrpcPlayerRef;
+  assisters?: GrpcPlayerRef[];
+  bounty?: number | string;
+}
+
+interface GrpcSpecialKillEvent {
+  killer?: GrpcPlayerRef;
+  victim?: GrpcPlayerRef | null;
+  killType?: string | number;
+  streak?: number | string;
+}
+
 interface GrpcPhaseEvent {
   phase?: string;
 }
 
 interface GrpcHeartbeatEvent {
   seq?: number | string;
+  uptimeMs?: number | string;
+  sourcesConnected?: string[];
+  sourcesDisabled?: string[];
+  lastPollLatencyMs?: number | string;
+  droppedEvents?: number | string;
+  overBudgetPolls?: number | string;
+  version?: string;
+}
+
+interface GrpcDiagnosticEvent {
+  source?: string;
+  message?: string;
 }
 
 interface GrpcCustomEvent {
-  json?: string;
+  /** UTF-8 JSON; a `bytes` field, so proto-loader hands it over as a Buffer. */
+  json?: Uint8Array | string;
 }
 
 interface GrpcEvent {
+  id?: string;
+  seq?: string | number;
+  signature?: Uint8Array;
+  manual?: boolean;
+  stale?: boolean;
+  source?: string | number;
+  gameId?: string;
   kind?: string | number;
   ts?: string | number;
   player?: GrpcPlayerEvent;
@@ -208,8 +481,36 @@ interface GrpcEvent {
   playerLevel?: GrpcLevelEvent;
   playerSkillLevel?: GrpcSkillLevelEvent;
   playerGold?: GrpcGoldEvent;
+  playerScore?: GrpcScoreEvent;
+  playerEconomy?: GrpcEconomyEvent;
+  winProbability?: GrpcWinProbabilityEvent;
+  laneAssignment?: GrpcLaneAssignmentEvent;
+  playerBounty?: GrpcBountyEvent;
+  dragon?: GrpcDragonEvent;
+  objectiveKill?: GrpcObjectiveKillEvent;
+  structure?: GrpcStructureEvent;
+  teamWipe?: GrpcTeamWipeEvent;
+  playerFarm?: GrpcFarmEvent;
+  teamInfo?: GrpcTeamInfoEvent;
+  gameResult?: GrpcGameResultEvent;
+  scoreAdjustment?: GrpcScoreAdjustmentEvent;
+  surrenderVote?: GrpcSurrenderVoteEvent;
+  lobbyChat?: GrpcLobbyChatEvent;
+  runePage?: GrpcRunePageEvent;
+  activeRunes?: GrpcActiveRunesEvent;
+  resource?: GrpcResourceEvent;
+  gameMetadata?: GrpcGameMetadataEvent;
+  resourceChanged?: GrpcResourceChangedEvent;
+  skinSelected?: GrpcSkinSelectedEvent;
+  queue?: GrpcQueueEvent;
+  friendPresence?: GrpcFriendPresenceEvent;
+  gameflowSession?: GrpcGameflowSessionEvent;
+  pickTimerWarning?: GrpcPickTimerWarningEvent;
+  killDetail?: GrpcKillDetailEvent;
+  specialKill?: GrpcSpecialKillEvent;
   phase?: GrpcPhaseEvent;
   heartbeat?: GrpcHeartbeatEvent;
+  diagnostic?: GrpcDiagnosticEvent;
   custom?: GrpcCustomEvent;
 }
 
@@ -225,6 +526,46 @@ const EVENT_KIND_FROM_STRING: Record<string, EventKind> = {
   EVENT_KIND_RESPAWN: "respawn",
   EVENT_KIND_PHASE_CHANGE: "phaseChange",
   EVENT_KIND_HEARTBEAT: "heartbeat",
+  EVENT_KIND_ITEM_MOVED: "itemMoved",
+  EVENT_KIND_ITEM_STACK_CHANGED: "itemStackChanged",
+  EVENT_KIND_ECONOMY_SNAPSHOT: "economySnapshot",
+  EVENT_KIND_WIN_PROBABILITY: "winProbability",
+  EVENT_KIND_LANE_ASSIGNMENT: "laneAssignment",
+  EVENT_KIND_BOUNTY_UPDATED: "bountyUpdated",
+  EVENT_KIND_SOUL_POINT: "soulPoint",
+  EVENT_KIND_SOUL_TAKEN: "soulTaken",
+  EVENT_KIND_ELDER_BUFF: "elderBuff",
+  EVENT_KIND_TEAM_WIPED: "teamWiped",
+  EVENT_KIND_FARM_RATE: "farmRate",
+  EVENT_KIND_TEAM_INFO: "teamInfo",
+  EVENT_KIND_GAME_ENDED: "gameEnded",
+  EVENT_KIND_SCORE_ADJUSTED: "scoreAdjusted",
+  EVENT_KIND_DIAGNOSTIC: "diagnostic",
+  EVENT_KIND_SURRENDER_VOTE_STARTED: "surrenderVoteStarted",
+  EVENT_KIND_SURRENDER_VOTE_PASSED: "surrenderVotePassed",
+  EVENT_KIND_SURRENDER_VOTE_FAILED: "surrenderVoteFailed",
+  EVENT_KIND_REMAKE: "remake",
+  EVENT_KIND_LOBBY_CHAT: "lobbyChat",
+  EVENT_KIND_RUNE_PAGE_LOCKED: "runePageLocked",
+  EVENT_KIND_SKIN_SELECTED: "skinSelected",
+  EVENT_KIND_QUEUE_ENTERED: "queueEntered",
+  EVENT_KIND_QUEUE_TIME_UPDATE: "queueTimeUpdate",
+  EVENT_KIND_MATCH_FOUND: "matchFound",
+  EVENT_KIND_FRIEND_PRESENCE: "friendPresence",
+  EVENT_KIND_GAMEFLOW_SESSION: "gameflowSession",
+  EVENT_KIND_PICK_TIMER_WARNING: "pickTimerWarning",
+  EVENT_KIND_CHAMPION_KILL: "championKill",
+  EVENT_KIND_MULTIKILL: "multikill",
+  EVENT_KIND_FIRST_BLOOD: "firstBlood",
+  EVENT_KIND_SHUTDOWN: "shutdown",
+  EVENT_KIND_OBJECTIVE_KILL: "objectiveKill",
+  EVENT_KIND_STRUCTURE_DESTROYED: "structureDestroyed",
+  EVENT_KIND_CS_DELTA: "csDelta",
+  EVENT_KIND_VISION_SCORE_DELTA: "visionScoreDelta",
+  EVENT_KIND_ACTIVE_PLAYER_RUNES: "activePlayerRunes",
+  EVENT_KIND_ACTIVE_PLAYER_RESOURCE: "activePlayerResource",
+  EVENT_KIND_GAME_METADATA: "gameMetadata",
+  EVENT_KIND_RESOURCE_CHANGED: "resourceChanged",
 };
 
 const EVENT_KIND_FROM_NUMBER: Record<number, EventKind> = {
@@ -239,6 +580,46 @@ const EVENT_KIND_FROM_NUMBER: Record<number, EventKind> = {
   8: "respawn",
   9: "phaseChange",
   10: "heartbeat",
+  12: "itemMoved",
+  13: "itemStackChanged",
+  14: "economySnapshot",
+  15: "winProbability",
+  16: "laneAssignment",
+  17: "bountyUpdated",
+  18: "soulPoint",
+  19: "soulTaken",
+  20: "elderBuff",
+  21: "teamWiped",
+  22: "farmRate",
+  23: "teamInfo",
+  24: "gameEnded",
+  25: "scoreAdjusted",
+  26: "diagnostic",
+  27: "surrenderVoteStarted",
+  28: "surrenderVotePassed",
+  29: "surrenderVoteFailed",
+  30: "remake",
+  31: "lobbyChat",
+  32: "runePageLocked",
+  33: "skinSelected",
+  34: "queueEntered",
+  35: "queueTimeUpdate",
+  36: "matchFound",
+  37: "friendPresence",
+  38: "gameflowSession",
+  39: "pickTimerWarning",
+  40: "championKill",
+  41: "multikill",
+  42: "firstBlood",
+  43: "shutdown",
+  44: "objectiveKill",
+  45: "structureDestroyed",
+  46: "csDelta",
+  47: "visionScoreDelta",
+  48: "activePlayerRunes",
+  49: "activePlayerResource",
+  50: "gameMetadata",
+  51: "resourceChanged",
 };
 
 const ABILITY_FROM_STRING: Record<string, "q" | "w" | "e" | "r"> = {
@@ -270,6 +651,94 @@ const TEAM_FROM_NUMBER: Record<number, PlayerRef["team"]> = {
   3: "neutral",
 };
 
+const OBJECTIVE_FROM_STRING: Record<string, Objective> = {
+  OBJECTIVE_DRAGON: "dragon",
+  OBJECTIVE_HERALD: "herald",
+  OBJECTIVE_BARON: "baron",
+  OBJECTIVE_GRUBS: "grubs",
+};
+
+const OBJECTIVE_FROM_NUMBER: Record<number, Objective> = {
+  1: "dragon",
+  2: "herald",
+  3: "baron",
+  4: "grubs",
+};
+
+const STRUCTURE_TYPE_FROM_STRING: Record<string, StructureType> = {
+  STRUCTURE_TYPE_TURRET: "turret",
+  STRUCTURE_TYPE_INHIBITOR: "inhibitor",
+};
+
+const STRUCTURE_TYPE_FROM_NUMBER: Record<number, StructureType> = {
+  1: "turret",
+  2: "inhibitor",
+};
+
+const TURRET_TIER_FROM_STRING: Record<string, TurretTier> = {
+  TURRET_TIER_OUTER: "outer",
+  TURRET_TIER_INNER: "inner",
+  TURRET_TIER_BASE: "base",
+  TURRET_TIER_NEXUS: "nexus",
+};
+
+const TURRET_TIER_FROM_NUMBER: Record<number, TurretTier> = {
+  1: "outer",
+  2: "inner",
+  3: "base",
+  4: "nexus",
+};
+
+const KILL_TYPE_FROM_STRING: Record<string, KillType> = {
+  KILL_TYPE_DOUBLE: "double",
+  KILL_TYPE_TRIPLE: "triple",
+  KILL_TYPE_QUADRA: "quadra",
+  KILL_TYPE_PENTA: "penta",
+  KILL_TYPE_FIRST_BLOOD: "firstBlood",
+  KILL_TYPE_SHUTDOWN: "shutdown",
+};
+
+const KILL_TYPE_FROM_NUMBER: Record<number, KillType> = {
+  1: "double",
+  2: "triple",
+  3: "quadra",
+  4: "penta",
+  5: "firstBlood",
+  6: "shutdown",
+};
+
+const LANE_FROM_STRING: Record<string, Lane> = {
+  LANE_TOP: "top",
+  LANE_JUNGLE: "jungle",
+  LANE_MID: "mid",
+  LANE_BOTTOM: "bottom",
+  LANE_SUPPORT: "support",
+};
+
+const LANE_FROM_NUMBER: Record<number, Lane> = {
+  1: "top",
+  2: "jungle",
+  3: "mid",
+  4: "bottom",
+  5: "support",
+};
+
+const SOURCE_FROM_STRING: Record<string, EventSource> = {
+  EVENT_SOURCE_DAEMON: "daemon",
+  EVENT_SOURCE_LIVE_CLIENT: "liveClient",
+  EVENT_SOURCE_LCU: "lcu",
+  EVENT_SOURCE_SYNTHETIC: "synthetic",
+  EVENT_SOURCE_REPLAY: "replay",
+};
+
+const SOURCE_FROM_NUMBER: Record<number, EventSource> = {
+  1: "daemon",
+  2: "liveClient",
+  3: "lcu",
+  4: "synthetic",
+  5: "replay",
+};
+
 function resolveOptions(options: ClientOptions): ResolvedClientOptions {
   const live: ResolvedLiveConfig = {
     enabled: options.live?.enabled ?? true,
@@ -293,6 +762,8 @@ function resolveOptions(options: ClientOptions): ResolvedClientOptions {
     daemonArgs: options.daemonArgs ?? [],
     daemonEnv: options.daemonEnv ?? {},
     daemonReadyTimeoutMs: options.daemonReadyTimeoutMs ?? 10_000,
+    delayMs: options.delayMs,
+    apiKey: options.apiKey,
   };
 }
 
@@ -323,6 +794,9 @@ export class LeventsClient {
   private currentReconnectDelayMs: number;
   private daemon?: ChildProcess;
   private startedDaemon = false;
+  /** 再接続時に続きから受信するためのセッション ID と最後に受信した seq */
+  private session?: string;
+  private lastSeq?: number;
 
   constructor(options: ClientOptions = {}) {
     this.options = resolveOptions(options);
@@ -544,22 +1018,56 @@ export class LeventsClient {
     this.clearReconnectTimer();
     this.currentReconnectDelayMs = this.options.reconnectInitialDelayMs;
 
-    const stream = client.subscribe({ kinds: [] });
+    const resumeToken =
+      this.session && this.lastSeq !== undefined
+        ? `${this.session}:${this.lastSeq}`
+        : undefined;
+    const metadata = new grpc.Metadata();
+    if (this.options.apiKey) {
+      metadata.set("x-api-key", this.options.apiKey);
+    }
+    const stream = client.subscribe(
+      {
+        kinds: [],
+        delayMs: this.options.delayMs,
+        resumeToken,
+      },
+      metadata
+    );
     this.stream = stream;
 
+    stream.on("metadata", (metadata) => {
+      const [session] = metadata.get(SESSION_HEADER);
+      if (typeof session === "string" && session !== this.session) {
+        this.session = session;
+        this.lastSeq = undefined;
+      }
+    });
+
     stream.on("data", (message) => {
       try {
         const event = convertGrpcEvent(message);
+        if (event.seq !== undefined) {
+          this.lastSeq = event.seq;
+        }
         this.emitter.emit(event.kind, event);
       } catch (error) {
         this.notifyError(error);
       }
     });
 
-    stream.on("error", (error) => {
+    stream.on("error", (error: ServiceError) => {
       if (this.shuttingDown) {
         return;
       }
+      if (
+        error.code === grpc.status.FAILED_PRECONDITION ||
+        error.code === grpc.status.OUT_OF_RANGE
+      ) {
+        // 続きから再開できない（デーモン再起動・バッファ外）。次回は新規購読する。
+        this.session = undefined;
+        this.lastSeq = undefined;
+      }
       this.notifyError(error);
       this.teardownStream();
       this.scheduleReconnect();
@@ -636,8 +1144,18 @@ function convertGrpcEvent(message: GrpcEvent): Event {
   const kind = normalizeEventKind(message.kind);
   const ts = normalizeNumber(message.ts, "ts");
   const payload = convertGrpcPayload(message);
-
-  return { kind, ts, payload };
+  const id = message.id ? message.id : undefined;
+  const manual = message.manual ? true : undefined;
+  const stale = message.stale ? true : undefined;
+  const source = normalizeSource(message.source);
+  const gameId = message.gameId ? message.gameId : undefined;
+  const seq = message.seq ? normalizeNumber(message.seq, "seq") : undefined;
+  const signature =
+    message.signature && message.signature.length > 0
+      ? Buffer.from(message.signature).toString("base64")
+      : undefined;
+
+  return { id, seq, kind, source, gameId, ts, payload, manual, stale, signature };
 }
 
 function convertGrpcPayload(message: GrpcEvent): EventPayload {
@@ -647,6 +1165,13 @@ function convertGrpcPayload(message: GrpcEvent): EventPayload {
       player: convertGrpcPlayerRef(message.playerItem.player),
       itemId: normalizeNumber(message.playerItem.itemId, "itemId"),
       itemName: message.playerItem.itemName ?? undefined,
+      slot: normalizeOptionalNumber(message.playerItem.slot, "slot"),
+      count: normalizeOptionalNumber(message.playerItem.count, "count"),
+      previousSlot: normalizeOptionalNumber(
+        message.playerItem.previousSlot,
+        "previousSlot"
+      ),
+      price: normalizeOptionalNumber(message.playerItem.price, "price"),
     };
   }
 
@@ -676,10 +1201,333 @@ function convertGrpcPayload(message: GrpcEvent): EventPayload {
     };
   }
 
+  if (message.playerScore) {
+    return {
+      payloadKind: "playerScore",
+      player: convertGrpcPlayerRef(message.playerScore.player),
+      delta: normalizeNumber(message.playerScore.delta, "delta"),
+      total: normalizeNumber(message.playerScore.total, "total"),
+    };
+  }
+
+  if (message.playerEconomy) {
+    return {
+      payloadKind: "playerEconomy",
+      player: convertGrpcPlayerRef(message.playerEconomy.player),
+      earned: normalizeNumber(message.playerEconomy.earned, "earned"),
+      spent: normalizeNumber(message.playerEconomy.spent, "spent"),
+      current: normalizeNumber(message.playerEconomy.current, "current"),
+    };
+  }
+
+  if (message.winProbability) {
+    return {
+      payloadKind: "winProbability",
+      order: message.winProbability.order ?? 0,
+      chaos: message.winProbability.chaos ?? 0,
+    };
+  }
+
+  if (message.laneAssignment) {
+    return {
+      payloadKind: "laneAssignment",
+      players: (message.laneAssignment.players ?? []).map((player) =>
+        convertGrpcPlayerRef(player)
+      ),
+    };
+  }
+
+  if (message.playerBounty) {
+    return {
+      payloadKind: "playerBounty",
+      player: convertGrpcPlayerRef(message.playerBounty.player),
+      bounty: normalizeNumber(message.playerBounty.bounty, "bounty"),
+      killStreak: normalizeNumber(message.playerBounty.killStreak, "killStreak"),
+      deathStreak: normalizeNumber(message.playerBounty.deathStreak, "deathStreak"),
+    };
+  }
+
+  if (message.dragon) {
+    return {
+      payloadKind: "dragon",
+      team: normalizeTeam(message.dragon.team),
+      dragonType: message.dragon.dragonType ?? "",
+      soulPoints: normalizeNumber(message.dragon.soulPoints, "soulPoints"),
+      soulType: message.dragon.soulType ?? undefined,
+      expiresAt: normalizeOptionalNumber(message.dragon.expiresAt, "expiresAt"),
+    };
+  }
+
+  if (message.objectiveKill) {
+    const kill = message.objectiveKill;
+    return {
+      payloadKind: "objectiveKill",
+      objective: normalizeObjective(kill.objective),
+      dragonType: kill.dragonType ?? undefined,
+      stolen: kill.stolen ?? false,
+      stealSuspected: kill.stealSuspected ?? false,
+      team: normalizeOptionalTeam(kill.team),
+      killer: kill.killer ? convertGrpcPlayerRef(kill.killer) : undefined,
+    };
+  }
+
+  if (message.structure) {
+    const structure = message.structure;
+    return {
+      payloadKind: "structure",
+      structure: normalizeStructureType(structure.structure),
+      id: structure.id ?? "",
+      team: normalizeTeam(structure.team),
+      lane: normalizeLane(structure.lane),
+      tier: normalizeTurretTier(structure.tier),
+      killer: structure.killer ? convertGrpcPlayerRef(structure.killer) : undefined,
+    };
+  }
+
+  if (message.teamWipe) {
+    return {
+      payloadKind: "teamWipe",
+      team: normalizeTeam(message.teamWipe.team),
+      durationEstimateMs: normalizeNumber(
+        message.teamWipe.durationEstimateMs,
+        "durationEstimateMs"
+      ),
+    };
+  }
+
+  if (message.playerFarm) {
+    return {
+      payloadKind: "playerFarm",
+      player: convertGrpcPlayerRef(message.playerFarm.player),
+      creepScore: normalizeNumber(message.playerFarm.creepScore, "creepScore"),
+      csPerMinute: normalizeNumber(
+        message.playerFarm.csPerMinute,
+        "csPerMinute"
+      ),
+      csLastMinute: normalizeNumber(
+        message.playerFarm.csLastMinute,
+        "csLastMinute"
+      ),
+      jungleEfficiency: normalizeOptionalNumber(
+        message.playerFarm.jungleEfficiency,
+        "jungleEfficiency"
+      ),
+    };
+  }
+
+  if (message.teamInfo) {
+    return {
+      payloadKind: "teamInfo",
+      order: convertGrpcTeamMetadata(message.teamInfo.order),
+      chaos: convertGrpcTeamMetadata(message.teamInfo.chaos),
+      series: convertGrpcSeriesInfo(message.teamInfo.series),
+    };
+  }
+
+  if (message.gameResult) {
+    return {
+      payloadKind: "gameResult",
+      winner: normalizeOptionalTeam(message.gameResult.winner),
+      series: convertGrpcSeriesInfo(message.gameResult.series),
+    };
+  }
+
+  if (message.scoreAdjustment) {
+    return {
+      payloadKind: "scoreAdjustment",
+      team: normalizeTeam(message.scoreAdjustment.team),
+      kills: normalizeNumber(message.scoreAdjustment.kills, "kills"),
+    };
+  }
+
+  if (message.surrenderVote) {
+    return {
+      payloadKind: "surrenderVote",
+      team: normalizeOptionalTeam(message.surrenderVote.team),
+      remake: message.surrenderVote.remake ?? false,
+      votesFor: normalizeNumber(message.surrenderVote.votesFor ?? 0, "votesFor"),
+      votesAgainst: normalizeNumber(
+        message.surrenderVote.votesAgainst ?? 0,
+        "votesAgainst"
+      ),
+    };
+  }
+
+  if (message.lobbyChat) {
+    return {
+      payloadKind: "lobbyChat",
+      channel: message.lobbyChat.channel ?? "",
+      sender: message.lobbyChat.sender ?? "",
+      body: message.lobbyChat.body ?? "",
+    };
+  }
+
+  if (message.runePage) {
+    return {
+      payloadKind: "runePage",
+      name: message.runePage.name ?? "",
+      primaryStyleId: normalizeNumber(
+        message.runePage.primaryStyleId ?? 0,
+        "primaryStyleId"
+      ),
+      subStyleId: normalizeNumber(message.runePage.subStyleId ?? 0, "subStyleId"),
+      perkIds: (message.runePage.perkIds ?? []).map((id) =>
+        normalizeNumber(id, "perkIds")
+      ),
+    };
+  }
+
+  if (message.activeRunes) {
+    return {
+      payloadKind: "activeRunes",
+      player: convertGrpcPlayerRef(message.activeRunes.player),
+      primaryStyleId: normalizeNumber(
+        message.activeRunes.primaryStyleId ?? 0,
+        "primaryStyleId"
+      ),
+      subStyleId: normalizeNumber(message.activeRunes.subStyleId ?? 0, "subStyleId"),
+      perkIds: (message.activeRunes.perkIds ?? []).map((id) =>
+        normalizeNumber(id, "perkIds")
+      ),
+    };
+  }
+
+  if (message.resource) {
+    return {
+      payloadKind: "resource",
+      player: convertGrpcPlayerRef(message.resource.player),
+      resource: message.resource.resource ?? "",
+    };
+  }
+
+  if (message.resourceChanged) {
+    return {
+      payloadKind: "resourceChanged",
+      player: convertGrpcPlayerRef(message.resourceChanged.player),
+      health: message.resourceChanged.health ?? 0,
+      maxHealth: message.resourceChanged.maxHealth ?? 0,
+      resource: message.resourceChanged.resource ?? 0,
+      maxResource: message.resourceChanged.maxResource ?? 0,
+      lowHealth: message.resourceChanged.lowHealth ?? false,
+    };
+  }
+
+  if (message.gameMetadata) {
+    return {
+      payloadKind: "gameMetadata",
+      gameMode: message.gameMetadata.gameMode ?? "",
+      mapNumber: normalizeNumber(message.gameMetadata.mapNumber ?? 0, "mapNumber"),
+      mapName: message.gameMetadata.mapName ?? "",
+      mapTerrain: message.gameMetadata.mapTerrain || undefined,
+      gameTimeMs: normalizeNumber(message.gameMetadata.gameTimeMs ?? 0, "gameTimeMs"),
+    };
+  }
+
+  if (message.skinSelected) {
+    return {
+      payloadKind: "skinSelected",
+      cellId: normalizeNumber(message.skinSelected.cellId ?? 0, "cellId"),
+      riotId: message.skinSelected.riotId || undefined,
+      championId: normalizeNumber(
+        message.skinSelected.championId ?? 0,
+        "championId"
+      ),
+      skinId: normalizeNumber(message.skinSelected.skinId ?? 0, "skinId"),
+    };
+  }
+
+  if (message.queue) {
+    return {
+      payloadKind: "queue",
+      timeInQueueSecs: normalizeNumber(
+        message.queue.timeInQueueSecs ?? 0,
+        "timeInQueueSecs"
+      ),
+      estimatedQueueSecs: normalizeNumber(
+        message.queue.estimatedQueueSecs ?? 0,
+        "estimatedQueueSecs"
+      ),
+    };
+  }
+
+  if (message.friendPresence) {
+    return {
+      payloadKind: "friendPresence",
+      riotId: message.friendPresence.riotId ?? "",
+      puuid: message.friendPresence.puuid ?? "",
+      availability: message.friendPresence.availability ?? "",
+      gameStatus: message.friendPresence.gameStatus || undefined,
+      queueType: message.friendPresence.queueType || undefined,
+      championId: normalizeOptionalNumber(
+        message.friendPresence.championId,
+        "championId"
+      ),
+    };
+  }
+
+  if (message.gameflowSession) {
+    const session = message.gameflowSession;
+    return {
+      payloadKind: "gameflowSession",
+      phase: session.phase ?? "",
+      gameId: normalizeOptionalNumber(session.gameId, "gameId"),
+      queueId: normalizeOptionalNumber(session.queueId, "queueId"),
+      queueType: session.queueType || undefined,
+      mapId: normalizeOptionalNumber(session.mapId, "mapId"),
+      mapName: session.mapName || undefined,
+      members: (session.members ?? []).map((member) => ({
+        puuid: member.puuid ?? "",
+        riotId: member.riotId || undefined,
+        team: normalizeTeam(member.team),
+        championId: normalizeOptionalNumber(member.championId, "championId"),
+      })),
+    };
+  }
+
+  if (message.pickTimerWarning) {
+    return {
+      payloadKind: "pickTimerWarning",
+      action: message.pickTimerWarning.action ?? "",
+      secondsLeft: normalizeNumber(
+        message.pickTimerWarning.secondsLeft ?? 0,
+        "secondsLeft"
+      ),
+      championId: normalizeOptionalNumber(
+        message.pickTimerWarning.championId,
+        "championId"
+      ),
+    };
+  }
+
+  if (message.killDetail) {
+    const kill = message.killDetail;
+    return {
+      payloadKind: "killDetail",
+      killer: kill.killer ? convertGrpcPlayerRef(kill.killer) : undefined,
+      victim: convertGrpcPlayerRef(kill.victim),
+      assisters: (kill.assisters ?? []).map((player) =>
+        convertGrpcPlayerRef(player)
+      ),
+      bounty: normalizeOptionalNumber(kill.bounty, "bounty"),
+    };
+  }
+
+  if (message.specialKill) {
+    const kill = message.specialKill;
+    return {
+      payloadKind: "specialKill",
+      killer: convertGrpcPlayerRef(kill.killer),
+      victim: kill.victim ? convertGrpcPlayerRef(kill.victim) : undefined,
+      killType: normalizeKillType(kill.killType),
+      streak: normalizeNumber(kill.streak, "streak"),
+    };
+  }
+
   if (message.player) {
     return {
       payloadKind: "player",
       player: convertGrpcPlayerRef(message.player.player),
+      bounty: normalizeOptionalNumber(message.player.bounty, "bounty"),
     };
   }
 
@@ -698,6 +1546,30 @@ function convertGrpcPayload(message: GrpcEvent): EventPayload {
     return {
       payloadKind: "heartbeat",
       seq: normalizeNumber(message.heartbeat.seq, "seq"),
+      uptimeMs: normalizeOptionalNumber(message.heartbeat.uptimeMs, "uptimeMs"),
+      sourcesConnected: message.heartbeat.sourcesConnected ?? [],
+      sourcesDisabled: message.heartbeat.sourcesDisabled ?? [],
+      lastPollLatencyMs: normalizeOptionalNumber(
+        message.heartbeat.lastPollLatencyMs,
+        "lastPollLatencyMs"
+      ),
+      droppedEvents: normalizeOptionalNumber(
+        message.heartbeat.droppedEvents,
+        "droppedEvents"
+      ),
+      overBudgetPolls: normalizeOptionalNumber(
+        message.heartbeat.overBudgetPolls,
+        "overBudgetPolls"
+      ),
+      version: message.heartbeat.version || undefined,
+    };
+  }
+
+  if (message.diagnostic) {
+    return {
+      payloadKind: "diagnostic",
+      source: message.diagnostic.source ?? "",
+      message: message.diagnostic.message ?? "",
     };
   }
 
@@ -720,6 +1592,58 @@ function convertGrpcPlayerRef(player?: GrpcPlayerRef): PlayerRef {
     summonerName: player.summonerName ?? "",
     team: normalizeTeam(player.team),
     slot: normalizeNumber(player.slot, "slot"),
+    lane: normalizeLane(player.lane),
+    identity: convertGrpcPlayerIdentity(player.identity),
+    activePlayer: player.activePlayer ?? false,
+    riotId: player.riotId ?? undefined,
+    championName: player.championName ?? undefined,
+    skinId: player.skinId == null ? undefined : normalizeNumber(player.skinId, "skinId"),
+  };
+}
+
+function convertGrpcTeamMetadata(
+  metadata?: GrpcTeamMetadata | null
+): TeamMetadata | undefined {
+  if (!metadata) {
+    return undefined;
+  }
+
+  return {
+    name: metadata.name ?? "",
+    tag: metadata.tag ?? undefined,
+    color: metadata.color ?? undefined,
+    logoUrl: metadata.logoUrl ?? undefined,
+  };
+}
+
+function convertGrpcSeriesInfo(
+  series?: GrpcSeriesInfo | null
+): SeriesInfo | undefined {
+  if (!series) {
+    return undefined;
+  }
+
+  return {
+    bestOf: normalizeNumber(series.bestOf, "bestOf"),
+    gameNumber: normalizeNumber(series.gameNumber, "gameNumber"),
+    orderWins: normalizeNumber(series.orderWins, "orderWins"),
+    chaosWins: normalizeNumber(series.chaosWins, "chaosWins"),
+    sidesSwapped: series.sidesSwapped ?? false,
+  };
+}
+
+function convertGrpcPlayerIdentity(
+  identity?: GrpcPlayerIdentity | null
+): PlayerIdentity | undefined {
+  if (!identity) {
+    return undefined;
+  }
+
+  return {
+    displayName: identity.displayName ?? "",
+    teamTag: identity.teamTag ?? undefined,
+    portraitUrl: identity.portraitUrl ?? undefined,
+    socials: identity.socials ?? {},
   };
 }
 
@@ -755,6 +1679,97 @@ function normalizeTeam(value: string | number | undefined): PlayerRef["team"] {
   throw new Error(`Unsupported team value: ${value as string}`);
 }
 
+function normalizeOptionalTeam(
+  value: string | number | undefined
+): PlayerRef["team"] | undefined {
+  if (value === undefined || value === "TEAM_UNSPECIFIED" || value === 0) {
+    return undefined;
+  }
+  return normalizeTeam(value);
+}
+
+function normalizeSource(value: string | number | undefined): EventSource | undefined {
+  if (typeof value === "string") {
+    return SOURCE_FROM_STRING[value];
+  }
+  if (typeof value === "number") {
+    return SOURCE_FROM_NUMBER[value];
+  }
+  return undefined;
+}
+
+function normalizeLane(value: string | number | undefined): Lane | undefined {
+  if (typeof value === "string") {
+    return LANE_FROM_STRING[value];
+  }
+  if (typeof value === "number") {
+    return LANE_FROM_NUMBER[value];
+  }
+  return undefined;
+}
+
+function normalizeObjective(value: string | number | undefined): Objective {
+  if (typeof value === "string") {
+    const result = OBJECTIVE_FROM_STRING[value];
+    if (result) {
+      return result;
+    }
+  } else if (typeof value === "number") {
+    const result = OBJECTIVE_FROM_NUMBER[value];
+    if (result) {
+      return result;
+    }
+  }
+
+  throw new Error(`Unsupported objective: ${value as string}`);
+}
+
+function normalizeStructureType(
+  value: string | number | undefined,
+): StructureType {
+  if (typeof value === "string") {
+    const result = STRUCTURE_TYPE_FROM_STRING[value];
+    if (result) {
+      return result;
+    }
+  } else if (typeof value === "number") {
+    const result = STRUCTURE_TYPE_FROM_NUMBER[value];
+    if (result) {
+      return result;
+    }
+  }
+
+  throw new Error(`Unsupported structure type: ${value as string}`);
+}
+
+function normalizeTurretTier(
+  value: string | number | undefined,
+): TurretTier | undefined {
+  if (typeof value === "string") {
+    return TURRET_TIER_FROM_STRING[value];
+  }
+  if (typeof value === "number") {
+    return TURRET_TIER_FROM_NUMBER[value];
+  }
+  return undefined;
+}
+
+function normalizeKillType(value: string | number | undefined): KillType {
+  if (typeof value === "string") {
+    const result = KILL_TYPE_FROM_STRING[value];
+    if (result) {
+      return result;
+    }
+  } else if (typeof value === "number") {
+    const result = KILL_TYPE_FROM_NUMBER[value];
+    if (result) {
+      return result;
+    }
+  }
+
+  throw new Error(`Unsupported kill type: ${value as string}`);
+}
+
 function normalizeNumber(
   value: string | number | undefined,
   label: string
@@ -771,13 +1786,27 @@ function normalizeNumber(
   throw new Error(`Invalid numeric value for ${label}: ${value as string}`);
 }
 
+function normalizeOptionalNumber(
+  value: string | number | null | undefined,
+  label: string
+): number | undefined {
+  if (value === null || value === undefined) {
+    return undefined;
+  }
+  return normalizeNumber(value, label);
+}
+
 function parseCustomPayload(payload: GrpcCustomEvent): Record<string, unknown> {
-  if (!payload.json) {
+  if (!payload.json || payload.json.length === 0) {
     return {};
   }
 
+  const text =
+    typeof payload.json === "string"
+      ? payload.json
+      : Buffer.from(payload.json).toString("utf8");
   try {
-    const parsed = JSON.parse(payload.json);
+    const parsed = JSON.parse(text);
     if (parsed && typeof parsed === "object") {
       return parsed as Record<string, unknown>;
     }
@@ -0,0 +1,210 @@
+import { createSocket } from "node:dgram";
+
+/** デーモンが mDNS で登録するサービス名 */
+export const SERVICE_NAME = "_levents._tcp.local";
+
+const MDNS_GROUP = "224.0.0.251";
+const MDNS_PORT = 5353;
+
+const TYPE_A = 1;
+const TYPE_PTR = 12;
+const TYPE_TXT = 16;
+const TYPE_AAAA = 28;
+const TYPE_SRV = 33;
+
+export interface DiscoveryOptions {
+  /** 応答を待つ時間（ms）。既定: 1500 */
+  timeoutMs?: number;
+}
+
+export interface DiscoveredDaemon {
+  /** インスタンス名（デーモンの LEVENTS_MDNS） */
+  name: string;
+  host: string;
+  port: number;
+  addresses: string[];
+  /** TXT レコード（version, schema, transports） */
+  txt: Record<string, string>;
+  /** createClient の endpoint にそのまま渡せるアドレス */
+  endpoint: string;
+}
+
+interface ResourceRecord {
+  name: string;
+  type: number;
+  data: Buffer;
+  dataOffset: number;
+}
+
+/**
+ * LAN 上の levents デーモンを mDNS で探す。
+ * `timeoutMs` の間に応答したデーモンを返す（見つからなければ空配列）。
+ */
+export function discoverDaemons(
+  options: DiscoveryOptions = {}
+): Promise<DiscoveredDaemon[]> {
+  const timeoutMs = options.timeoutMs ?? 1500;
+  return new Promise((resolve, reject) => {
+    const socket = createSocket({ type: "udp4", reuseAddr: true });
+    const found = new Map<string, DiscoveredDaemon>();
+
+    socket.on("message", (message) => {
+      try {
+        for (const daemon of parseResponse(message)) {
+          found.set(daemon.name, daemon);
+        }
+      } catch {
+        // 壊れたパケットや他サービスの応答は無視する
+      }
+    });
+    socket.on("error", (error) => {
+      socket.close();
+      reject(error);
+    });
+    socket.bind(0, () => {
+      // 5353 以外のポートからの問い合わせにはユニキャストで応答が返る（RFC 6762 §6.7）
+      socket.send(buildQuery(), MDNS_PORT, MDNS_GROUP);
+      setTimeout(() => {
+        socket.close();
+        resolve([...found.values()]);
+      }, timeoutMs);
+    });
+  });
+}
+
+function buildQuery(): Buffer {
+  const header = Buffer.alloc(12);
+  header.writeUInt16BE(1, 4);
+  const question = Buffer.alloc(4);
+  question.writeUInt16BE(TYPE_PTR, 0);
+  question.writeUInt16BE(1, 2);
+  return Buffer.concat([header, encodeName(SERVICE_NAME), question]);
+}
+
+function encodeName(name: string): Buffer {
+  const parts: Buffer[] = [];
+  for (const label of name.split(".").filter((label) => label.length > 0)) {
+    const bytes = Buffer.from(label, "utf8");
+    parts.push(Buffer.from([bytes.length]), bytes);
+  }
+  parts.push(Buffer.from([0]));
+  return Buffer.concat(parts);
+}
+
+function readName(packet: Buffer, offset: number): [string, number] {
+  const labels: string[] = [];
+  let end: number | undefined;
+  // ポインタの循環で無限ループしないよう回数を制限する
+  for (let hops = 0; hops < 32; hops += 1) {
+    const length = packet.readUInt8(offset);
+    if (length === 0) {
+      return [labels.join("."), end ?? offset + 1];
+    }
+    if ((length & 0xc0) === 0xc0) {
+      end ??= offset + 2;
+      offset = ((length & 0x3f) << 8) | packet.readUInt8(offset + 1);
+      continue;
+    }
+    labels.push(packet.toString("utf8", offset + 1, offset + 1 + length));
+    offset += 1 + length;
+  }
+  throw new Error("DNS name compression loop");
+}
+
+function parseResponse(packet: Buffer): DiscoveredDaemon[] {
+  if ((packet.readUInt8(2) & 0x80) === 0) {
+    return [];
+  }
+  const questions = packet.readUInt16BE(4);
+  const records =
+    packet.readUInt16BE(6) + packet.readUInt16BE(8) + packet.readUInt16BE(10);
+
+  let offset = 12;
+  for (let i = 0; i < questions; i += 1) {
+    offset = readName(packet, offset)[1] + 4;
+  }
+
+  const parsed: ResourceRecord[] = [];
+  for (let i = 0; i < records; i += 1) {
+    const [name, next] = readName(packet, offset);
+    const type = packet.readUInt16BE(next);
+    const length = packet.readUInt16BE(next + 8);
+    const dataOffset = next + 10;
+    parsed.push({
+      name: name.toLowerCase(),
+      type,
+      data: packet.subarray(dataOffset, dataOffset + length),
+      dataOffset,
+    });
+    offset = dataOffset + length;
+  }
+
+  const instances = parsed
+    .filter(
+      (record) => record.type === TYPE_PTR && record.name === SERVICE_NAME
+    )
+    .map((record) => readName(packet, record.dataOffset)[0]);
+
+  const daemons: DiscoveredDaemon[] = [];
+  for (const instance of instances) {
+    const key = instance.toLowerCase();
+    const srv = parsed.find(
+      (record) => record.type === TYPE_SRV && record.name === key
+    );
+    if (!srv) {
+      continue;
+    }
+    const port = srv.data.readUInt16BE(4);
+    const host = readName(packet, srv.dataOffset + 6)[0];
+    const addresses = parsed
+      .filter((record) => record.name === host.toLowerCase())
+      .flatMap((record) => {
+        if (record.type === TYPE_A && record.data.length === 4) {
+          return [[...record.data].join(".")];
+        }
+        if (record.type === TYPE_AAAA && record.data.length === 16) {
+          return [formatIpv6(record.data)];
+        }
+        return [];
+      });
+    const txtRecord = parsed.find(
+      (record) => record.type === TYPE_TXT && record.name === key
+    );
+    const name = instance.slice(0, -(SERVICE_NAME.length + 1));
+    const address = addresses[0] ?? host;
+    daemons.push({
+      name,
+      host,
+      port,
+      addresses,
+      txt: txtRecord ? parseTxt(txtRecord.data) : {},
+      endpoint: address.includes(":")
+        ? `[${address}]:${port}`
+        : `${address}:${port}`,
+    });
+  }
+  return daemons;
+}
+
+function parseTxt(data: Buffer): Record<string, string> {
+  const entries: Record<string, string> = {};
+  let offset = 0;
+  while (offset < data.length) {
+    const length = data.readUInt8(offset);
+    const entry = data.toString("utf8", offset + 1, offset + 1 + length);
+    const separator = entry.indexOf("=");
+    if (separator > 0) {
+      entries[entry.slice(0, separator)] = entry.slice(separator + 1);
+    }
+    offset += 1 + length;
+  }
+  return entries;
+}
+
+function formatIpv6(bytes: Buffer): string {
+  const groups: string[] = [];
+  for (let i = 0; i < 16; i += 2) {
+    groups.push(bytes.readUInt16BE(i).toString(16));
+  }
+  return groups.join(":");
+}
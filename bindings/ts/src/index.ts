@@ -1,4 +1,6 @@
 export { createClient, LeventsClient } from './client.js';
+export { discoverDaemons, SERVICE_NAME } from './discovery.js';
+export type { DiscoveredDaemon, DiscoveryOptions } from './discovery.js';
 export type {
   ClientOptions,
   LiveConfig,
@@ -9,13 +11,51 @@ export type {
   Event,
   EventKind,
   EventPayload,
+  EventSource,
+  Lane,
+  PlayerIdentity,
   PlayerRef,
   PlayerEventPayload,
   ItemEventPayload,
   LevelEventPayload,
   SkillLevelEventPayload,
   GoldEventPayload,
+  ScoreEventPayload,
+  ActiveRunesEventPayload,
+  ResourceEventPayload,
+  ResourceChangedEventPayload,
+  GameMetadataEventPayload,
+  EconomyEventPayload,
+  WinProbabilityEventPayload,
+  LaneAssignmentEventPayload,
+  BountyEventPayload,
+  DragonEventPayload,
+  Objective,
+  ObjectiveKillEventPayload,
+  StructureType,
+  TurretTier,
+  StructureEventPayload,
+  TeamWipeEventPayload,
+  FarmEventPayload,
+  TeamMetadata,
+  TeamInfoEventPayload,
+  SeriesInfo,
+  GameResultEventPayload,
+  ScoreAdjustmentEventPayload,
+  SurrenderVoteEventPayload,
+  LobbyChatEventPayload,
+  RunePageEventPayload,
+  SkinSelectedEventPayload,
+  QueueEventPayload,
+  FriendPresenceEventPayload,
+  SessionMember,
+  GameflowSessionEventPayload,
+  PickTimerWarningEventPayload,
+  KillDetailEventPayload,
+  KillType,
+  SpecialKillEventPayload,
   PhaseEventPayload,
   HeartbeatEventPayload,
+  DiagnosticEventPayload,
   CustomEventPayload
 } from './types.js';
@@ -0,0 +1,35 @@
+// Checks the hand-written kinds and payloads against the taxonomy exported by the daemon
+// (`cargo run -p levents-model --bin event-taxonomy -- ../bindings/taxonomy.json`).
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { readFileSync } from "node:fs";
+
+interface TaxonomyKind {
+  name: string;
+  payload: string;
+}
+
+const taxonomy = JSON.parse(
+  readFileSync(new URL("../../taxonomy.json", import.meta.url), "utf8")
+) as { kinds: TaxonomyKind[] };
+const types = readFileSync(new URL("./types.ts", import.meta.url), "utf8");
+const client = readFileSync(new URL("./client.ts", import.meta.url), "utf8");
+
+test("EventKind lists every kind in the taxonomy", () => {
+  const union = types.match(/export type EventKind =([^;]*);/);
+  assert.ok(union, "EventKind union not found");
+  const kinds = [...union[1].matchAll(/'(\w+)'/g)].map((match) => match[1]);
+  assert.deepEqual(kinds.sort(), taxonomy.kinds.map((kind) => kind.name).sort());
+});
+
+test("every kind can be decoded from gRPC", () => {
+  for (const kind of taxonomy.kinds) {
+    assert.match(client, new RegExp(`: "${kind.name}",`), kind.name);
+  }
+});
+
+test("every payload of the taxonomy has a payload type", () => {
+  for (const payload of new Set(taxonomy.kinds.map((kind) => kind.payload))) {
+    assert.match(types, new RegExp(`payloadKind: '${payload}';`), payload);
+  }
+});
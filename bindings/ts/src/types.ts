@@ -9,21 +9,110 @@ export type EventKind =
   | 'goldDelta'
   | 'respawn'
   | 'phaseChange'
-  | 'heartbeat';
+  | 'heartbeat'
+  | 'itemMoved'
+  | 'itemStackChanged'
+  | 'economySnapshot'
+  | 'winProbability'
+  | 'laneAssignment'
+  | 'bountyUpdated'
+  | 'soulPoint'
+  | 'soulTaken'
+  | 'elderBuff'
+  | 'teamWiped'
+  | 'farmRate'
+  | 'teamInfo'
+  | 'gameEnded'
+  | 'scoreAdjusted'
+  | 'diagnostic'
+  | 'surrenderVoteStarted'
+  | 'surrenderVotePassed'
+  | 'surrenderVoteFailed'
+  | 'remake'
+  | 'lobbyChat'
+  | 'runePageLocked'
+  | 'skinSelected'
+  | 'queueEntered'
+  | 'queueTimeUpdate'
+  | 'matchFound'
+  | 'friendPresence'
+  | 'gameflowSession'
+  | 'pickTimerWarning'
+  | 'championKill'
+  | 'multikill'
+  | 'firstBlood'
+  | 'shutdown'
+  | 'objectiveKill'
+  | 'structureDestroyed'
+  | 'csDelta'
+  | 'visionScoreDelta'
+  | 'activePlayerRunes'
+  | 'activePlayerResource'
+  | 'gameMetadata'
+  | 'resourceChanged';
 
 export interface Timestamped {
   ts: number;
 }
 
+export type Lane = 'top' | 'jungle' | 'mid' | 'bottom' | 'support';
+
+/** Where an event came from; `daemon` covers heartbeats, diagnostics and manual corrections. */
+export type EventSource = 'daemon' | 'liveClient' | 'lcu' | 'synthetic' | 'replay';
+
+export interface PlayerIdentity {
+  displayName: string;
+  teamTag?: string;
+  portraitUrl?: string;
+  /** Social handles keyed by network, e.g. `twitter` or `twitch`. */
+  socials: Record<string, string>;
+}
+
 export interface PlayerRef {
   summonerName: string;
   team: 'order' | 'chaos' | 'neutral';
   slot: number;
+  lane?: Lane;
+  /** Branded identity from the daemon's roster, when the player is listed. */
+  identity?: PlayerIdentity;
+  /** The player the Live Client runs for: the local player, or the one being spectated. */
+  activePlayer: boolean;
+  /** Riot ID (`gameName#tagLine`), on clients that report it. */
+  riotId?: string;
+  /** Champion played, in the client's language. */
+  championName?: string;
+  /** 0 is the base skin. */
+  skinId?: number;
 }
 
 export interface PlayerEventPayload {
   payloadKind: 'player';
   player: PlayerRef;
+  bounty?: number;
+}
+
+/** A champion kill with killer, victim and assisters together. */
+export interface KillDetailEventPayload {
+  payloadKind: 'killDetail';
+  /** Absent when the client names no killer. */
+  killer?: PlayerRef;
+  victim: PlayerRef;
+  assisters: PlayerRef[];
+  /** Estimated gold awarded for the kill (the victim's bounty). */
+  bounty?: number;
+}
+
+export type KillType = 'double' | 'triple' | 'quadra' | 'penta' | 'firstBlood' | 'shutdown';
+
+/** A multikill, first blood or shutdown, reported on top of the kill itself. */
+export interface SpecialKillEventPayload {
+  payloadKind: 'specialKill';
+  killer: PlayerRef;
+  /** Absent for multikills, which span several victims. */
+  victim?: PlayerRef;
+  killType: KillType;
+  /** 2 to 5 for multikills, 1 for first blood, the victim's lost streak for shutdowns. */
+  streak: number;
 }
 
 export interface ItemEventPayload {
@@ -31,6 +120,11 @@ export interface ItemEventPayload {
   player: PlayerRef;
   itemId: number;
   itemName?: string;
+  slot?: number;
+  count?: number;
+  previousSlot?: number;
+  /** Gold price of one unit; times `count`, what the stack is worth. */
+  price?: number;
 }
 
 export interface LevelEventPayload {
@@ -53,6 +147,278 @@ export interface GoldEventPayload {
   total: number;
 }
 
+/** Creep score (`csDelta`) or vision score (`visionScoreDelta`) growth since last reported. */
+export interface ScoreEventPayload {
+  payloadKind: 'playerScore';
+  player: PlayerRef;
+  delta: number;
+  total: number;
+}
+
+export interface EconomyEventPayload {
+  payloadKind: 'playerEconomy';
+  player: PlayerRef;
+  earned: number;
+  spent: number;
+  current: number;
+}
+
+export interface WinProbabilityEventPayload {
+  payloadKind: 'winProbability';
+  order: number;
+  chaos: number;
+}
+
+export interface LaneAssignmentEventPayload {
+  payloadKind: 'laneAssignment';
+  players: PlayerRef[];
+}
+
+export interface BountyEventPayload {
+  payloadKind: 'playerBounty';
+  player: PlayerRef;
+  bounty: number;
+  killStreak: number;
+  deathStreak: number;
+}
+
+export interface DragonEventPayload {
+  payloadKind: 'dragon';
+  team: PlayerRef['team'];
+  dragonType: string;
+  soulPoints: number;
+  soulType?: string;
+  expiresAt?: number;
+}
+
+export type Objective = 'dragon' | 'herald' | 'baron' | 'grubs';
+
+/** An epic monster kill. */
+export interface ObjectiveKillEventPayload {
+  payloadKind: 'objectiveKill';
+  objective: Objective;
+  /** Dragons only, as reported by the client (`Fire`, `Hextech`, `Elder`, ...). */
+  dragonType?: string;
+  stolen: boolean;
+  /** `stolen`, or taken by a team outnumbered in the champion kills just before; a heuristic. */
+  stealSuspected: boolean;
+  /** Absent when the last hit was not a known player's. */
+  team?: PlayerRef['team'];
+  killer?: PlayerRef;
+}
+
+export type StructureType = 'turret' | 'inhibitor';
+
+export type TurretTier = 'outer' | 'inner' | 'base' | 'nexus';
+
+/** A turret or inhibitor destroyed. */
+export interface StructureEventPayload {
+  payloadKind: 'structure';
+  structure: StructureType;
+  /** Client id, e.g. `Turret_T2_L_03_A` or `Barracks_T1_R1`. */
+  id: string;
+  /** Team the structure belonged to. */
+  team: PlayerRef['team'];
+  /** Absent for nexus turrets. */
+  lane?: Lane;
+  /** Turrets only. */
+  tier?: TurretTier;
+  killer?: PlayerRef;
+}
+
+export interface TeamWipeEventPayload {
+  payloadKind: 'teamWipe';
+  team: PlayerRef['team'];
+  durationEstimateMs: number;
+}
+
+export interface FarmEventPayload {
+  payloadKind: 'playerFarm';
+  player: PlayerRef;
+  creepScore: number;
+  csPerMinute: number;
+  csLastMinute: number;
+  jungleEfficiency?: number;
+}
+
+export interface TeamMetadata {
+  name: string;
+  tag?: string;
+  color?: string;
+  logoUrl?: string;
+}
+
+export interface SeriesInfo {
+  bestOf: number;
+  gameNumber: number;
+  /** Wins of the team currently on the order side. */
+  orderWins: number;
+  /** Wins of the team currently on the chaos side. */
+  chaosWins: number;
+  sidesSwapped: boolean;
+}
+
+export interface TeamInfoEventPayload {
+  payloadKind: 'teamInfo';
+  order?: TeamMetadata;
+  chaos?: TeamMetadata;
+  series?: SeriesInfo;
+}
+
+export interface GameResultEventPayload {
+  payloadKind: 'gameResult';
+  winner?: PlayerRef['team'];
+  series?: SeriesInfo;
+}
+
+export interface ScoreAdjustmentEventPayload {
+  payloadKind: 'scoreAdjustment';
+  team: PlayerRef['team'];
+  /** Kills added to (or, when negative, removed from) the team's score. */
+  kills: number;
+}
+
+export interface SurrenderVoteEventPayload {
+  payloadKind: 'surrenderVote';
+  /** Team that called the vote, when the client reports it. */
+  team?: PlayerRef['team'];
+  /** The vote is an early surrender that remakes the game. */
+  remake: boolean;
+  votesFor: number;
+  votesAgainst: number;
+}
+
+export interface LobbyChatEventPayload {
+  payloadKind: 'lobbyChat';
+  /** `lobby` or `championSelect`. */
+  channel: string;
+  /** Chat id of the sender as reported by the client. */
+  sender: string;
+  body: string;
+}
+
+/** Runes of the active player, from the Live Client. */
+export interface ActiveRunesEventPayload {
+  payloadKind: 'activeRunes';
+  player: PlayerRef;
+  primaryStyleId: number;
+  subStyleId: number;
+  /** Keystone first, stat shards last. */
+  perkIds: number[];
+}
+
+/** Resource bar of the active player's champion. */
+export interface ResourceEventPayload {
+  payloadKind: 'resource';
+  player: PlayerRef;
+  /** As reported by the client, e.g. `MANA`, `ENERGY` or `NONE`. */
+  resource: string;
+}
+
+/**
+ * Health and resource bar of a champion, sent once either moved past the daemon's thresholds.
+ * Only sent with `LEVENTS_HIGH_FREQUENCY_EVENTS=1`.
+ */
+export interface ResourceChangedEventPayload {
+  payloadKind: 'resourceChanged';
+  player: PlayerRef;
+  health: number;
+  maxHealth: number;
+  /** Mana, energy or fury; zero for champions without a resource bar. */
+  resource: number;
+  maxResource: number;
+  /** Set under the low-health threshold and cleared once health recovers past a higher one. */
+  lowHealth: boolean;
+}
+
+/** Mode and map of the running game, from the Live Client. */
+export interface GameMetadataEventPayload {
+  payloadKind: 'gameMetadata';
+  /** As reported by the client, e.g. `CLASSIC`, `ARAM` or `CHERRY` (Arena). */
+  gameMode: string;
+  /** 11 for Summoner's Rift, 12 for the Howling Abyss, 30 for Arena. */
+  mapNumber: number;
+  mapName: string;
+  /** Terrain of the transformed rift, e.g. `Infernal`; unset until the rift transforms. */
+  mapTerrain?: string;
+  gameTimeMs: number;
+}
+
+export interface RunePageEventPayload {
+  payloadKind: 'runePage';
+  name: string;
+  /** Style id of the keystone tree, e.g. 8000 for Precision. */
+  primaryStyleId: number;
+  subStyleId: number;
+  /** Selected perks in client order: keystone first, stat shards last. */
+  perkIds: number[];
+}
+
+export interface SkinSelectedEventPayload {
+  payloadKind: 'skinSelected';
+  /** Champ select cell of the player. */
+  cellId: number;
+  /** `name#tag` of the player, when the client reports it. */
+  riotId?: string;
+  championId: number;
+  /** Skin id, or the chroma id when a chroma is picked. */
+  skinId: number;
+}
+
+export interface QueueEventPayload {
+  payloadKind: 'queue';
+  /** Whole seconds spent in queue so far. */
+  timeInQueueSecs: number;
+  /** The client's estimate of the total queue time, in whole seconds. */
+  estimatedQueueSecs: number;
+}
+
+export interface FriendPresenceEventPayload {
+  payloadKind: 'friendPresence';
+  /** `name#tag` of the friend. */
+  riotId: string;
+  puuid: string;
+  /** `chat`, `away`, `dnd`, `mobile` or `offline`. */
+  availability: string;
+  /** e.g. `inGame`, `championSelect`, `inQueue` or `outOfGame`. */
+  gameStatus?: string;
+  /** Queue of the current game, e.g. `RANKED_SOLO_5x5`. */
+  queueType?: string;
+  championId?: number;
+}
+
+export interface SessionMember {
+  puuid: string;
+  /** `name#tag`, when the client reports it. */
+  riotId?: string;
+  team: PlayerRef['team'];
+  championId?: number;
+}
+
+export interface GameflowSessionEventPayload {
+  payloadKind: 'gameflowSession';
+  phase: string;
+  /** Id of the game, once matchmaking has placed the player in one. */
+  gameId?: number;
+  /** e.g. 420 for ranked solo/duo. */
+  queueId?: number;
+  /** e.g. `RANKED_SOLO_5x5`. */
+  queueType?: string;
+  mapId?: number;
+  mapName?: string;
+  /** Players of the game, as far as the client knows them yet. */
+  members: SessionMember[];
+}
+
+export interface PickTimerWarningEventPayload {
+  payloadKind: 'pickTimerWarning';
+  /** `pick` or `ban`. */
+  action: string;
+  secondsLeft: number;
+  /** Champion hovered so far, if any. */
+  championId?: number;
+}
+
 export interface PhaseEventPayload {
   payloadKind: 'phase';
   phase: string;
@@ -61,6 +427,27 @@ export interface PhaseEventPayload {
 export interface HeartbeatEventPayload {
   payloadKind: 'heartbeat';
   seq: number;
+  /** Milliseconds since the daemon started. */
+  uptimeMs?: number;
+  /** Sources currently reachable: `liveClient` and/or `lcu`. */
+  sourcesConnected?: string[];
+  /** Sources the daemon never runs: turned off in its configuration or not built in. */
+  sourcesDisabled?: string[];
+  /** Duration of the latest Live Client poll. */
+  lastPollLatencyMs?: number;
+  /** Events subscribers have lost to lag since the daemon started. */
+  droppedEvents?: number;
+  /** Live Client polls that took longer than their interval since the daemon started. */
+  overBudgetPolls?: number;
+  /** Daemon version and git revision, e.g. `0.1.0+3f2a9c1d0b4e`. */
+  version?: string;
+}
+
+export interface DiagnosticEventPayload {
+  payloadKind: 'diagnostic';
+  /** Component the diagnostic is about, e.g. `liveClient` or `lcu`. */
+  source: string;
+  message: string;
 }
 
 export interface CustomEventPayload {
@@ -74,11 +461,52 @@ export type EventPayload =
   | LevelEventPayload
   | SkillLevelEventPayload
   | GoldEventPayload
+  | ScoreEventPayload
+  | EconomyEventPayload
+  | WinProbabilityEventPayload
+  | LaneAssignmentEventPayload
+  | BountyEventPayload
+  | DragonEventPayload
+  | ObjectiveKillEventPayload
+  | StructureEventPayload
+  | TeamWipeEventPayload
+  | FarmEventPayload
+  | TeamInfoEventPayload
+  | GameResultEventPayload
+  | ScoreAdjustmentEventPayload
+  | SurrenderVoteEventPayload
+  | LobbyChatEventPayload
+  | RunePageEventPayload
+  | ActiveRunesEventPayload
+  | ResourceEventPayload
+  | ResourceChangedEventPayload
+  | GameMetadataEventPayload
+  | SkinSelectedEventPayload
+  | QueueEventPayload
+  | FriendPresenceEventPayload
+  | GameflowSessionEventPayload
+  | PickTimerWarningEventPayload
+  | KillDetailEventPayload
+  | SpecialKillEventPayload
   | PhaseEventPayload
   | HeartbeatEventPayload
+  | DiagnosticEventPayload
   | CustomEventPayload;
 
 export interface Event<T extends EventPayload = EventPayload> extends Timestamped {
+  /** Globally unique event id (UUID); absent for events that were never published. */
+  id?: string;
+  /** Position in the daemon's event sequence; used to resume after reconnects. */
+  seq?: number;
   kind: EventKind;
+  source?: EventSource;
+  /** Game the event belongs to (UUID), new for every game; absent before the first one. */
+  gameId?: string;
+  /** Set on operator corrections issued through the Control RPC. */
+  manual?: boolean;
+  /** Delivered well after it was due (backfill, resume, lag); skip animating it. */
+  stale?: boolean;
+  /** Base64 ed25519 signature, verifiable with the key from GetServerInfo. */
+  signature?: string;
   payload: T;
 }
@@ -0,0 +1,636 @@
+//! Bounded history behind [`EventBus`](crate::EventBus) backfill and delayed subscriptions.
+//!
+//! The newest events stay in memory. With a spill directory configured, events beyond the
+//! memory budget are written to JSON-lines segment files instead of being dropped, so long
+//! spectator delays and post-match backfills can reach further back than memory allows.
+//!
+//! The backlog lives behind the bus lock, so it never touches the disk itself: a writer thread
+//! writes and deletes segment files, and reads return the segments they need so the caller can
+//! load them once the lock is released.
+
+use crate::bus::EventFilter;
+use anyhow::{Context, Result};
+use levents_model::{Event, Uuid};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
+use tokio::time::Instant;
+use tracing::warn;
+
+#[derive(Debug)]
+pub(crate) struct Backlog {
+    memory: VecDeque<(Instant, Event)>,
+    /// Total events retained, in memory and on disk.
+    capacity: usize,
+    memory_budget: usize,
+    spill: Option<Spill>,
+    /// Highest sequence number no longer retained, either evicted or lost to a failed spill.
+    evicted_through: u64,
+}
+
+/// Where a new subscription starts in the history.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Start {
+    /// Replay up to this many matching events from before the cursor.
+    Backfill(usize),
+    /// Replay every matching event with a higher sequence number.
+    After(u64),
+}
+
+/// Events selected for a new subscription.
+pub(crate) struct Window {
+    /// Events before the cursor with the instant they were published, delivered immediately
+    /// (oldest first).
+    pub(crate) replay: VecDeque<(Instant, Event)>,
+    /// Events after the cursor with the instant they were published (oldest first).
+    pub(crate) held: VecDeque<(Instant, Event)>,
+}
+
+/// A [`Window`] taken under the bus lock, still missing the spilled events it reaches.
+pub(crate) struct PendingWindow {
+    window: Window,
+    start: Start,
+    cursor: Option<Instant>,
+    /// Segments still to visit, newest first.
+    spilled: Vec<Arc<Segment>>,
+}
+
+/// The most recent events, still missing the spilled ones.
+pub(crate) struct Recent {
+    /// In-memory events, newest first.
+    events: Vec<Event>,
+    count: usize,
+    /// Segments to read, newest first.
+    spilled: Vec<Arc<Segment>>,
+}
+
+/// Retained events a predicate accepts, still missing the spilled ones.
+pub(crate) struct Matching<F> {
+    keep: F,
+    memory: Vec<Event>,
+    /// Segments to read, oldest first.
+    spilled: Vec<Arc<Segment>>,
+}
+
+impl Backlog {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            memory: VecDeque::with_capacity(capacity),
+            capacity,
+            memory_budget: capacity,
+            spill: None,
+            evicted_through: 0,
+        }
+    }
+
+    /// Keep at most `memory_budget` events in memory and spill older ones under `dir`.
+    pub(crate) fn with_spill(
+        capacity: usize,
+        memory_budget: usize,
+        dir: impl Into<PathBuf>,
+    ) -> Result<Self> {
+        let memory_budget = memory_budget.clamp(1, capacity.max(1));
+        let dir = dir
+            .into()
+            .join(format!("levents-backlog-{}", Uuid::new_v4()));
+        fs::create_dir_all(&dir)
+            .with_context(|| format!("failed to create spill directory {}", dir.display()))?;
+        let (jobs, queue) = mpsc::channel::<Job>();
+        let lost_through = Arc::new(AtomicU64::new(0));
+        let writer = thread::Builder::new()
+            .name("levents-spill".to_string())
+            .spawn({
+                let lost_through = lost_through.clone();
+                move || {
+                    for job in queue {
+                        job.run(&lost_through);
+                    }
+                }
+            })
+            .context("failed to start the backlog spill writer")?;
+        Ok(Self {
+            memory: VecDeque::with_capacity(memory_budget),
+            capacity,
+            memory_budget,
+            spill: Some(Spill {
+                dir,
+                epoch: Instant::now(),
+                segments: VecDeque::new(),
+                next_segment: 0,
+                jobs: Some(jobs),
+                writer: Some(writer),
+                lost_through,
+            }),
+            evicted_through: 0,
+        })
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.memory.len() + self.spill.as_ref().map_or(0, Spill::len)
+    }
+
+    pub(crate) fn in_memory(&self) -> usize {
+        self.memory.len()
+    }
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Treat every event up to `seq` as evicted, for a history continued from an earlier run.
+    pub(crate) fn start_after(&mut self, seq: u64) {
+        self.evicted_through = self.evicted_through.max(seq);
+    }
+
+    /// Whether events after `seq` may have been dropped from the history.
+    pub(crate) fn evicted_after(&self, seq: u64) -> bool {
+        let lost = self
+            .spill
+            .as_ref()
+            .map_or(0, |spill| spill.lost_through.load(Ordering::Relaxed));
+        seq < self.evicted_through.max(lost)
+    }
+
+    pub(crate) fn push(&mut self, published: Instant, event: Event) {
+        if self.capacity == 0 {
+            self.evicted_through = event.seq;
+            return;
+        }
+        self.memory.push_back((published, event));
+
+        if self.memory.len() > self.memory_budget {
+            match &mut self.spill {
+                Some(spill) => {
+                    // Spill half the budget at once so segments stay reasonably large.
+                    let count = (self.memory_budget / 2).max(1);
+                    let segment: Vec<_> = self.memory.drain(..count).collect();
+                    spill.write(segment);
+                }
+                None => self.pop_memory(),
+            }
+        }
+        while self.len() > self.capacity {
+            match self.spill.as_mut().and_then(Spill::pop_oldest) {
+                Some(last_seq) => self.evicted_through = self.evicted_through.max(last_seq),
+                None => self.pop_memory(),
+            }
+        }
+    }
+
+    fn pop_memory(&mut self) {
+        if let Some((_, event)) = self.memory.pop_front() {
+            self.evicted_through = self.evicted_through.max(event.seq);
+        }
+    }
+
+    /// Most recent events (oldest first), up to `count`.
+    pub(crate) fn recent(&self, count: usize) -> Recent {
+        let events: Vec<Event> = self
+            .memory
+            .iter()
+            .rev()
+            .take(count)
+            .map(|(_, event)| event.clone())
+            .collect();
+        let spilled = match &self.spill {
+            Some(spill) if events.len() < count => spill.segments.iter().rev().cloned().collect(),
+            _ => Vec::new(),
+        };
+        Recent {
+            events,
+            count,
+            spilled,
+        }
+    }
+
+    /// Every retained event `keep` accepts (oldest first), spilled ones included.
+    pub(crate) fn matching<F: FnMut(&Event) -> bool>(&self, mut keep: F) -> Matching<F> {
+        let memory = self
+            .memory
+            .iter()
+            .map(|(_, event)| event)
+            .filter(|event| keep(event))
+            .cloned()
+            .collect();
+        Matching {
+            keep,
+            memory,
+            spilled: self.spilled(),
+        }
+    }
+
+    /// Split the history at `now - delay`: matching events before the cursor are replayed
+    /// according to `start`, every matching event after it is held.
+    pub(crate) fn window(
+        &self,
+        filter: &EventFilter,
+        start: Start,
+        delay: Duration,
+    ) -> PendingWindow {
+        let mut pending = PendingWindow {
+            window: Window {
+                replay: VecDeque::new(),
+                held: VecDeque::new(),
+            },
+            start,
+            cursor: Instant::now().checked_sub(delay),
+            spilled: Vec::new(),
+        };
+        if pending.visit(filter, self.memory.iter().rev().cloned()) {
+            pending.spilled = self.spilled().into_iter().rev().collect();
+        }
+        pending
+    }
+
+    /// Spilled segments, oldest first.
+    fn spilled(&self) -> Vec<Arc<Segment>> {
+        self.spill
+            .as_ref()
+            .map_or_else(Vec::new, |spill| spill.segments.iter().cloned().collect())
+    }
+}
+
+impl PendingWindow {
+    /// Read the spilled events the window reaches; call it without holding the bus lock.
+    pub(crate) fn load(mut self, filter: &EventFilter) -> Window {
+        for segment in std::mem::take(&mut self.spilled) {
+            if !self.visit(filter, segment.read().into_iter().rev()) {
+                break;
+            }
+        }
+        self.window
+    }
+
+    /// Walks events newest to oldest; returns whether older events may still be needed.
+    fn visit(
+        &mut self,
+        filter: &EventFilter,
+        events: impl Iterator<Item = (Instant, Event)>,
+    ) -> bool {
+        let Window { replay, held } = &mut self.window;
+        for (published, event) in events {
+            if let Start::After(seq) = self.start {
+                if event.seq <= seq {
+                    return false;
+                }
+            }
+            if self.cursor.map_or(true, |cursor| published > cursor) {
+                if filter.matches(&event) {
+                    held.push_front((published, event));
+                }
+            } else if matches!(self.start, Start::Backfill(backfill) if replay.len() >= backfill) {
+                return false;
+            } else if filter.matches(&event) {
+                replay.push_front((published, event));
+            }
+        }
+        true
+    }
+}
+
+impl Recent {
+    /// Read the spilled events still missing; call it without holding the bus lock.
+    pub(crate) fn load(mut self) -> Vec<Event> {
+        for segment in &self.spilled {
+            if self.events.len() >= self.count {
+                break;
+            }
+            let missing = self.count - self.events.len();
+            let loaded = segment.read();
+            self.events.extend(
+                loaded
+                    .into_iter()
+                    .rev()
+                    .take(missing)
+                    .map(|(_, event)| event),
+            );
+        }
+        self.events.reverse();
+        self.events
+    }
+}
+
+impl<F: FnMut(&Event) -> bool> Matching<F> {
+    /// Read and filter the spilled events; call it without holding the bus lock.
+    pub(crate) fn load(mut self) -> Vec<Event> {
+        let mut events = Vec::new();
+        for segment in &self.spilled {
+            events.extend(
+                segment
+                    .read()
+                    .into_iter()
+                    .map(|(_, event)| event)
+                    .filter(|event| (self.keep)(event)),
+            );
+        }
+        events.append(&mut self.memory);
+        events
+    }
+}
+
+#[derive(Debug)]
+struct Spill {
+    dir: PathBuf,
+    /// Reference point for the publish instants stored on disk.
+    epoch: Instant,
+    segments: VecDeque<Arc<Segment>>,
+    next_segment: u64,
+    /// Queue of the writer thread; `None` once the spill is dropped.
+    jobs: Option<mpsc::Sender<Job>>,
+    writer: Option<thread::JoinHandle<()>>,
+    /// Last sequence number of the newest segment the writer failed to write.
+    lost_through: Arc<AtomicU64>,
+}
+
+#[derive(Debug)]
+struct Segment {
+    path: PathBuf,
+    len: usize,
+    last_seq: u64,
+    epoch: Instant,
+    state: Mutex<SegmentState>,
+}
+
+#[derive(Debug)]
+enum SegmentState {
+    /// Waiting for the writer; readers use the events directly.
+    Pending(Vec<(Instant, Event)>),
+    Written,
+    /// The file could not be written and the events are lost.
+    Failed,
+}
+
+/// Disk work for the writer thread, done in order.
+enum Job {
+    Write(Arc<Segment>),
+    /// The segment left the backlog; its file goes once no reader holds it any more.
+    Evict(Arc<Segment>),
+}
+
+#[derive(Serialize, Deserialize)]
+struct SpilledEvent<E> {
+    /// Milliseconds between the spill epoch and publication.
+    at_ms: u64,
+    event: E,
+}
+
+impl Spill {
+    fn len(&self) -> usize {
+        self.segments.iter().map(|segment| segment.len).sum()
+    }
+
+    /// Queue `events` to be written as a new segment.
+    fn write(&mut self, events: Vec<(Instant, Event)>) {
+        let segment = Arc::new(Segment {
+            path: self.dir.join(format!("{:08}.jsonl", self.next_segment)),
+            len: events.len(),
+            last_seq: events.last().map_or(0, |(_, event)| event.seq),
+            epoch: self.epoch,
+            state: Mutex::new(SegmentState::Pending(events)),
+        });
+        self.next_segment += 1;
+        self.segments.push_back(segment.clone());
+        self.submit(Job::Write(segment));
+    }
+
+    /// Drop the oldest segment and return its last sequence number.
+    fn pop_oldest(&mut self) -> Option<u64> {
+        let segment = self.segments.pop_front()?;
+        let last_seq = segment.last_seq;
+        self.submit(Job::Evict(segment));
+        Some(last_seq)
+    }
+
+    fn submit(&self, job: Job) {
+        let Some(jobs) = &self.jobs else {
+            return;
+        };
+        if let Err(mpsc::SendError(job)) = jobs.send(job) {
+            warn!("backlog spill writer is gone; writing on the publishing thread");
+            job.run(&self.lost_through);
+        }
+    }
+}
+
+impl Drop for Spill {
+    fn drop(&mut self) {
+        self.jobs = None;
+        if let Some(writer) = self.writer.take() {
+            let _ = writer.join();
+        }
+        self.segments.clear();
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+impl Job {
+    fn run(self, lost_through: &AtomicU64) {
+        match self {
+            Job::Write(segment) => {
+                if !segment.write() {
+                    lost_through.fetch_max(segment.last_seq, Ordering::Relaxed);
+                }
+            }
+            Job::Evict(segment) => drop(segment),
+        }
+    }
+}
+
+impl Segment {
+    /// Write the pending events to the segment file; returns whether they made it to disk.
+    fn write(&self) -> bool {
+        let mut state = self.state.lock();
+        let SegmentState::Pending(events) = &*state else {
+            return true;
+        };
+        let result = fs::File::create(&self.path)
+            .map_err(anyhow::Error::from)
+            .and_then(|file| {
+                let mut writer = BufWriter::new(file);
+                for (published, event) in events {
+                    let at_ms = published.saturating_duration_since(self.epoch).as_millis() as u64;
+                    serde_json::to_writer(&mut writer, &SpilledEvent { at_ms, event })?;
+                    writer.write_all(b"\n")?;
+                }
+                writer.flush()?;
+                Ok(())
+            });
+
+        match result {
+            Ok(()) => {
+                *state = SegmentState::Written;
+                true
+            }
+            Err(error) => {
+                warn!(?error, path = %self.path.display(), "failed to spill backlog segment; dropping it");
+                let _ = fs::remove_file(&self.path);
+                *state = SegmentState::Failed;
+                false
+            }
+        }
+    }
+
+    /// The segment's events, oldest first, from memory while the writer has yet to get to it.
+    fn read(&self) -> Vec<(Instant, Event)> {
+        let state = self.state.lock();
+        match &*state {
+            SegmentState::Pending(events) => return events.clone(),
+            SegmentState::Failed => return Vec::new(),
+            SegmentState::Written => {}
+        }
+        let file = match fs::File::open(&self.path) {
+            Ok(file) => file,
+            Err(error) => {
+                warn!(?error, path = %self.path.display(), "failed to read backlog segment");
+                return Vec::new();
+            }
+        };
+        let mut events = Vec::with_capacity(self.len);
+        for (index, line) in BufReader::new(file).lines().enumerate() {
+            let line = match line {
+                Ok(line) => line,
+                Err(error) => {
+                    warn!(?error, path = %self.path.display(), "failed to read backlog segment");
+                    break;
+                }
+            };
+            match serde_json::from_str::<SpilledEvent<Event>>(&line) {
+                Ok(spilled) => {
+                    let published = self.epoch + Duration::from_millis(spilled.at_ms);
+                    events.push((published, spilled.event));
+                }
+                Err(error) => {
+                    warn!(?error, path = %self.path.display(), line = index + 1, "skipping undecodable spilled event");
+                }
+            }
+        }
+        events
+    }
+}
+
+impl Drop for Segment {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use levents_model::{EventKind, EventPayload, HeartbeatEvent};
+
+    fn heartbeat(seq: u64) -> Event {
+        let mut event = Event::new(
+            EventKind::Heartbeat,
+            seq,
+            EventPayload::Heartbeat(HeartbeatEvent {
+                seq,
+                ..Default::default()
+            }),
+        );
+        event.seq = seq;
+        event
+    }
+
+    #[test]
+    fn spilled_events_remain_available_for_backfill() {
+        let root = std::env::temp_dir();
+        let mut backlog = Backlog::with_spill(10, 4, &root).expect("spill dir");
+        let dir = backlog
+            .spill
+            .as_ref()
+            .map(|spill| spill.dir.clone())
+            .unwrap();
+        for seq in 1..=12 {
+            backlog.push(Instant::now(), heartbeat(seq));
+        }
+
+        assert!(backlog.memory.len() <= 4);
+        assert!(backlog.len() <= 10);
+        let recent: Vec<u64> = backlog
+            .recent(8)
+            .load()
+            .iter()
+            .map(|event| event.ts)
+            .collect();
+        assert_eq!(recent, (5..=12).collect::<Vec<_>>());
+
+        let filter = EventFilter::all();
+        let window = backlog
+            .window(&filter, Start::Backfill(6), Duration::ZERO)
+            .load(&filter);
+        let replay: Vec<u64> = window.replay.iter().map(|(_, event)| event.ts).collect();
+        assert_eq!(replay, (7..=12).collect::<Vec<_>>());
+
+        // Resuming reaches into spilled segments and knows what was evicted.
+        let window = backlog
+            .window(&filter, Start::After(4), Duration::ZERO)
+            .load(&filter);
+        let replay: Vec<u64> = window.replay.iter().map(|(_, event)| event.seq).collect();
+        assert_eq!(replay, (5..=12).collect::<Vec<_>>());
+        assert!(backlog.evicted_after(1));
+        assert!(!backlog.evicted_after(4));
+
+        drop(backlog);
+        assert!(!dir.exists());
+    }
+
+    /// Wait for the writer thread to get through every queued segment.
+    fn settle(backlog: &Backlog) {
+        let spill = backlog.spill.as_ref().unwrap();
+        while spill
+            .segments
+            .iter()
+            .any(|segment| matches!(*segment.state.lock(), SegmentState::Pending(_)))
+        {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn undecodable_spilled_lines_are_skipped() {
+        let mut backlog = Backlog::with_spill(10, 2, std::env::temp_dir()).expect("spill dir");
+        for seq in 1..=3 {
+            backlog.push(Instant::now(), heartbeat(seq));
+        }
+        settle(&backlog);
+
+        let path = backlog.spill.as_ref().unwrap().segments[0].path.clone();
+        let written = fs::read_to_string(&path).unwrap();
+        fs::write(&path, format!("not json\n{written}")).unwrap();
+        let recent: Vec<u64> = backlog
+            .recent(3)
+            .load()
+            .iter()
+            .map(|event| event.seq)
+            .collect();
+        assert_eq!(recent, [1, 2, 3]);
+    }
+
+    #[test]
+    fn failed_spills_count_as_evicted() {
+        let mut backlog = Backlog::with_spill(10, 2, std::env::temp_dir()).expect("spill dir");
+        fs::remove_dir_all(&backlog.spill.as_ref().unwrap().dir).unwrap();
+        for seq in 1..=3 {
+            backlog.push(Instant::now(), heartbeat(seq));
+        }
+        settle(&backlog);
+
+        assert!(backlog.evicted_after(0));
+        assert!(!backlog.evicted_after(1));
+        let recent: Vec<u64> = backlog
+            .recent(3)
+            .load()
+            .iter()
+            .map(|event| event.seq)
+            .collect();
+        assert_eq!(recent, [2, 3]);
+    }
+}
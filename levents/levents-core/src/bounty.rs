@@ -0,0 +1,139 @@
+//! Kill streak / death streak bounty estimation.
+//!
+//! The Live Client does not expose bounties, so they are rebuilt from the kill feed using the
+//! published base-kill and shutdown tables. Assist and objective bounties are not modelled.
+
+use levents_model::{BountyEvent, Event, EventKind, EventPayload, PlayerRef};
+use std::collections::HashMap;
+
+/// Gold for killing a player who is not on a kill streak, indexed by their death streak.
+const BASE_KILL_GOLD: [u32; 7] = [300, 300, 274, 220, 176, 140, 112];
+
+/// Shutdown bonus on top of the base kill gold, indexed by the victim's kill streak.
+const SHUTDOWN_GOLD: [u32; 8] = [0, 0, 150, 200, 300, 400, 500, 700];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Streak {
+    kills: u32,
+    deaths: u32,
+}
+
+impl Streak {
+    fn bounty(&self) -> u32 {
+        let base = BASE_KILL_GOLD[(self.deaths as usize).min(BASE_KILL_GOLD.len() - 1)];
+        let shutdown = SHUTDOWN_GOLD[(self.kills as usize).min(SHUTDOWN_GOLD.len() - 1)];
+        base + shutdown
+    }
+}
+
+/// Per-player streaks for the current game.
+#[derive(Debug, Default)]
+pub(crate) struct BountyTracker {
+    streaks: HashMap<String, Streak>,
+}
+
+impl BountyTracker {
+    /// Current bounty on `name`'s head.
+    pub(crate) fn bounty(&self, name: &str) -> u32 {
+        self.streaks.get(name).copied().unwrap_or_default().bounty()
+    }
+
+    /// Apply a champion kill and return `BountyUpdated` events for players whose bounty changed.
+    ///
+    /// `killer` is `None` when the victim was executed by a turret, minion or monster.
+    pub(crate) fn record_kill(
+        &mut self,
+        killer: Option<&PlayerRef>,
+        victim: &PlayerRef,
+        ts_ms: u64,
+    ) -> Vec<Event> {
+        let mut events = Vec::new();
+
+        if let Some(killer) = killer {
+            self.update(killer, ts_ms, &mut events, |streak| {
+                streak.kills += 1;
+                streak.deaths = 0;
+            });
+        }
+        self.update(victim, ts_ms, &mut events, |streak| {
+            streak.kills = 0;
+            streak.deaths += 1;
+        });
+
+        events
+    }
+
+    fn update(
+        &mut self,
+        player: &PlayerRef,
+        ts_ms: u64,
+        events: &mut Vec<Event>,
+        apply: impl FnOnce(&mut Streak),
+    ) {
+        let streak = self
+            .streaks
+            .entry(player.summoner_name.clone())
+            .or_default();
+        let before = streak.bounty();
+        apply(streak);
+
+        if streak.bounty() != before {
+            events.push(Event::new(
+                EventKind::BountyUpdated,
+                ts_ms,
+                EventPayload::PlayerBounty(BountyEvent {
+                    player: player.clone(),
+                    bounty: streak.bounty(),
+                    kill_streak: streak.kills,
+                    death_streak: streak.deaths,
+                }),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use levents_model::Team;
+
+    #[test]
+    fn kill_streaks_raise_bounty_until_shut_down() {
+        let mut tracker = BountyTracker::default();
+        let carry = PlayerRef::new("Carry", Team::Order, 0);
+        let feeder = PlayerRef::new("Feeder", Team::Chaos, 5);
+
+        // First kill does not change the carry's bounty yet.
+        tracker.record_kill(Some(&carry), &feeder, 1_000);
+        assert_eq!(tracker.bounty("Carry"), 300);
+
+        let events = tracker.record_kill(Some(&carry), &feeder, 2_000);
+        assert_eq!(tracker.bounty("Carry"), 450);
+        assert_eq!(tracker.bounty("Feeder"), 274);
+        assert_eq!(events.len(), 2);
+        assert!(matches!(
+            &events[0].payload,
+            EventPayload::PlayerBounty(BountyEvent {
+                bounty: 450,
+                kill_streak: 2,
+                ..
+            })
+        ));
+
+        tracker.record_kill(Some(&feeder), &carry, 3_000);
+        assert_eq!(tracker.bounty("Carry"), 300);
+        assert_eq!(tracker.bounty("Feeder"), 300);
+    }
+
+    #[test]
+    fn executions_only_update_the_victim() {
+        let mut tracker = BountyTracker::default();
+        let victim = PlayerRef::new("Victim", Team::Order, 1);
+
+        tracker.record_kill(None, &victim, 1_000);
+        let events = tracker.record_kill(None, &victim, 2_000);
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(tracker.bounty("Victim"), 274);
+    }
+}
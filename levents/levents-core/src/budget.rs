@@ -0,0 +1,139 @@
+//! Caps on the in-memory stores that grow with what the game reports.
+//!
+//! A spectate session can run for hours, across many games and client versions. Every store
+//! fed by Live Client payloads is capped so a misbehaving client cannot make the daemon grow
+//! without bound; entries beyond a cap are evicted and counted, and a periodic self-check logs
+//! stores that are close to their cap.
+
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// Share of a cap at which the self-check starts warning.
+const NEAR_BUDGET: f64 = 0.9;
+
+/// Limits on the daemon's growable in-memory stores.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryBudget {
+    /// Players tracked from the playerlist; extra entries are ignored.
+    pub max_players: usize,
+    /// Inventory slots tracked per player; items in higher slots are ignored.
+    pub max_items_per_player: usize,
+    /// Distinct unknown Live Client fields counted by [`crate::UnknownFieldStats`].
+    pub max_unknown_fields: usize,
+}
+
+impl Default for MemoryBudget {
+    fn default() -> Self {
+        Self {
+            // Arena lobbies hold 16 players; anything beyond twice that is a broken payload.
+            max_players: 32,
+            max_items_per_player: 16,
+            max_unknown_fields: 256,
+        }
+    }
+}
+
+/// Current size of the daemon's capped stores and how much was evicted to keep them there.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// Players currently tracked.
+    pub players: usize,
+    /// Inventory entries currently tracked, over all players.
+    pub items: usize,
+    /// Distinct unknown fields currently counted.
+    pub unknown_fields: usize,
+    /// Backfill events currently held in memory.
+    pub backfill_events: usize,
+    /// Playerlist entries and items ignored because their store was full.
+    pub evicted_entries: u64,
+    /// Unknown fields not counted because the field counters were full.
+    pub evicted_unknown_fields: u64,
+}
+
+impl MemoryUsage {
+    /// Stores filled to at least 90% of their cap, as `(name, len, cap)`.
+    pub fn near_budget(
+        &self,
+        budget: &MemoryBudget,
+        backfill_budget: usize,
+    ) -> Vec<(&'static str, usize, usize)> {
+        let max_items = budget
+            .max_items_per_player
+            .saturating_mul(budget.max_players);
+        [
+            ("players", self.players, budget.max_players),
+            ("items", self.items, max_items),
+            (
+                "unknownFields",
+                self.unknown_fields,
+                budget.max_unknown_fields,
+            ),
+            ("backfill", self.backfill_events, backfill_budget),
+        ]
+        .into_iter()
+        .filter(|&(_, len, cap)| cap > 0 && len as f64 >= cap as f64 * NEAR_BUDGET)
+        .collect()
+    }
+}
+
+/// Sizes reported by the Live Client poller, shared with the daemon handle.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RegistryMeter {
+    inner: Arc<MeterInner>,
+}
+
+#[derive(Debug, Default)]
+struct MeterInner {
+    players: AtomicUsize,
+    items: AtomicUsize,
+    evicted: AtomicU64,
+}
+
+impl RegistryMeter {
+    #[cfg_attr(not(feature = "live-client"), allow(dead_code))]
+    pub(crate) fn set(&self, players: usize, items: usize) {
+        self.inner.players.store(players, Ordering::Relaxed);
+        self.inner.items.store(items, Ordering::Relaxed);
+    }
+
+    #[cfg_attr(not(feature = "live-client"), allow(dead_code))]
+    pub(crate) fn evicted(&self, count: usize) {
+        self.inner
+            .evicted
+            .fetch_add(count as u64, Ordering::Relaxed);
+    }
+
+    /// `(players, items, evicted)` as last reported.
+    pub(crate) fn read(&self) -> (usize, usize, u64) {
+        (
+            self.inner.players.load(Ordering::Relaxed),
+            self.inner.items.load(Ordering::Relaxed),
+            self.inner.evicted.load(Ordering::Relaxed),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn near_budget_reports_stores_at_ninety_percent() {
+        let budget = MemoryBudget {
+            max_players: 10,
+            max_items_per_player: 2,
+            max_unknown_fields: 100,
+        };
+        let usage = MemoryUsage {
+            players: 9,
+            items: 4,
+            unknown_fields: 100,
+            backfill_events: 10,
+            ..MemoryUsage::default()
+        };
+        assert_eq!(
+            usage.near_budget(&budget, 0),
+            vec![("players", 9, 10), ("unknownFields", 100, 100)]
+        );
+    }
+}
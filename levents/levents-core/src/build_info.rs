@@ -0,0 +1,13 @@
+//! Version and build metadata, embedded by the build script.
+
+/// Crate version.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Abbreviated git revision the daemon was built from; `unknown` outside a checkout.
+pub const GIT_HASH: &str = env!("LEVENTS_GIT_HASH");
+/// UTC build date (`YYYY-MM-DD`); honours `SOURCE_DATE_EPOCH` for reproducible builds.
+pub const BUILD_DATE: &str = env!("LEVENTS_BUILD_DATE");
+
+/// `VERSION+GIT_HASH`, as reported in heartbeats.
+pub fn version() -> String {
+    format!("{VERSION}+{GIT_HASH}")
+}
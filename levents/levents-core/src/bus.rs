@@ -0,0 +1,819 @@
+//! Event fan-out shared by every frontend (gRPC, library consumers, future transports).
+
+use crate::backlog::{Backlog, Start, Window};
+use crate::query::Query;
+use anyhow::Result;
+use futures_core::Stream;
+use futures_util::StreamExt;
+use levents_model::{Event, EventBatch, EventKind, EventPayload, Uuid};
+use parking_lot::Mutex;
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast::{
+    self,
+    error::{RecvError, TryRecvError},
+};
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tokio::time::{sleep_until, Instant};
+use tracing::{trace, warn};
+
+pub(crate) const CHANNEL_CAPACITY: usize = 256;
+pub(crate) const BACKFILL_CAPACITY: usize = 256;
+
+/// Id of the running game, set by the source that detects games and stamped onto published
+/// events.
+pub(crate) type GameHandle = Arc<Mutex<Option<Uuid>>>;
+
+/// Selects which event kinds a subscriber receives.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventFilter {
+    kinds: Option<HashSet<EventKind>>,
+}
+
+impl EventFilter {
+    /// Accept every event.
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    /// Accept only the given kinds; an empty list accepts everything.
+    pub fn kinds(kinds: impl IntoIterator<Item = EventKind>) -> Self {
+        let kinds: HashSet<EventKind> = kinds.into_iter().collect();
+        Self {
+            kinds: (!kinds.is_empty()).then_some(kinds),
+        }
+    }
+
+    /// Whether `event` passes the filter.
+    pub fn matches(&self, event: &Event) -> bool {
+        self.kinds
+            .as_ref()
+            .map_or(true, |kinds| kinds.contains(&event.kind))
+    }
+}
+
+/// Cloneable handle that broadcasts events to any number of subscribers and keeps a bounded
+/// backlog of recent events for late joiners.
+#[derive(Debug, Clone)]
+pub struct EventBus {
+    inner: Arc<BusInner>,
+}
+
+#[derive(Debug)]
+struct BusInner {
+    /// Live events with the instant they were published.
+    sender: broadcast::Sender<(Instant, Event)>,
+    /// Recent events with the instant they were published.
+    backlog: Mutex<Backlog>,
+    /// Identifies this bus in resume tokens, so tokens from an earlier daemon run are rejected.
+    session: Uuid,
+    /// Sequence number of the last published event.
+    seq: AtomicU64,
+    /// Bumped by [`EventBus::seek_to_live`]; delayed subscriptions flush when it changes.
+    live: watch::Sender<u64>,
+    /// Events a delayed subscription holds at most; the oldest are dropped beyond it.
+    held_capacity: usize,
+    /// Events skipped by lagging subscribers; shared with them without keeping the bus alive.
+    dropped: Arc<AtomicU64>,
+    /// Lateness after which delivered events are marked `stale`.
+    stale_after: Option<Duration>,
+    /// Game stamped onto events published without a `game_id`.
+    game: GameHandle,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventBus {
+    /// Create a bus with the default channel and backfill capacities.
+    pub fn new() -> Self {
+        Self::with_capacity(CHANNEL_CAPACITY, BACKFILL_CAPACITY)
+    }
+
+    /// `channel` bounds how far a subscriber may lag; `backfill` bounds the replay backlog.
+    pub fn with_capacity(channel: usize, backfill: usize) -> Self {
+        Self::with_backlog(channel, Backlog::new(backfill))
+    }
+
+    /// Like [`EventBus::with_capacity`], but only the newest `memory_budget` events of the
+    /// backlog stay in memory; older ones are spilled to segment files under `spill_dir`.
+    ///
+    /// Each bus writes to its own subdirectory, removed again when the bus is dropped. Events
+    /// that cannot be written are logged and dropped from the backlog.
+    pub fn with_spill(
+        channel: usize,
+        backfill: usize,
+        memory_budget: usize,
+        spill_dir: impl Into<PathBuf>,
+    ) -> Result<Self> {
+        let backlog = Backlog::with_spill(backfill, memory_budget, spill_dir)?;
+        Ok(Self::with_backlog(channel, backlog))
+    }
+
+    fn with_backlog(channel: usize, backlog: Backlog) -> Self {
+        let (sender, _) = broadcast::channel(channel.max(1));
+        let held_capacity = backlog.capacity().max(channel).max(1);
+        Self {
+            inner: Arc::new(BusInner {
+                sender,
+                backlog: Mutex::new(backlog),
+                session: Uuid::new_v4(),
+                seq: AtomicU64::new(0),
+                live: watch::channel(0).0,
+                held_capacity,
+                dropped: Arc::default(),
+                stale_after: None,
+                game: GameHandle::default(),
+            }),
+        }
+    }
+
+    /// Mark events `stale` when a subscription delivers them more than `threshold` later than
+    /// due, so real-time consumers can tell replayed backfill and catch-up deliveries from fresh
+    /// events.
+    pub(crate) fn with_stale_after(mut self, threshold: Option<Duration>) -> Self {
+        Arc::get_mut(&mut self.inner)
+            .expect("bus is not shared yet")
+            .stale_after = threshold;
+        self
+    }
+
+    /// Stamp published events with the game last stored in `game`.
+    pub(crate) fn with_game(mut self, game: GameHandle) -> Self {
+        Arc::get_mut(&mut self.inner)
+            .expect("bus is not shared yet")
+            .game = game;
+        self
+    }
+
+    /// Take over `session` from an earlier run whose last event was `seq`, so clients can resume
+    /// with their tokens. Only the events published from now on can be replayed.
+    pub(crate) fn continue_session(mut self, session: Uuid, seq: u64) -> Self {
+        let inner = Arc::get_mut(&mut self.inner).expect("bus is not shared yet");
+        inner.session = session;
+        *inner.seq.get_mut() = seq;
+        inner.backlog.get_mut().start_after(seq);
+        self
+    }
+
+    /// Register a new subscriber; it only sees events published after this call.
+    pub fn subscribe(&self, filter: EventFilter) -> Subscription {
+        self.subscribe_with_backfill(filter, 0)
+    }
+
+    /// Register a subscriber that first replays up to `backfill` recent matching events.
+    pub fn subscribe_with_backfill(&self, filter: EventFilter, backfill: usize) -> Subscription {
+        self.subscribe_delayed(filter, backfill, Duration::ZERO)
+    }
+
+    /// Register a subscriber that sees every event `delay` after it was published, e.g. to line
+    /// an overlay up with a delayed spectator feed.
+    ///
+    /// The subscriber starts `delay` in the past: events published within that window are
+    /// replayed on schedule, and up to `backfill` matching events before it are replayed
+    /// immediately. Events still held are released early by [`EventBus::seek_to_live`].
+    ///
+    /// At most as many events as the backfill or the channel holds, whichever is larger, wait
+    /// for their delay; beyond that the oldest are dropped and counted as lag.
+    pub fn subscribe_delayed(
+        &self,
+        filter: EventFilter,
+        backfill: usize,
+        delay: Duration,
+    ) -> Subscription {
+        // Holding the backlog lock keeps `publish` from slipping an event between the replay
+        // snapshot and the live receiver; spilled events are read once it is released.
+        let (window, receiver) = {
+            let backlog = self.inner.backlog.lock();
+            let window = backlog.window(&filter, Start::Backfill(backfill), delay);
+            (window, self.inner.sender.subscribe())
+        };
+        self.subscription(window.load(&filter), receiver, filter, delay)
+    }
+
+    /// Register a subscriber that continues right after the event `token` was issued for, so a
+    /// reconnecting client neither misses nor repeats events. Delays apply as in
+    /// [`EventBus::subscribe_delayed`].
+    ///
+    /// Fails when the token comes from another bus (e.g. before a daemon restart without a
+    /// state file) or when events after it have already left the backlog.
+    pub fn subscribe_resumed(
+        &self,
+        filter: EventFilter,
+        token: ResumeToken,
+        delay: Duration,
+    ) -> Result<Subscription, ResumeError> {
+        let (window, receiver) = {
+            let backlog = self.inner.backlog.lock();
+            if token.session != self.inner.session
+                || token.seq > self.inner.seq.load(Ordering::SeqCst)
+            {
+                return Err(ResumeError::UnknownSession);
+            }
+            if backlog.evicted_after(token.seq) {
+                return Err(ResumeError::Expired);
+            }
+            let window = backlog.window(&filter, Start::After(token.seq), delay);
+            (window, self.inner.sender.subscribe())
+        };
+        Ok(self.subscription(window.load(&filter), receiver, filter, delay))
+    }
+
+    /// Build a subscription from a history window and a receiver taken with it under the
+    /// backlog lock.
+    fn subscription(
+        &self,
+        window: Window,
+        receiver: broadcast::Receiver<(Instant, Event)>,
+        filter: EventFilter,
+        delay: Duration,
+    ) -> Subscription {
+        let Window { replay, held } = window;
+        let due = |(published, event)| (published + delay, event);
+
+        Subscription {
+            backlog: replay.into_iter().map(due).collect(),
+            receiver,
+            filter,
+            delay,
+            held: held.into_iter().map(due).collect(),
+            held_capacity: self.inner.held_capacity,
+            live: (!delay.is_zero()).then(|| self.inner.live.subscribe()),
+            closed: false,
+            dropped: self.inner.dropped.clone(),
+            lagged: 0,
+            stale_after: self.inner.stale_after,
+        }
+    }
+
+    /// Token that resumes a subscription right after `event`, which must come from this bus.
+    pub fn resume_token(&self, event: &Event) -> ResumeToken {
+        ResumeToken {
+            session: self.inner.session,
+            seq: event.seq,
+        }
+    }
+
+    /// Sequence number of the last published event.
+    pub(crate) fn last_seq(&self) -> u64 {
+        self.inner.seq.load(Ordering::SeqCst)
+    }
+
+    /// Identifies this bus instance in resume tokens.
+    pub fn session(&self) -> Uuid {
+        self.inner.session
+    }
+
+    /// Game that events are currently stamped with; `None` before the first detected game.
+    pub fn game_id(&self) -> Option<Uuid> {
+        *self.inner.game.lock()
+    }
+
+    /// Release every event held by delayed subscriptions now, e.g. when a broadcast returns to
+    /// live during an intermission. Later events are delayed as before.
+    pub fn seek_to_live(&self) {
+        self.inner.live.send_modify(|generation| *generation += 1);
+    }
+
+    /// Most recent events (oldest first), up to `count`.
+    pub fn recent(&self, count: usize) -> Vec<Event> {
+        let recent = self.inner.backlog.lock().recent(count);
+        recent.load()
+    }
+
+    /// Like [`EventBus::recent`], but gives up instead of blocking when the backlog is locked,
+    /// e.g. from a panic hook that may run while this thread holds the lock.
+    pub fn try_recent(&self, count: usize) -> Option<Vec<Event>> {
+        let recent = self.inner.backlog.try_lock()?.recent(count);
+        Some(recent.load())
+    }
+
+    /// Search the retained history, e.g. `bus.events().kind(EventKind::Kill).collect()`.
+    pub fn events(&self) -> Query {
+        Query::new(self.clone())
+    }
+
+    /// Every retained event `keep` accepts, oldest first.
+    pub(crate) fn retained(&self, keep: impl FnMut(&Event) -> bool) -> Vec<Event> {
+        let matching = self.inner.backlog.lock().matching(keep);
+        matching.load()
+    }
+
+    /// Backfill events currently held in memory, excluding any spilled to disk.
+    pub fn backfill_in_memory(&self) -> usize {
+        self.inner.backlog.lock().in_memory()
+    }
+
+    /// Deliver one event to every current subscriber and record it for backfill.
+    ///
+    /// Events without an id get a fresh v4 UUID here, so every event seen by a subscriber is
+    /// uniquely identifiable. Every event also gets the next sequence number of this bus,
+    /// which heartbeats repeat in their payload.
+    pub fn publish(&self, mut event: Event) {
+        if event.id.is_nil() {
+            event.id = Uuid::new_v4();
+        }
+        if event.game_id.is_none() {
+            event.game_id = *self.inner.game.lock();
+        }
+        // Numbered under the backlog lock so subscribers and the backlog see one order.
+        let mut backlog = self.inner.backlog.lock();
+        event.seq = self.inner.seq.fetch_add(1, Ordering::SeqCst) + 1;
+        if let EventPayload::Heartbeat(heartbeat) = &mut event.payload {
+            heartbeat.seq = event.seq;
+        }
+        let published = Instant::now();
+        // Heartbeats only matter live; retaining them would crowd real events out of backfill.
+        if event.kind != EventKind::Heartbeat {
+            backlog.push(published, event.clone());
+        }
+        if self.inner.sender.send((published, event)).is_err() {
+            trace!("no active subscribers; dropping event");
+        }
+    }
+
+    /// Deliver every event of `batch` in order.
+    pub fn publish_batch(&self, batch: EventBatch) {
+        for event in batch.events {
+            self.publish(event);
+        }
+    }
+
+    /// Forward every batch produced by `stream` into the bus on a background task.
+    pub fn spawn_source<S>(&self, stream: S) -> JoinHandle<()>
+    where
+        S: Stream<Item = Result<EventBatch>> + Send + 'static,
+    {
+        let bus = self.clone();
+        tokio::spawn(async move {
+            let mut stream = Box::pin(stream);
+            while let Some(result) = stream.next().await {
+                match result {
+                    Ok(batch) => bus.publish_batch(batch),
+                    Err(error) => warn!(?error, "event source error"),
+                }
+            }
+        })
+    }
+
+    /// Events skipped so far by subscribers that fell more than the channel capacity behind,
+    /// summed over all of them.
+    pub fn dropped_events(&self) -> u64 {
+        self.inner.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Number of live subscriptions.
+    pub fn subscriber_count(&self) -> usize {
+        self.inner.sender.receiver_count()
+    }
+}
+
+/// Position in a bus's event sequence that a subscription can resume after.
+///
+/// Rendered as `<session>:<seq>`; clients keep the session from the subscription and the
+/// `seq` of the last event they handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResumeToken {
+    /// Bus the sequence number belongs to.
+    pub session: Uuid,
+    /// Last sequence number the client received.
+    pub seq: u64,
+}
+
+impl fmt::Display for ResumeToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.session, self.seq)
+    }
+}
+
+impl FromStr for ResumeToken {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let (session, seq) = value
+            .split_once(':')
+            .ok_or_else(|| anyhow::anyhow!("resume token must be `<session>:<seq>`"))?;
+        Ok(Self {
+            session: session.parse()?,
+            seq: seq.parse()?,
+        })
+    }
+}
+
+/// Why [`EventBus::subscribe_resumed`] could not continue a subscription.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ResumeError {
+    /// The token was not issued by this bus, e.g. it predates a daemon restart.
+    UnknownSession,
+    /// Events after the token are no longer retained.
+    Expired,
+}
+
+impl fmt::Display for ResumeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownSession => f.write_str("resume token belongs to another session"),
+            Self::Expired => f.write_str("events after the resume token are no longer retained"),
+        }
+    }
+}
+
+impl std::error::Error for ResumeError {}
+
+/// Receiving side of [`EventBus::subscribe`].
+#[derive(Debug)]
+pub struct Subscription {
+    /// Replayed events with the instant they were due.
+    backlog: VecDeque<(Instant, Event)>,
+    receiver: broadcast::Receiver<(Instant, Event)>,
+    filter: EventFilter,
+    delay: Duration,
+    /// Delayed events with the instant they become due.
+    held: VecDeque<(Instant, Event)>,
+    held_capacity: usize,
+    live: Option<watch::Receiver<u64>>,
+    closed: bool,
+    dropped: Arc<AtomicU64>,
+    /// Events this subscription skipped by falling behind.
+    lagged: u64,
+    stale_after: Option<Duration>,
+}
+
+impl Subscription {
+    /// Wait for the next matching event; returns `None` once the bus is gone.
+    ///
+    /// Replayed events come first. Subscribers that fall more than the channel capacity behind
+    /// skip the oldest live events; delayed subscriptions keep draining the channel while they
+    /// wait, so only the consumer's own pace counts.
+    pub async fn recv(&mut self) -> Option<Event> {
+        if let Some((due, event)) = self.backlog.pop_front() {
+            return Some(self.deliver(due, event));
+        }
+        loop {
+            let due = self.held.front().map(|(due, _)| *due);
+            if due.is_some_and(|due| due <= Instant::now()) {
+                return self
+                    .held
+                    .pop_front()
+                    .map(|(due, event)| self.deliver(due, event));
+            }
+            if self.closed && due.is_none() {
+                return None;
+            }
+
+            tokio::select! {
+                received = self.receiver.recv(), if !self.closed => match received {
+                    Ok((published, event)) if self.filter.matches(&event) => {
+                        if self.delay.is_zero() {
+                            return Some(self.deliver(published, event));
+                        }
+                        self.hold(published + self.delay, event);
+                    }
+                    Ok(_) => {}
+                    Err(RecvError::Lagged(skipped)) => self.record_lag(skipped),
+                    Err(RecvError::Closed) => self.closed = true,
+                },
+                _ = sleep_until(due.unwrap_or_else(Instant::now)), if due.is_some() => {}
+                changed = wait_for_live(&mut self.live) => {
+                    if changed {
+                        self.release_held();
+                    } else {
+                        self.live = None;
+                    }
+                }
+            }
+        }
+    }
+
+    /// The next matching event if one is ready now, without waiting; lets a consumer drain a
+    /// burst after [`Subscription::recv`] returns.
+    pub fn try_recv(&mut self) -> Option<Event> {
+        if let Some((due, event)) = self.backlog.pop_front() {
+            return Some(self.deliver(due, event));
+        }
+        loop {
+            if self
+                .held
+                .front()
+                .is_some_and(|(due, _)| *due <= Instant::now())
+            {
+                return self
+                    .held
+                    .pop_front()
+                    .map(|(due, event)| self.deliver(due, event));
+            }
+            if self.closed {
+                return None;
+            }
+            match self.receiver.try_recv() {
+                Ok((published, event)) if self.filter.matches(&event) => {
+                    if self.delay.is_zero() {
+                        return Some(self.deliver(published, event));
+                    }
+                    self.hold(published + self.delay, event);
+                }
+                Ok(_) => {}
+                Err(TryRecvError::Lagged(skipped)) => self.record_lag(skipped),
+                Err(TryRecvError::Empty) => return None,
+                Err(TryRecvError::Closed) => self.closed = true,
+            }
+        }
+    }
+
+    /// Events this subscription skipped so far because it fell more than the channel capacity
+    /// behind.
+    pub fn lagged(&self) -> u64 {
+        self.lagged
+    }
+
+    fn record_lag(&mut self, skipped: u64) {
+        warn!(skipped, "subscriber lagged; dropping events");
+        self.dropped.fetch_add(skipped, Ordering::Relaxed);
+        self.lagged += skipped;
+    }
+
+    /// Hold `event` until `due`, dropping the oldest held event when the subscription is full.
+    fn hold(&mut self, due: Instant, event: Event) {
+        if self.held.len() >= self.held_capacity {
+            self.held.pop_front();
+            self.record_lag(1);
+        }
+        self.held.push_back((due, event));
+    }
+
+    /// Stamp an event that was due at `due` as it is handed to the consumer.
+    fn deliver(&self, due: Instant, mut event: Event) -> Event {
+        if let Some(threshold) = self.stale_after {
+            event.stale = due.elapsed() > threshold;
+        }
+        event
+    }
+
+    /// Make every event published so far due immediately.
+    fn release_held(&mut self) {
+        let now = Instant::now();
+        for (due, _) in &mut self.held {
+            *due = now;
+        }
+        loop {
+            match self.receiver.try_recv() {
+                Ok((_, event)) if self.filter.matches(&event) => self.hold(now, event),
+                Ok(_) => {}
+                Err(TryRecvError::Lagged(skipped)) => self.record_lag(skipped),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Closed) => {
+                    self.closed = true;
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Adapt the subscription into a `Stream` of matching events.
+    pub fn into_stream(mut self) -> impl Stream<Item = Event> + Send + 'static {
+        async_stream::stream! {
+            while let Some(event) = self.recv().await {
+                yield event;
+            }
+        }
+    }
+}
+
+/// Resolves with `true` on a seek-to-live request and `false` once the bus is gone; never
+/// resolves for undelayed subscriptions.
+async fn wait_for_live(live: &mut Option<watch::Receiver<u64>>) -> bool {
+    match live {
+        Some(live) => live.changed().await.is_ok(),
+        None => std::future::pending().await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use levents_model::{EventPayload, HeartbeatEvent, PhaseEvent};
+
+    fn phase(ts: u64) -> Event {
+        Event::new(
+            EventKind::PhaseChange,
+            ts,
+            EventPayload::Phase(PhaseEvent {
+                phase: format!("phase {ts}"),
+            }),
+        )
+    }
+
+    /// Published events carry fresh ids, so compare on the timestamp instead.
+    fn ts(event: Option<Event>) -> Option<u64> {
+        event.map(|event| event.ts)
+    }
+
+    #[tokio::test]
+    async fn subscribers_receive_filtered_copies() {
+        let bus = EventBus::new();
+        let mut everything = bus.subscribe(EventFilter::all());
+        let mut kills = bus.clone().subscribe(EventFilter::kinds([EventKind::Kill]));
+        assert_eq!(bus.subscriber_count(), 2);
+
+        bus.publish(phase(1));
+        let mut kill = phase(2);
+        kill.kind = EventKind::Kill;
+        bus.publish(kill);
+        drop(bus);
+
+        assert_eq!(ts(everything.recv().await), Some(1));
+        assert_eq!(ts(everything.recv().await), Some(2));
+        assert_eq!(everything.recv().await, None);
+        assert_eq!(ts(kills.recv().await), Some(2));
+        assert_eq!(kills.recv().await, None);
+    }
+
+    #[tokio::test]
+    async fn try_recv_drains_without_waiting() {
+        let bus = EventBus::with_capacity(8, 1);
+        bus.publish(phase(1));
+        let mut events = bus.subscribe_with_backfill(EventFilter::all(), 1);
+        bus.publish(phase(2));
+
+        assert_eq!(ts(events.try_recv()), Some(1));
+        assert_eq!(ts(events.try_recv()), Some(2));
+        assert_eq!(ts(events.try_recv()), None);
+        bus.publish(phase(3));
+        assert_eq!(ts(events.recv().await), Some(3));
+    }
+
+    #[tokio::test]
+    async fn late_subscribers_replay_the_backlog() {
+        let bus = EventBus::with_capacity(8, 2);
+        for seq in 1..=3 {
+            bus.publish(phase(seq));
+        }
+        let recent: Vec<_> = bus.recent(5).into_iter().map(|event| event.ts).collect();
+        assert_eq!(recent, vec![2, 3]);
+
+        // Heartbeats reach live subscribers but never displace backfill.
+        let heartbeat = Event::new(
+            EventKind::Heartbeat,
+            99,
+            EventPayload::Heartbeat(HeartbeatEvent::default()),
+        );
+        bus.publish(heartbeat);
+        let mut late = bus.subscribe_with_backfill(EventFilter::all(), 5);
+        bus.publish(phase(4));
+
+        for seq in 2..=4 {
+            assert_eq!(ts(late.recv().await), Some(seq));
+        }
+    }
+
+    #[tokio::test]
+    async fn heartbeats_carry_their_own_sequence_number() {
+        let bus = EventBus::new();
+        let mut events = bus.subscribe(EventFilter::all());
+        bus.publish(phase(1));
+        bus.publish(Event::new(
+            EventKind::Heartbeat,
+            2,
+            EventPayload::Heartbeat(HeartbeatEvent::default()),
+        ));
+
+        events.recv().await.unwrap();
+        let heartbeat = events.recv().await.unwrap();
+        assert_eq!(heartbeat.seq, 2);
+        assert!(matches!(
+            heartbeat.payload,
+            EventPayload::Heartbeat(HeartbeatEvent { seq: 2, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn late_deliveries_are_marked_stale() {
+        let threshold = Duration::from_millis(30);
+        let bus = EventBus::new().with_stale_after(Some(threshold));
+        let mut live = bus.subscribe(EventFilter::all());
+        let mut delayed = bus.subscribe_delayed(EventFilter::all(), 0, Duration::from_millis(50));
+        bus.publish(phase(1));
+        assert!(!live.recv().await.unwrap().stale);
+
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        let mut late = bus.subscribe_with_backfill(EventFilter::all(), 1);
+        assert!(late.recv().await.unwrap().stale);
+        // Held for its delay and delivered on time, so not stale.
+        assert!(!delayed.recv().await.unwrap().stale);
+    }
+
+    #[tokio::test]
+    async fn publish_assigns_unique_ids() {
+        let bus = EventBus::new();
+        let mut events = bus.subscribe(EventFilter::all());
+
+        let mut preset = phase(3);
+        preset.id = Uuid::from_u128(7);
+        bus.publish(phase(1));
+        bus.publish(phase(2));
+        bus.publish(preset);
+
+        let first = events.recv().await.unwrap().id;
+        let second = events.recv().await.unwrap().id;
+        assert!(!first.is_nil());
+        assert_ne!(first, second);
+        assert_eq!(events.recv().await.unwrap().id, Uuid::from_u128(7));
+    }
+
+    #[tokio::test]
+    async fn resumed_subscriptions_continue_after_the_token() {
+        let bus = EventBus::with_capacity(8, 3);
+        let mut first = bus.subscribe(EventFilter::all());
+        for seq in 1..=3 {
+            bus.publish(phase(seq));
+        }
+        let handled = first.recv().await.unwrap();
+        assert_eq!(handled.seq, 1);
+        let token: ResumeToken = bus.resume_token(&handled).to_string().parse().unwrap();
+        drop(first);
+
+        bus.publish(phase(4));
+        let mut resumed = bus
+            .subscribe_resumed(EventFilter::all(), token, Duration::ZERO)
+            .unwrap();
+        bus.publish(phase(5));
+        for seq in 2..=5 {
+            assert_eq!(ts(resumed.recv().await), Some(seq));
+        }
+
+        // Event 2 has left the three-event backlog by now.
+        bus.publish(phase(6));
+        let resume = |token| bus.subscribe_resumed(EventFilter::all(), token, Duration::ZERO);
+        assert_eq!(resume(token).err(), Some(ResumeError::Expired));
+        let foreign = ResumeToken {
+            session: Uuid::from_u128(1),
+            ..token
+        };
+        assert_eq!(resume(foreign).err(), Some(ResumeError::UnknownSession));
+    }
+
+    #[tokio::test]
+    async fn delayed_subscribers_lag_behind_and_can_seek_to_live() {
+        let bus = EventBus::new();
+        let delay = Duration::from_millis(60);
+        bus.publish(phase(1));
+        let mut delayed = bus.subscribe_delayed(EventFilter::all(), 0, delay);
+        let start = Instant::now();
+        bus.publish(phase(2));
+
+        // The event published just before subscribing is still inside the delay window.
+        assert_eq!(ts(delayed.recv().await), Some(1));
+        assert_eq!(ts(delayed.recv().await), Some(2));
+        assert!(start.elapsed() >= Duration::from_millis(55));
+
+        bus.publish(phase(3));
+        bus.seek_to_live();
+        let start = Instant::now();
+        assert_eq!(ts(delayed.recv().await), Some(3));
+        assert!(start.elapsed() < delay);
+    }
+
+    #[tokio::test]
+    async fn delayed_subscribers_hold_a_bounded_number_of_events() {
+        let bus = EventBus::with_capacity(8, 4);
+        let mut delayed = bus.subscribe_delayed(EventFilter::all(), 0, Duration::from_secs(3600));
+        for ts in 1..=6 {
+            bus.publish(phase(ts));
+        }
+        assert_eq!(delayed.try_recv(), None);
+        for ts in 7..=12 {
+            bus.publish(phase(ts));
+        }
+        assert_eq!(delayed.try_recv(), None);
+        assert_eq!(delayed.lagged(), 4);
+
+        bus.seek_to_live();
+        for ts in 5..=12 {
+            assert_eq!(self::ts(delayed.recv().await), Some(ts));
+        }
+    }
+
+    #[tokio::test]
+    async fn live_and_delayed_cursors_are_independent() {
+        let bus = EventBus::new();
+        let mut coach = bus.subscribe(EventFilter::all());
+        let mut caster = bus.subscribe_delayed(EventFilter::all(), 0, Duration::from_millis(40));
+        let start = Instant::now();
+        bus.publish(phase(1));
+
+        assert_eq!(ts(coach.recv().await), Some(1));
+        assert!(start.elapsed() < Duration::from_millis(40));
+        assert_eq!(ts(caster.recv().await), Some(1));
+        assert!(start.elapsed() >= Duration::from_millis(40));
+    }
+}
@@ -0,0 +1,104 @@
+//! Lobby and champ select chat reported by the League Client.
+//!
+//! Only group conversations of a lobby or champ select are read; direct messages and
+//! post-game rooms never become events. Nothing is read unless
+//! [`crate::DaemonConfig::lcu_lobby_chat`] is set, since chat is private to the players.
+
+use levents_model::{Event, EventKind, EventPayload, LobbyChatEvent};
+use serde::Deserialize;
+
+const CONVERSATIONS_URI_PREFIX: &str = "/lol-chat/v1/conversations/";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ChatMessage {
+    body: String,
+    #[serde(default)]
+    from_id: String,
+    #[serde(rename = "type", default)]
+    message_type: String,
+    #[serde(default)]
+    is_historical: bool,
+}
+
+/// The lobby or champ select room a conversation id belongs to, if any.
+fn channel(conversation: &str) -> Option<&'static str> {
+    // Ids are `<room>@<service>.<region>.pvp.net`, with the `@` percent-encoded in URIs.
+    let service = conversation
+        .split_once('@')
+        .or_else(|| conversation.split_once("%40"))?
+        .1;
+    if service.starts_with("champ-select.") {
+        Some("championSelect")
+    } else if service.starts_with("sec.") {
+        Some("lobby")
+    } else {
+        None
+    }
+}
+
+/// A `LobbyChat` event for a message created in a lobby or champ select conversation.
+pub(crate) fn chat_event(
+    uri: &str,
+    event_type: &str,
+    data: &serde_json::Value,
+    ts: u64,
+) -> Option<Event> {
+    let (conversation, message) = uri
+        .strip_prefix(CONVERSATIONS_URI_PREFIX)?
+        .split_once("/messages/")?;
+    if event_type != "Create" || message.is_empty() {
+        return None;
+    }
+    let channel = channel(conversation)?;
+    let message = ChatMessage::deserialize(data).ok()?;
+    // System lines ("X joined the lobby") and replayed history are not new chat.
+    if message.is_historical || !matches!(message.message_type.as_str(), "groupchat" | "chat") {
+        return None;
+    }
+    Some(Event::new(
+        EventKind::LobbyChat,
+        ts,
+        EventPayload::LobbyChat(LobbyChatEvent {
+            channel: channel.to_string(),
+            sender: message.from_id,
+            body: message.body,
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn only_new_lobby_and_champ_select_messages_become_events() {
+        let message = |message_type: &str| {
+            json!({
+                "body": "ready up",
+                "fromId": "3f2a9c1d",
+                "type": message_type,
+                "isHistorical": false,
+            })
+        };
+        let champ_select = "/lol-chat/v1/conversations/a1b2%40champ-select.eu1.pvp.net/messages/7";
+
+        let event = chat_event(champ_select, "Create", &message("groupchat"), 1).unwrap();
+        assert_eq!(
+            event.payload,
+            EventPayload::LobbyChat(LobbyChatEvent {
+                channel: "championSelect".to_string(),
+                sender: "3f2a9c1d".to_string(),
+                body: "ready up".to_string(),
+            })
+        );
+
+        let lobby = "/lol-chat/v1/conversations/c3d4@sec.eu1.pvp.net/messages/8";
+        assert!(chat_event(lobby, "Create", &message("chat"), 1).is_some());
+        assert!(chat_event(champ_select, "Update", &message("groupchat"), 1).is_none());
+        assert!(chat_event(champ_select, "Create", &message("system"), 1).is_none());
+        let direct = "/lol-chat/v1/conversations/e5f6@eu1.pvp.net/messages/9";
+        assert!(chat_event(direct, "Create", &message("chat"), 1).is_none());
+    }
+}
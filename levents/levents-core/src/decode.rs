@@ -0,0 +1,247 @@
+//! Deserialization policy for Live Client payloads and telemetry for fields we do not model.
+
+use crate::budget::MemoryBudget;
+use parking_lot::Mutex;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+/// How strictly Live Client payloads are decoded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DeserializeMode {
+    /// Ignore unknown fields after recording them (production default).
+    #[default]
+    Lenient,
+    /// Reject any payload carrying fields the deserializers do not model (used in tests).
+    Strict,
+}
+
+/// Shared counters of unknown fields observed per payload, keyed by payload name then field.
+///
+/// At most `max_fields` distinct fields are counted; fields first seen after that are only
+/// tallied in [`UnknownFieldStats::evicted`].
+#[derive(Debug, Clone)]
+pub struct UnknownFieldStats {
+    inner: Arc<Mutex<FieldCounts>>,
+    max_fields: usize,
+}
+
+#[derive(Debug, Default)]
+struct FieldCounts {
+    counts: HashMap<&'static str, HashMap<String, u64>>,
+    fields: usize,
+    evicted: u64,
+}
+
+impl Default for UnknownFieldStats {
+    fn default() -> Self {
+        Self::with_limit(MemoryBudget::default().max_unknown_fields)
+    }
+}
+
+impl UnknownFieldStats {
+    /// Count at most `max_fields` distinct fields over all payloads.
+    pub fn with_limit(max_fields: usize) -> Self {
+        Self {
+            inner: Arc::default(),
+            max_fields,
+        }
+    }
+
+    /// Record the unknown fields of one payload instance.
+    #[cfg_attr(not(feature = "live-client"), allow(dead_code))]
+    pub(crate) fn record<'a>(
+        &self,
+        payload: &'static str,
+        fields: impl Iterator<Item = &'a String>,
+    ) {
+        let mut guard = self.inner.lock();
+        let FieldCounts {
+            counts,
+            fields: distinct,
+            evicted,
+        } = &mut *guard;
+        let counts = counts.entry(payload).or_default();
+        for field in fields {
+            match counts.get_mut(field) {
+                Some(count) => *count += 1,
+                None if *distinct >= self.max_fields => {
+                    if *evicted == 0 {
+                        warn!(
+                            max_fields = self.max_fields,
+                            "unknown field counters are full; new fields are no longer counted"
+                        );
+                    }
+                    *evicted += 1;
+                }
+                None => {
+                    debug!(payload, field = %field, "unknown field in live client payload");
+                    counts.insert(field.clone(), 1);
+                    *distinct += 1;
+                }
+            }
+        }
+    }
+
+    /// Number of distinct fields counted.
+    pub fn len(&self) -> usize {
+        self.inner.lock().fields
+    }
+
+    /// Whether no unknown field has been counted.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Occurrences of fields that were not counted because the counters were full.
+    pub fn evicted(&self) -> u64 {
+        self.inner.lock().evicted
+    }
+
+    /// Number of times `field` was seen on `payload`.
+    pub fn count(&self, payload: &str, field: &str) -> u64 {
+        self.inner
+            .lock()
+            .counts
+            .get(payload)
+            .and_then(|counts| counts.get(field))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Return a sorted copy of all counters.
+    pub fn snapshot(&self) -> BTreeMap<String, BTreeMap<String, u64>> {
+        self.inner
+            .lock()
+            .counts
+            .iter()
+            .map(|(payload, counts)| {
+                let counts = counts
+                    .iter()
+                    .map(|(field, count)| (field.clone(), *count))
+                    .collect();
+                (payload.to_string(), counts)
+            })
+            .collect()
+    }
+}
+
+/// Record the unknown fields captured for `payload` and reject them in strict mode.
+///
+/// Fields listed in `ignored` are known to the poller but deliberately left unmodelled, so
+/// they are neither counted nor rejected.
+#[cfg_attr(not(feature = "live-client"), allow(dead_code))]
+pub(crate) fn audit_unknown_fields(
+    mode: DeserializeMode,
+    stats: &UnknownFieldStats,
+    payload: &'static str,
+    ignored: &[&str],
+    unknown: &HashMap<String, Value>,
+) -> anyhow::Result<()> {
+    let mut fields: Vec<&String> = unknown
+        .keys()
+        .filter(|field| !ignored.contains(&field.as_str()))
+        .collect();
+    if fields.is_empty() {
+        return Ok(());
+    }
+
+    stats.record(payload, fields.iter().copied());
+
+    if mode == DeserializeMode::Strict {
+        fields.sort_unstable();
+        let fields: Vec<&str> = fields.into_iter().map(String::as_str).collect();
+        anyhow::bail!("unknown fields in {payload}: {}", fields.join(", "));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn unknown(fields: &[&str]) -> HashMap<String, Value> {
+        fields
+            .iter()
+            .map(|field| (field.to_string(), json!(null)))
+            .collect()
+    }
+
+    #[test]
+    fn lenient_mode_counts_unknown_fields() {
+        let stats = UnknownFieldStats::default();
+        let fields = unknown(&["championName", "skinID"]);
+
+        for _ in 0..2 {
+            audit_unknown_fields(DeserializeMode::Lenient, &stats, "playerlist", &[], &fields)
+                .expect("lenient");
+        }
+
+        assert_eq!(stats.count("playerlist", "championName"), 2);
+        assert_eq!(stats.count("eventdata", "championName"), 0);
+        assert_eq!(stats.snapshot()["playerlist"].len(), 2);
+    }
+
+    #[test]
+    fn strict_mode_rejects_unknown_fields() {
+        let stats = UnknownFieldStats::default();
+        let error = audit_unknown_fields(
+            DeserializeMode::Strict,
+            &stats,
+            "eventdata",
+            &[],
+            &unknown(&["Recipient"]),
+        )
+        .expect_err("strict");
+
+        assert!(error.to_string().contains("Recipient"));
+        assert_eq!(stats.count("eventdata", "Recipient"), 1);
+    }
+
+    #[test]
+    fn ignored_fields_are_neither_counted_nor_rejected() {
+        let stats = UnknownFieldStats::default();
+        audit_unknown_fields(
+            DeserializeMode::Strict,
+            &stats,
+            "activeplayer",
+            &["currentGold", "teamRelativeColors"],
+            &unknown(&["currentGold", "teamRelativeColors"]),
+        )
+        .expect("strict");
+
+        assert!(stats.snapshot().is_empty());
+    }
+
+    #[test]
+    fn fields_beyond_the_limit_are_evicted() {
+        let stats = UnknownFieldStats::with_limit(2);
+        audit_unknown_fields(
+            DeserializeMode::Lenient,
+            &stats,
+            "playerlist",
+            &[],
+            &unknown(&["a", "b", "c"]),
+        )
+        .expect("lenient");
+        audit_unknown_fields(
+            DeserializeMode::Lenient,
+            &stats,
+            "eventdata",
+            &[],
+            &unknown(&["d"]),
+        )
+        .expect("lenient");
+
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats.evicted(), 2);
+        assert!(stats
+            .snapshot()
+            .get("eventdata")
+            .map_or(true, BTreeMap::is_empty));
+    }
+}
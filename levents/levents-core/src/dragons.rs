@@ -0,0 +1,183 @@
+//! Dragon soul and elder buff tracking.
+//!
+//! Seasonal changes to the dragon pit are expressed as a [`DragonRules`] value so the state
+//! machine itself does not need to change when Riot rebalances souls or elder.
+
+use levents_model::{DragonEvent, Event, EventKind, EventPayload, Team};
+use tracing::debug;
+
+/// Dragon pit rules for one season.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct DragonRules {
+    pub version: &'static str,
+    /// Elemental dragons a team needs for its soul.
+    pub soul_threshold: u32,
+    /// The rift transforms after this many elemental dragons and the next one fixes the soul type.
+    pub soul_decided_after: u32,
+    pub elder_buff_ms: u64,
+}
+
+pub(crate) const SEASON_2024: DragonRules = DragonRules {
+    version: "2024",
+    soul_threshold: 4,
+    soul_decided_after: 2,
+    elder_buff_ms: 150_000,
+};
+
+/// Rules applied by the live poller.
+pub(crate) const CURRENT_RULES: DragonRules = SEASON_2024;
+
+const ELDER: &str = "Elder";
+
+/// Dragon state for the current game.
+#[derive(Debug)]
+pub(crate) struct DragonTracker {
+    rules: DragonRules,
+    order_points: u32,
+    chaos_points: u32,
+    soul_type: Option<String>,
+    soul_owner: Option<Team>,
+}
+
+impl Default for DragonTracker {
+    fn default() -> Self {
+        Self::new(CURRENT_RULES)
+    }
+}
+
+impl DragonTracker {
+    pub(crate) fn new(rules: DragonRules) -> Self {
+        Self {
+            rules,
+            order_points: 0,
+            chaos_points: 0,
+            soul_type: None,
+            soul_owner: None,
+        }
+    }
+
+    /// Apply a dragon kill by `team` and return the resulting soul/elder events.
+    pub(crate) fn record(&mut self, team: Team, dragon_type: &str, ts_ms: u64) -> Vec<Event> {
+        if dragon_type.eq_ignore_ascii_case(ELDER) {
+            let Some(points) = self.points_mut(&team).map(|points| *points) else {
+                return Vec::new();
+            };
+            let expires_at = ts_ms + self.rules.elder_buff_ms;
+            return vec![self.event(
+                EventKind::ElderBuff,
+                ts_ms,
+                team,
+                dragon_type,
+                points,
+                Some(expires_at),
+            )];
+        }
+
+        if self.soul_owner.is_some() {
+            // Elemental dragons stop spawning once a soul is taken.
+            return Vec::new();
+        }
+
+        let taken_before = self.order_points + self.chaos_points;
+        let Some(points) = self.points_mut(&team) else {
+            return Vec::new();
+        };
+        *points += 1;
+        let points = *points;
+
+        if taken_before == self.rules.soul_decided_after && self.soul_type.is_none() {
+            self.soul_type = Some(dragon_type.to_string());
+        }
+
+        let mut events = vec![self.event(
+            EventKind::SoulPoint,
+            ts_ms,
+            team.clone(),
+            dragon_type,
+            points,
+            None,
+        )];
+        if points >= self.rules.soul_threshold {
+            debug!(rules = self.rules.version, ?team, soul = ?self.soul_type, "dragon soul taken");
+            self.soul_owner = Some(team.clone());
+            events.push(self.event(EventKind::SoulTaken, ts_ms, team, dragon_type, points, None));
+        }
+        events
+    }
+
+    fn points_mut(&mut self, team: &Team) -> Option<&mut u32> {
+        match team {
+            Team::Order => Some(&mut self.order_points),
+            Team::Chaos => Some(&mut self.chaos_points),
+            Team::Neutral => None,
+        }
+    }
+
+    fn event(
+        &self,
+        kind: EventKind,
+        ts_ms: u64,
+        team: Team,
+        dragon_type: &str,
+        soul_points: u32,
+        expires_at: Option<u64>,
+    ) -> Event {
+        Event::new(
+            kind,
+            ts_ms,
+            EventPayload::Dragon(DragonEvent {
+                team,
+                dragon_type: dragon_type.to_string(),
+                soul_points,
+                soul_type: self.soul_type.clone(),
+                expires_at,
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(events: &[Event]) -> Vec<EventKind> {
+        events.iter().map(|event| event.kind.clone()).collect()
+    }
+
+    #[test]
+    fn fourth_dragon_takes_the_soul_decided_by_the_third() {
+        let mut tracker = DragonTracker::default();
+        tracker.record(Team::Order, "Fire", 300_000);
+        tracker.record(Team::Chaos, "Water", 600_000);
+        tracker.record(Team::Order, "Hextech", 900_000);
+        tracker.record(Team::Order, "Hextech", 1_200_000);
+
+        let events = tracker.record(Team::Order, "Hextech", 1_500_000);
+        assert_eq!(
+            kinds(&events),
+            vec![EventKind::SoulPoint, EventKind::SoulTaken]
+        );
+        let EventPayload::Dragon(soul) = &events[1].payload else {
+            panic!("expected dragon payload");
+        };
+        assert_eq!(soul.team, Team::Order);
+        assert_eq!(soul.soul_points, 4);
+        assert_eq!(soul.soul_type.as_deref(), Some("Hextech"));
+
+        // No further elemental dragons once the soul is taken.
+        assert!(tracker.record(Team::Chaos, "Hextech", 1_800_000).is_empty());
+    }
+
+    #[test]
+    fn elder_kill_reports_buff_expiry() {
+        let mut tracker = DragonTracker::default();
+        let events = tracker.record(Team::Chaos, "Elder", 2_000_000);
+
+        assert_eq!(kinds(&events), vec![EventKind::ElderBuff]);
+        let EventPayload::Dragon(elder) = &events[0].payload else {
+            panic!("expected dragon payload");
+        };
+        assert_eq!(elder.expires_at, Some(2_150_000));
+        assert!(tracker.record(Team::Neutral, "Elder", 2_100_000).is_empty());
+    }
+}
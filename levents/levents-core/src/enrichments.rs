@@ -0,0 +1,99 @@
+//! Switches for the data the daemon adds on top of what the game clients report.
+//!
+//! Some consumers want the raw minimal feed, and every enrichment costs some CPU per poll, so
+//! each one can be turned off on its own. Win probability is switched by
+//! [`crate::DaemonConfig::win_probability_interval`] instead, since it also needs an interval.
+
+use anyhow::{bail, Result};
+use levents_model::{EventBatch, EventPayload};
+
+/// Enrichments applied to Live Client events; all on by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Enrichments {
+    /// Item names on `itemAdded`, `itemRemoved` and `itemMoved` events, as the client reports
+    /// them.
+    pub item_names: bool,
+    /// Lane inference: `laneAssignment` events and `lane` on player references.
+    pub lanes: bool,
+    /// Roster identities on player references; needs a roster to have any effect.
+    pub identities: bool,
+}
+
+impl Default for Enrichments {
+    fn default() -> Self {
+        Self {
+            item_names: true,
+            lanes: true,
+            identities: true,
+        }
+    }
+}
+
+impl Enrichments {
+    /// No enrichment at all.
+    pub fn none() -> Self {
+        Self {
+            item_names: false,
+            lanes: false,
+            identities: false,
+        }
+    }
+
+    /// Parse the enabled enrichments from a comma-separated list, e.g. `lanes,identities`;
+    /// `none` (or an empty list) disables every one.
+    pub fn parse(value: &str) -> Result<Self> {
+        let mut enrichments = Self::none();
+        for name in value
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+        {
+            match name {
+                "none" => {}
+                "itemNames" => enrichments.item_names = true,
+                "lanes" => enrichments.lanes = true,
+                "identities" => enrichments.identities = true,
+                other => bail!(
+                    "unknown enrichment `{other}` (expected itemNames, lanes, identities or none)"
+                ),
+            }
+        }
+        Ok(enrichments)
+    }
+
+    /// Drop item names from `batch` unless they are enabled.
+    #[cfg_attr(not(feature = "live-client"), allow(dead_code))]
+    pub(crate) fn strip(&self, batch: &mut EventBatch) {
+        if self.item_names {
+            return;
+        }
+        for event in &mut batch.events {
+            if let EventPayload::PlayerItem(item) = &mut event.payload {
+                item.item_name = None;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enabled_enrichments_parse() {
+        assert_eq!(
+            Enrichments::parse(" lanes, identities ").unwrap(),
+            Enrichments {
+                item_names: false,
+                lanes: true,
+                identities: true,
+            }
+        );
+        assert_eq!(Enrichments::parse("none").unwrap(), Enrichments::none());
+        assert_eq!(
+            Enrichments::parse("itemNames,lanes,identities").unwrap(),
+            Enrichments::default()
+        );
+        assert!(Enrichments::parse("winProbability").is_err());
+    }
+}
@@ -0,0 +1,99 @@
+//! Per-minute farming rates derived from playerlist creep scores.
+
+use levents_model::{Event, EventKind, EventPayload, FarmEvent, Lane, PlayerRef};
+use std::collections::HashMap;
+
+/// Creep score a jungler collects per minute on an efficient full clear, used as the 100% mark.
+const JUNGLE_CS_PER_MINUTE: f32 = 6.0;
+
+/// Remembers creep scores at the previous sample so each minute can be reported separately.
+#[derive(Debug, Default)]
+pub(crate) struct FarmTracker {
+    last_minute: u64,
+    baseline: HashMap<String, u32>,
+}
+
+impl FarmTracker {
+    /// Emit one `FarmRate` event per player each time the game clock crosses a new minute.
+    pub(crate) fn sample<'a>(
+        &mut self,
+        game_time: f64,
+        players: impl Iterator<Item = (&'a PlayerRef, u32)>,
+        ts_ms: u64,
+    ) -> Vec<Event> {
+        let minute = (game_time.max(0.0) / 60.0) as u64;
+        if minute <= self.last_minute {
+            return Vec::new();
+        }
+        let elapsed_minutes = (minute - self.last_minute) as f32;
+        self.last_minute = minute;
+
+        players
+            .map(|(player, creep_score)| {
+                let previous = self
+                    .baseline
+                    .insert(player.summoner_name.clone(), creep_score)
+                    .unwrap_or(0);
+                let cs_last_minute = creep_score.saturating_sub(previous);
+                let jungle_efficiency = (player.lane == Some(Lane::Jungle))
+                    .then(|| cs_last_minute as f32 / elapsed_minutes / JUNGLE_CS_PER_MINUTE);
+
+                Event::new(
+                    EventKind::FarmRate,
+                    ts_ms,
+                    EventPayload::PlayerFarm(FarmEvent {
+                        player: player.clone(),
+                        creep_score,
+                        cs_per_minute: creep_score as f32 / (game_time / 60.0) as f32,
+                        cs_last_minute,
+                        jungle_efficiency,
+                    }),
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use levents_model::Team;
+
+    fn player(name: &str, slot: u8, lane: Option<Lane>) -> PlayerRef {
+        let mut player = PlayerRef::new(name, Team::Order, slot);
+        player.lane = lane;
+        player
+    }
+
+    fn farm(event: &Event) -> &FarmEvent {
+        match &event.payload {
+            EventPayload::PlayerFarm(farm) => farm,
+            other => panic!("unexpected payload {other:?}"),
+        }
+    }
+
+    #[test]
+    fn samples_once_per_game_minute() {
+        let mut tracker = FarmTracker::default();
+        let laner = player("Laner", 0, Some(Lane::Mid));
+        let jungler = player("Jungler", 1, Some(Lane::Jungle));
+
+        assert!(tracker
+            .sample(45.0, [(&laner, 0), (&jungler, 0)].into_iter(), 0)
+            .is_empty());
+
+        let events = tracker.sample(120.0, [(&laner, 16), (&jungler, 12)].into_iter(), 1);
+        assert_eq!(events.len(), 2);
+        assert_eq!(farm(&events[0]).cs_per_minute, 8.0);
+        assert_eq!(farm(&events[0]).jungle_efficiency, None);
+        assert_eq!(farm(&events[1]).jungle_efficiency, Some(1.0));
+
+        assert!(tracker
+            .sample(150.0, [(&laner, 20), (&jungler, 16)].into_iter(), 2)
+            .is_empty());
+
+        let events = tracker.sample(180.0, [(&laner, 24), (&jungler, 15)].into_iter(), 3);
+        assert_eq!(farm(&events[0]).cs_last_minute, 8);
+        assert_eq!(farm(&events[1]).cs_last_minute, 3);
+    }
+}
@@ -0,0 +1,146 @@
+//! The client's gameflow session: which game the phase changes belong to.
+//!
+//! The Live Client only comes up once the game has loaded, so before that the session is the
+//! only place consumers can learn the queue, map and game id.
+
+use levents_model::{Event, EventKind, EventPayload, GameflowSessionEvent, SessionMember, Team};
+use serde::Deserialize;
+
+pub(crate) const SESSION_URI: &str = "/lol-gameflow/v1/session";
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct Session {
+    phase: String,
+    game_data: GameData,
+    map: Map,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct GameData {
+    game_id: u64,
+    queue: Queue,
+    team_one: Vec<Member>,
+    team_two: Vec<Member>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct Queue {
+    id: i64,
+    #[serde(rename = "type")]
+    queue_type: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct Map {
+    id: u32,
+    name: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct Member {
+    puuid: String,
+    game_name: String,
+    tag_line: String,
+    champion_id: u32,
+}
+
+impl Member {
+    fn into_member(self, team: Team) -> SessionMember {
+        let riot_id = match (self.game_name.is_empty(), self.tag_line.is_empty()) {
+            (true, _) => None,
+            (false, true) => Some(self.game_name),
+            (false, false) => Some(format!("{}#{}", self.game_name, self.tag_line)),
+        };
+        SessionMember {
+            puuid: self.puuid,
+            riot_id,
+            team,
+            champion_id: Some(self.champion_id).filter(|&id| id != 0),
+        }
+    }
+}
+
+/// A `GameflowSession` event for a `/lol-gameflow/v1/session` response.
+pub(crate) fn session_event(data: &serde_json::Value, ts: u64) -> Option<Event> {
+    let session = Session::deserialize(data).ok()?;
+    let game = session.game_data;
+    let members = game
+        .team_one
+        .into_iter()
+        .map(|member| member.into_member(Team::Order))
+        .chain(
+            game.team_two
+                .into_iter()
+                .map(|member| member.into_member(Team::Chaos)),
+        )
+        .collect();
+    Some(Event::new(
+        EventKind::GameflowSession,
+        ts,
+        EventPayload::GameflowSession(GameflowSessionEvent {
+            phase: session.phase,
+            // Zero and -1 stand for "no game" and "no queue" in the client.
+            game_id: Some(game.game_id).filter(|&id| id != 0),
+            queue_id: u32::try_from(game.queue.id).ok().filter(|&id| id != 0),
+            queue_type: Some(game.queue.queue_type).filter(|queue| !queue.is_empty()),
+            map_id: Some(session.map.id).filter(|&id| id != 0),
+            map_name: Some(session.map.name).filter(|name| !name.is_empty()),
+            members,
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn session_carries_queue_map_and_members() {
+        let session = json!({
+            "phase": "ChampSelect",
+            "gameData": {
+                "gameId": 6543210987u64,
+                "queue": { "id": 420, "type": "RANKED_SOLO_5x5" },
+                "teamOne": [{ "puuid": "a1", "gameName": "Faker", "tagLine": "KR1", "championId": 0 }],
+                "teamTwo": [{ "puuid": "b2" }],
+            },
+            "map": { "id": 11, "name": "Summoner's Rift" },
+        });
+        let EventPayload::GameflowSession(session) = session_event(&session, 1).unwrap().payload
+        else {
+            panic!("unexpected payload");
+        };
+        assert_eq!(session.game_id, Some(6543210987));
+        assert_eq!(session.queue_id, Some(420));
+        assert_eq!(session.map_name.as_deref(), Some("Summoner's Rift"));
+        assert_eq!(
+            session.members,
+            vec![
+                SessionMember {
+                    puuid: "a1".to_string(),
+                    riot_id: Some("Faker#KR1".to_string()),
+                    team: Team::Order,
+                    champion_id: None,
+                },
+                SessionMember {
+                    puuid: "b2".to_string(),
+                    riot_id: None,
+                    team: Team::Chaos,
+                    champion_id: None,
+                },
+            ]
+        );
+
+        let lobby = json!({ "phase": "Lobby", "gameData": { "gameId": 0, "queue": { "id": -1 } } });
+        let EventPayload::GameflowSession(lobby) = session_event(&lobby, 2).unwrap().payload else {
+            panic!("unexpected payload");
+        };
+        assert_eq!((lobby.game_id, lobby.queue_id), (None, None));
+    }
+}
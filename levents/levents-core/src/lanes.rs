@@ -0,0 +1,186 @@
+//! Lane assignment heuristics derived from playerlist positions, summoner spells and items.
+
+use levents_model::Lane;
+
+const LANES: [Lane; 5] = [
+    Lane::Top,
+    Lane::Jungle,
+    Lane::Mid,
+    Lane::Bottom,
+    Lane::Support,
+];
+
+/// Support quest items (Spellthief's/World Atlas lines and their upgrades).
+const SUPPORT_ITEMS: [u32; 13] = [
+    3850, 3851, 3853, 3854, 3855, 3857, 3858, 3859, 3860, 3862, 3863, 3864, 3865,
+];
+
+/// Jungle companion items.
+const JUNGLE_ITEMS: [u32; 3] = [1101, 1102, 1103];
+
+/// Signals available for one player when inferring their lane.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct LaneHints {
+    /// Position reported by the client (`TOP`, `JUNGLE`, ...); empty in most queues.
+    pub position: Option<String>,
+    /// Raw summoner spell identifiers such as `SummonerSmite`.
+    pub spells: Vec<String>,
+    pub item_ids: Vec<u32>,
+}
+
+impl LaneHints {
+    fn score(&self, lane: &Lane) -> i32 {
+        let mut score = 0;
+
+        if self.position.as_deref().and_then(parse_position).as_ref() == Some(lane) {
+            score += 10;
+        }
+
+        for spell in &self.spells {
+            score += match (spell_key(spell), lane) {
+                ("SummonerSmite", Lane::Jungle) => 6,
+                ("SummonerTeleport", Lane::Top) => 2,
+                ("SummonerTeleport", Lane::Mid) => 1,
+                ("SummonerHeal", Lane::Bottom) => 2,
+                ("SummonerExhaust", Lane::Support) => 2,
+                ("SummonerDot", Lane::Mid | Lane::Support) => 1,
+                ("SummonerBarrier" | "SummonerBoost", Lane::Bottom | Lane::Mid) => 1,
+                ("SummonerHaste", Lane::Top) => 1,
+                _ => 0,
+            };
+        }
+
+        for item in &self.item_ids {
+            if SUPPORT_ITEMS.contains(item) && *lane == Lane::Support {
+                score += 6;
+            }
+            if JUNGLE_ITEMS.contains(item) && *lane == Lane::Jungle {
+                score += 6;
+            }
+        }
+
+        score
+    }
+}
+
+/// Assign a lane to every player of one team.
+///
+/// Full five-player teams get the highest-scoring one-to-one assignment; other team sizes
+/// (ARAM, customs) fall back to each player's individually best lane.
+pub(crate) fn assign_lanes(team: &[LaneHints]) -> Vec<Lane> {
+    if team.len() != LANES.len() {
+        return team
+            .iter()
+            .map(|hints| {
+                LANES
+                    .iter()
+                    .max_by_key(|lane| hints.score(lane))
+                    .cloned()
+                    .unwrap_or(Lane::Mid)
+            })
+            .collect();
+    }
+
+    let mut best = (i32::MIN, Vec::new());
+    permute(&mut LANES.to_vec(), 0, &mut |order| {
+        let score = team
+            .iter()
+            .zip(order.iter())
+            .map(|(hints, lane)| hints.score(lane))
+            .sum();
+        if score > best.0 {
+            best = (score, order.to_vec());
+        }
+    });
+    best.1
+}
+
+fn permute(lanes: &mut Vec<Lane>, start: usize, visit: &mut impl FnMut(&[Lane])) {
+    if start == lanes.len() {
+        visit(lanes);
+        return;
+    }
+    for index in start..lanes.len() {
+        lanes.swap(start, index);
+        permute(lanes, start + 1, visit);
+        lanes.swap(start, index);
+    }
+}
+
+fn parse_position(position: &str) -> Option<Lane> {
+    match position.to_ascii_uppercase().as_str() {
+        "TOP" => Some(Lane::Top),
+        "JUNGLE" => Some(Lane::Jungle),
+        "MIDDLE" | "MID" => Some(Lane::Mid),
+        "BOTTOM" | "BOT" => Some(Lane::Bottom),
+        "UTILITY" | "SUPPORT" => Some(Lane::Support),
+        _ => None,
+    }
+}
+
+/// Reduce `GeneratedTip_SummonerSpell_SummonerSmite_DisplayName` to `SummonerSmite`.
+fn spell_key(raw: &str) -> &str {
+    raw.split('_')
+        .find(|part| part.starts_with("Summoner") && *part != "SummonerSpell")
+        .unwrap_or(raw)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hints(spells: &[&str], items: &[u32]) -> LaneHints {
+        LaneHints {
+            position: None,
+            spells: spells
+                .iter()
+                .map(|spell| format!("GeneratedTip_SummonerSpell_{spell}_DisplayName"))
+                .collect(),
+            item_ids: items.to_vec(),
+        }
+    }
+
+    #[test]
+    fn assigns_lanes_from_spells_and_items() {
+        let team = vec![
+            hints(&["SummonerFlash", "SummonerHeal"], &[1055]),
+            hints(&["SummonerFlash", "SummonerExhaust"], &[3865]),
+            hints(&["SummonerFlash", "SummonerSmite"], &[1101]),
+            hints(&["SummonerFlash", "SummonerTeleport"], &[1054]),
+            hints(&["SummonerFlash", "SummonerDot"], &[1056]),
+        ];
+
+        assert_eq!(
+            assign_lanes(&team),
+            vec![
+                Lane::Bottom,
+                Lane::Support,
+                Lane::Jungle,
+                Lane::Top,
+                Lane::Mid
+            ]
+        );
+    }
+
+    #[test]
+    fn reported_positions_win_over_spells() {
+        let mut team: Vec<LaneHints> = (0..5).map(|_| hints(&["SummonerSmite"], &[])).collect();
+        for (hints, position) in team
+            .iter_mut()
+            .zip(["UTILITY", "BOTTOM", "MIDDLE", "JUNGLE", "TOP"])
+        {
+            hints.position = Some(position.to_string());
+        }
+
+        assert_eq!(
+            assign_lanes(&team),
+            vec![
+                Lane::Support,
+                Lane::Bottom,
+                Lane::Mid,
+                Lane::Jungle,
+                Lane::Top
+            ]
+        );
+    }
+}
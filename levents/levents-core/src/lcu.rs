@@ -1,19 +1,31 @@
-use super::{DaemonConfig, Event, EventBatch, EventKind, EventPayload, PhaseEvent};
+use super::chat::chat_event;
+use super::gameflow::{session_event, SESSION_URI};
+use super::pick_timer::PickTimer;
+use super::presence::PresenceTracker;
+use super::queue::QueueTracker;
+use super::runes::{champ_select_ended, rune_page_event, CURRENT_PAGE_URI};
+use super::skins::SkinTracker;
+use super::surrender::SurrenderTracker;
+use super::vitals::{Source, Vitals};
+use super::DaemonConfig;
 use anyhow::{anyhow, Context, Result};
 use async_stream::try_stream;
 use base64::{engine::general_purpose::STANDARD, Engine as _};
 use futures_util::{SinkExt, StreamExt};
 use http::header::{AUTHORIZATION, ORIGIN};
+use levents_model::{Event, EventBatch, EventKind, EventPayload, EventSource, PhaseEvent};
 use reqwest::{Client, StatusCode};
 use serde_json::Value;
 use std::collections::HashSet;
 use std::env;
 use std::io::ErrorKind;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tokio::fs;
-use tokio::time::sleep;
+use tokio::sync::broadcast;
+use tokio::time::{sleep, timeout};
 use tokio_tungstenite::tungstenite::client::IntoClientRequest;
 use tokio_tungstenite::tungstenite::Message;
 use tokio_tungstenite::{connect_async_tls_with_config, Connector};
@@ -24,14 +36,28 @@ use rustls::{Certificate, ClientConfig, Error as RustlsError, RootCertStore};
 
 const GAMEFLOW_URI: &str = "/lol-gameflow/v1/gameflow-phase";
 
+/// How long a quiet websocket is awaited before reporting progress to the watchdog.
+const IDLE_BEAT: Duration = Duration::from_secs(5);
+
+/// Object keys whose values are dropped from raw frames; matched case-insensitively as
+/// substrings, so `accessToken` and `Authorization` are covered too.
+const REDACTED_KEYS: [&str; 5] = ["token", "password", "authorization", "cookie", "secret"];
+
+const REDACTED: &str = "<redacted>";
+
 pub(super) fn lcu_event_stream(
     config: DaemonConfig,
     http: Client,
+    vitals: Vitals,
+    auto_accept: Arc<AtomicBool>,
+    raw: Option<broadcast::Sender<LcuRawFrame>>,
 ) -> impl futures_core::Stream<Item = Result<EventBatch>> + Send {
     try_stream! {
         let mut last_phase: Option<String> = None;
+        let mut trackers = Trackers::default();
 
         loop {
+            vitals.beat(Source::Lcu);
             let candidates = lockfile_candidates(&config);
             let (path, auth) = match load_lockfile(&candidates).await {
                 Ok(value) => value,
@@ -53,24 +79,61 @@ pub(super) fn lcu_event_stream(
                         sleep(config.lcu_retry_delay).await;
                         continue;
                     }
+                    vitals.set_connected(Source::Lcu, true);
 
                     if let Ok(Some(phase)) = fetch_current_phase(&http, &auth).await {
                         if last_phase.as_deref() != Some(phase.as_str()) {
-                            let event = phase_event(&phase);
-                            last_phase = Some(phase);
-                            yield EventBatch { events: vec![event] };
+                            accept_ready_check(&http, &auth, &phase, &auto_accept).await;
+                            let previous = last_phase.replace(phase.clone());
+                            let events =
+                                phase_change(&http, &auth, previous.as_deref(), &phase, &mut trackers)
+                                    .await;
+                            yield EventBatch::new(events).with_source(EventSource::Lcu);
                         }
                     }
 
                     loop {
-                        match socket.next().await {
+                        // A quiet client is healthy; only a hung handshake or fetch is a stall.
+                        let wait = trackers.next_wakeup().map_or(IDLE_BEAT, |wakeup| {
+                            wakeup.saturating_duration_since(Instant::now()).min(IDLE_BEAT)
+                        });
+                        let Ok(message) = timeout(wait, socket.next()).await else {
+                            vitals.beat(Source::Lcu);
+                            let events = trackers.tick(timestamp_ms());
+                            if !events.is_empty() {
+                                yield EventBatch::new(events).with_source(EventSource::Lcu);
+                            }
+                            continue;
+                        };
+                        vitals.beat(Source::Lcu);
+                        match message {
                             Some(Ok(Message::Text(text))) => {
+                                if let Some(raw) = raw.as_ref().filter(|raw| raw.receiver_count() > 0) {
+                                    // Only fails once every subscriber is gone.
+                                    let _ = raw.send(LcuRawFrame {
+                                        timestamp_ms: timestamp_ms(),
+                                        text: redact_frame(&text, &auth),
+                                    });
+                                }
                                 if let Some(phase) = parse_phase_message(&text) {
                                     if last_phase.as_deref() != Some(phase.as_str()) {
                                         trace!(phase = %phase, "LCU phase update");
-                                        let event = phase_event(&phase);
-                                        last_phase = Some(phase);
-                                        yield EventBatch { events: vec![event] };
+                                        accept_ready_check(&http, &auth, &phase, &auto_accept).await;
+                                        let previous = last_phase.replace(phase.clone());
+                                        let events = phase_change(
+                                            &http,
+                                            &auth,
+                                            previous.as_deref(),
+                                            &phase,
+                                            &mut trackers,
+                                        )
+                                        .await;
+                                        yield EventBatch::new(events).with_source(EventSource::Lcu);
+                                    }
+                                } else if let Some(update) = parse_api_event(&text) {
+                                    let events = trackers.on_update(&update, &config, timestamp_ms());
+                                    if !events.is_empty() {
+                                        yield EventBatch::new(events).with_source(EventSource::Lcu);
                                     }
                                 }
                             }
@@ -94,6 +157,7 @@ pub(super) fn lcu_event_stream(
                             None => break,
                         }
                     }
+                    vitals.set_connected(Source::Lcu, false);
                 }
                 Err(error) => {
                     warn!(?error, "failed to connect to LCU websocket");
@@ -213,6 +277,73 @@ async fn fetch_current_phase(http: &Client, auth: &LockfileAuth) -> Result<Optio
     Ok(Some(trimmed.trim_matches('"').to_string()))
 }
 
+/// GET a JSON resource from the client; `None` when it does not exist right now.
+async fn fetch_json(http: &Client, auth: &LockfileAuth, uri: &str) -> Result<Option<Value>> {
+    let url = format!("{}{uri}", auth.base_url());
+    let response = http
+        .get(&url)
+        .basic_auth("riot", Some(&auth.password))
+        .send()
+        .await
+        .with_context(|| format!("request failed: GET {url}"))?;
+
+    if response.status() == StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    if !response.status().is_success() {
+        anyhow::bail!("GET {url} -> {}", response.status());
+    }
+    Ok(Some(
+        response
+            .json()
+            .await
+            .with_context(|| format!("decode GET {url}"))?,
+    ))
+}
+
+/// Accept the ready check that `phase` announces, if auto-accept is on.
+async fn accept_ready_check(http: &Client, auth: &LockfileAuth, phase: &str, enabled: &AtomicBool) {
+    if phase != "ReadyCheck" || !enabled.load(Ordering::Relaxed) {
+        return;
+    }
+    let url = format!("{}/lol-matchmaking/v1/ready-check/accept", auth.base_url());
+    let result = http
+        .post(&url)
+        .basic_auth("riot", Some(&auth.password))
+        .send()
+        .await
+        .and_then(|response| response.error_for_status());
+    match result {
+        Ok(_) => debug!("ready check accepted"),
+        Err(error) => warn!(?error, "failed to accept the ready check"),
+    }
+}
+
+/// Events for a gameflow change from `previous` to `phase`.
+async fn phase_change(
+    http: &Client,
+    auth: &LockfileAuth,
+    previous: Option<&str>,
+    phase: &str,
+    trackers: &mut Trackers,
+) -> Vec<Event> {
+    trackers.on_phase(phase);
+    let mut events = vec![phase_event(phase)];
+    match fetch_json(http, auth, SESSION_URI).await {
+        Ok(Some(session)) => events.extend(session_event(&session, timestamp_ms())),
+        Ok(None) => {}
+        Err(error) => debug!(?error, "failed to fetch the gameflow session"),
+    }
+    if champ_select_ended(previous, phase) {
+        match fetch_json(http, auth, CURRENT_PAGE_URI).await {
+            Ok(Some(page)) => events.extend(rune_page_event(&page, timestamp_ms())),
+            Ok(None) => {}
+            Err(error) => warn!(?error, "failed to fetch the locked rune page"),
+        }
+    }
+    events
+}
+
 async fn load_lockfile(candidates: &[PathBuf]) -> Result<(PathBuf, LockfileAuth)> {
     for path in candidates {
         match fs::read_to_string(path).await {
@@ -257,13 +388,211 @@ fn parse_lockfile(path: &Path, raw: &str) -> Result<LockfileAuth> {
 }
 
 fn phase_event(phase: &str) -> Event {
-    Event {
-        kind: EventKind::PhaseChange,
-        ts: timestamp_ms(),
-        payload: EventPayload::Phase(PhaseEvent {
+    Event::new(
+        EventKind::PhaseChange,
+        timestamp_ms(),
+        EventPayload::Phase(PhaseEvent {
             phase: phase.to_string(),
         }),
+    )
+}
+
+/// A websocket text frame from the League Client, as received apart from redacted
+/// credentials; see [`crate::LiveDaemon::subscribe_lcu_raw`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LcuRawFrame {
+    /// When the frame was received, in milliseconds since the Unix epoch.
+    pub timestamp_ms: u64,
+    /// The frame text.
+    pub text: String,
+}
+
+/// Strip credentials from a raw frame: values under credential-like keys, and the lockfile
+/// password wherever it appears.
+fn redact_frame(text: &str, auth: &LockfileAuth) -> String {
+    let text = match serde_json::from_str::<Value>(text) {
+        Ok(mut value) => {
+            redact_value(&mut value);
+            value.to_string()
+        }
+        Err(_) => text.to_string(),
+    };
+    text.replace(&auth.basic_token(), REDACTED)
+        .replace(&auth.password, REDACTED)
+}
+
+fn redact_value(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                let key = key.to_ascii_lowercase();
+                if REDACTED_KEYS.iter().any(|redacted| key.contains(redacted)) {
+                    *value = Value::String(REDACTED.to_string());
+                } else {
+                    redact_value(value);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact_value),
+        _ => {}
+    }
+}
+
+/// The spectate session started by [`crate::LiveDaemon::spectate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpectateSession {
+    /// `name#tag` of the spectated player.
+    pub riot_id: String,
+    /// Riot account id of the spectated player.
+    pub puuid: String,
+    /// Game being spectated, if the client already reports it.
+    pub game_id: Option<u64>,
+}
+
+/// Ask the client to spectate the game `riot_id` (`name#tag`) is playing.
+pub(super) async fn spectate(
+    config: &DaemonConfig,
+    http: &Client,
+    riot_id: &str,
+) -> Result<SpectateSession> {
+    let (game_name, tag_line) = riot_id
+        .split_once('#')
+        .filter(|(name, tag)| !name.is_empty() && !tag.is_empty())
+        .ok_or_else(|| anyhow!("riot id `{riot_id}` is not of the form name#tag"))?;
+    let (_, auth) = load_lockfile(&lockfile_candidates(config)).await?;
+
+    let url = format!("{}/lol-summoner/v1/alias/lookup", auth.base_url());
+    let response = http
+        .get(&url)
+        .basic_auth("riot", Some(&auth.password))
+        .query(&[("gameName", game_name), ("tagLine", tag_line)])
+        .send()
+        .await
+        .with_context(|| format!("request failed: GET {url}"))?;
+    if response.status() == StatusCode::NOT_FOUND {
+        anyhow::bail!("no player named {riot_id}");
     }
+    let summoner: Value = response
+        .error_for_status()?
+        .json()
+        .await
+        .with_context(|| format!("decode GET {url}"))?;
+    let puuid = summoner
+        .get("puuid")
+        .and_then(Value::as_str)
+        .ok_or_else(|| anyhow!("lookup of {riot_id} returned no puuid"))?
+        .to_string();
+
+    let url = format!("{}/lol-spectator/v1/spectate/launch", auth.base_url());
+    let response = http
+        .post(&url)
+        .basic_auth("riot", Some(&auth.password))
+        .json(&serde_json::json!({
+            "allowObserveMode": "ALL",
+            "dropInSpectateGameId": game_name,
+            "gameQueueType": "",
+            "puuid": puuid,
+        }))
+        .send()
+        .await
+        .with_context(|| format!("request failed: POST {url}"))?;
+    if !response.status().is_success() {
+        // The client explains refusals, e.g. that the player is not in a game.
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        anyhow::bail!("spectating {riot_id} was refused ({status}): {body}");
+    }
+
+    let game_id = match fetch_json(http, &auth, SESSION_URI).await {
+        Ok(Some(session)) => session
+            .pointer("/gameData/gameId")
+            .and_then(Value::as_u64)
+            .filter(|&id| id != 0),
+        _ => None,
+    };
+    Ok(SpectateSession {
+        riot_id: riot_id.to_string(),
+        puuid,
+        game_id,
+    })
+}
+
+/// Per-game state derived from client updates.
+#[derive(Debug, Default)]
+struct Trackers {
+    surrender: SurrenderTracker,
+    skins: SkinTracker,
+    queue: QueueTracker,
+    presence: PresenceTracker,
+    pick_timer: PickTimer,
+}
+
+impl Trackers {
+    fn on_phase(&mut self, phase: &str) {
+        self.surrender.on_phase(phase);
+        self.skins.on_phase(phase);
+        self.pick_timer.on_phase(phase);
+    }
+
+    /// When a tracker next has something to report without a client update.
+    fn next_wakeup(&self) -> Option<Instant> {
+        self.pick_timer.next_wakeup()
+    }
+
+    /// Events due by now without a client update.
+    fn tick(&mut self, ts: u64) -> Vec<Event> {
+        self.pick_timer
+            .poll(Instant::now(), ts)
+            .into_iter()
+            .collect()
+    }
+
+    fn on_update(&mut self, update: &ApiUpdate, config: &DaemonConfig, ts: u64) -> Vec<Event> {
+        let mut events = self.surrender.on_update(&update.uri, &update.data, ts);
+        events.extend(self.skins.on_update(&update.uri, &update.data, ts));
+        let now = Instant::now();
+        self.pick_timer.on_update(&update.uri, &update.data, now);
+        events.extend(self.pick_timer.poll(now, ts));
+        events.extend(
+            self.queue
+                .on_update(&update.uri, &update.event_type, &update.data, ts),
+        );
+        if config.lcu_friend_presence {
+            events.extend(self.presence.on_update(&update.uri, &update.data, ts));
+        }
+        if config.lcu_lobby_chat {
+            events.extend(chat_event(
+                &update.uri,
+                &update.event_type,
+                &update.data,
+                ts,
+            ));
+        }
+        events
+    }
+}
+
+/// One resource update pushed over the `OnJsonApiEvent` subscription.
+struct ApiUpdate {
+    uri: String,
+    /// `Create`, `Update` or `Delete`.
+    event_type: String,
+    data: Value,
+}
+
+/// Parse an `[8, "OnJsonApiEvent", {"uri": …, "data": …}]` message.
+fn parse_api_event(payload: &str) -> Option<ApiUpdate> {
+    let value: Value = serde_json::from_str(payload).ok()?;
+    let body = value.as_array()?.get(2)?.as_object()?;
+    Some(ApiUpdate {
+        uri: body.get("uri")?.as_str()?.to_string(),
+        event_type: body
+            .get("eventType")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string(),
+        data: body.get("data").cloned().unwrap_or(Value::Null),
+    })
 }
 
 fn parse_phase_message(payload: &str) -> Option<String> {
@@ -275,10 +604,10 @@ fn extract_phase(value: &Value) -> Option<String> {
     match value {
         Value::Array(items) => {
             if items.len() >= 3 {
-                if items[0].as_str() == Some("OnJsonApiEvent") {
-                    if items[1].as_str() == Some(GAMEFLOW_URI) {
-                        return items.get(2)?.as_str().map(|s| s.to_string());
-                    }
+                if items[0].as_str() == Some("OnJsonApiEvent")
+                    && items[1].as_str() == Some(GAMEFLOW_URI)
+                {
+                    return items.get(2)?.as_str().map(|s| s.to_string());
                 }
 
                 if items[1].as_str() == Some("OnJsonApiEvent") {
@@ -485,6 +814,31 @@ mod tests {
         assert_eq!(parsed.protocol, "https");
     }
 
+    #[test]
+    fn raw_frames_are_redacted() {
+        let auth = parse_lockfile(Path::new("/tmp/lockfile"), "LeagueClient:1:2:hunter2:https")
+            .expect("lockfile");
+        let frame = r#"[8,"OnJsonApiEvent",{"uri":"/lol-rso-auth/v1/authorization/access-token","data":{"accessToken":"eyJhbGciOi","expiry":1700000000,"scopes":["openid"]}}]"#;
+        let redacted = redact_frame(frame, &auth);
+        assert!(!redacted.contains("eyJhbGciOi"));
+        assert!(redacted.contains(r#""expiry":1700000000"#));
+        assert!(redacted.contains("/lol-rso-auth/v1/authorization/access-token"));
+
+        let echoed = format!("not json: riot:hunter2 / Basic {}", auth.basic_token());
+        assert_eq!(
+            redact_frame(&echoed, &auth),
+            "not json: riot:<redacted> / Basic <redacted>"
+        );
+    }
+
+    #[tokio::test]
+    async fn spectate_rejects_names_without_a_tag() {
+        let error = spectate(&DaemonConfig::default(), &Client::new(), "Faker")
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("name#tag"));
+    }
+
     #[test]
     fn parse_phase_variants() {
         let variant_a = "[\"OnJsonApiEvent\",\"/lol-gameflow/v1/gameflow-phase\",\"Lobby\"]";
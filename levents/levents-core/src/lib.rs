@@ -1,25 +1,122 @@
 //! Core runtime primitives for the levents daemon.
+//!
+//! Event sources are feature-gated so embedders only compile what they use: `live-client`
+//! (Live Client Data poller and derived analytics) and `lcu` (League Client websocket) are
+//! both on by default.
+//!
+//! The public surface is deliberately small: [`LiveDaemon`] and its [`DaemonConfig`], the
+//! [`EventBus`] fan-out, decoding telemetry, and the shared event [`model`]. Releases are
+//! checked with `cargo semver-checks`, so anything not exported here may change freely.
 
+#![warn(missing_docs)]
+
+mod backlog;
+#[cfg(feature = "live-client")]
+mod bounty;
+mod budget;
+pub mod build_info;
+mod bus;
+#[cfg(feature = "lcu")]
+mod chat;
+mod decode;
+#[cfg(feature = "live-client")]
+mod dragons;
+mod enrichments;
+#[cfg(feature = "live-client")]
+mod farm;
+#[cfg(feature = "lcu")]
+mod gameflow;
+#[cfg(feature = "live-client")]
+mod lanes;
+#[cfg(feature = "lcu")]
 mod lcu;
+#[cfg(feature = "live-client")]
 mod live_client;
+#[cfg(feature = "lcu")]
+mod pick_timer;
+mod preflight;
+#[cfg(feature = "lcu")]
+mod presence;
+mod query;
+#[cfg(feature = "lcu")]
+mod queue;
+mod resources;
+mod roster;
+#[cfg(feature = "lcu")]
+mod runes;
+mod scores;
+mod series;
+#[cfg(feature = "lcu")]
+mod skins;
+mod state;
+#[cfg(feature = "live-client")]
+mod steals;
+#[cfg(feature = "live-client")]
+mod structures;
+#[cfg(feature = "lcu")]
+mod surrender;
+mod vitals;
+#[cfg(any(feature = "live-client", feature = "lcu"))]
+mod watchdog;
+#[cfg(feature = "live-client")]
+mod win_probability;
+
+pub use budget::{MemoryBudget, MemoryUsage};
+pub use bus::{EventBus, EventFilter, ResumeError, ResumeToken, Subscription};
+pub use decode::{DeserializeMode, UnknownFieldStats};
+pub use enrichments::Enrichments;
+#[cfg(feature = "lcu")]
+pub use lcu::{LcuRawFrame, SpectateSession};
+/// Shared event model, re-exported so embedders can depend on `levents-core` alone.
+pub use levents_model as model;
+pub use preflight::{SelfTestCheck, SelfTestReport};
+pub use query::Query;
+pub use resources::ResourceThresholds;
+pub use roster::Roster;
+pub use scores::ScoreDeltas;
+pub use series::SeriesSettings;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
+use budget::RegistryMeter;
+#[cfg(any(feature = "live-client", feature = "lcu"))]
 use futures_core::Stream;
 use levents_model::{
-    Event, EventBatch, EventKind, EventPayload, GoldEvent, HeartbeatEvent, ItemEvent, LevelEvent,
-    PhaseEvent, PlayerEvent, PlayerRef, Team,
+    DiagnosticEvent, Event, EventBatch, EventKind, EventPayload, EventSource, GameResultEvent,
+    HeartbeatEvent, PlayerEvent, PlayerRef, ScoreAdjustmentEvent, Team, Uuid,
 };
-use parking_lot::Mutex;
 use reqwest::Client;
 use serde_json::{json, Value};
-use std::{path::PathBuf, sync::Arc, time::Duration};
-use tokio::time::{sleep, Instant};
-use tracing::{debug, instrument, warn};
+use series::SeriesTracker;
+use state::SavedState;
+use std::{
+    path::PathBuf,
+    sync::atomic::{AtomicBool, Ordering},
+    sync::{Arc, OnceLock},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+#[cfg(feature = "lcu")]
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tokio::time::{sleep, Instant, MissedTickBehavior};
+use tracing::{debug, info, instrument, warn};
+use vitals::Vitals;
+
+/// Raw LCU frames buffered for a slow debug subscriber before it starts missing some.
+#[cfg(feature = "lcu")]
+const LCU_RAW_CAPACITY: usize = 1024;
 
 /// Configuration passed to the daemon when bootstrapping.
+///
+/// Start from [`DaemonConfig::default`] and override fields; new options are added over time.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct DaemonConfig {
+    /// Interval between heartbeat events carrying the daemon's vitals; zero disables them.
     pub heartbeat_interval: Duration,
+    /// Whether the Live Client source runs; off for boxes that only want League Client data,
+    /// e.g. pre-game analytics. On by default.
+    pub live_client_enabled: bool,
+    /// Base URL of the Live Client Data API.
     pub live_base_url: String,
     /// Interval used while the player is in combat/high activity.
     pub poll_interval_combat: Duration,
@@ -31,30 +128,133 @@ pub struct DaemonConfig {
     pub combat_cooldown: Duration,
     /// Cooldown before downgrading from normal activity to idle.
     pub idle_cooldown: Duration,
+    /// Interval between `EconomySnapshot` events; `None` disables them.
+    pub economy_snapshot_interval: Option<Duration>,
+    /// Interval between `WinProbability` estimates; `None` (the default) disables them.
+    pub win_probability_interval: Option<Duration>,
+    /// Data added to Live Client events on top of what the client reports.
+    pub enrichments: Enrichments,
+    /// Whether every champion kill is also sent as separate `Kill`, `Death` and `Assist`
+    /// events next to the `ChampionKill` event linking them; on for existing consumers.
+    pub flattened_kills: bool,
+    /// Minimum creep score and vision score growth reported by `CsDelta` and
+    /// `VisionScoreDelta` events.
+    pub score_deltas: ScoreDeltas,
+    /// Whether item events are sent per unit, as older consumers expect: buying five potions
+    /// sends five `ItemAdded` events instead of one with a `count` of five, and stack changes
+    /// are sent as `ItemAdded` or `ItemRemoved` units instead of `ItemStackChanged`. Off by
+    /// default.
+    pub per_unit_items: bool,
+    /// Whether high-frequency event categories are sent: for now `ResourceChanged`, reporting
+    /// health and mana of every champion the Live Client exposes them for. Off by default.
+    pub high_frequency_events: bool,
+    /// When `ResourceChanged` events are sent, with `high_frequency_events` on.
+    pub resource_thresholds: ResourceThresholds,
     /// Backoff used when the Live Client endpoints cannot be reached.
     pub error_backoff: Duration,
+    /// Whether the League Client (LCU) source runs; off for boxes without a League Client,
+    /// such as spectator machines, so it does not keep looking for the lockfile. On by default.
+    pub lcu_enabled: bool,
     /// Optional override pointing at the League Client lockfile location.
     pub lcu_lockfile: Option<PathBuf>,
     /// Interval used while the lockfile is missing; controls discovery polling.
     pub lcu_discovery_interval: Duration,
     /// Delay before attempting to reconnect after an LCU websocket disconnect.
     pub lcu_retry_delay: Duration,
+    /// Whether lobby and champ select chat is emitted as `LobbyChat` events; off by default,
+    /// since chat is private to the players in it.
+    pub lcu_lobby_chat: bool,
+    /// Whether friends' availability and current game are emitted as `FriendPresence` events;
+    /// off by default. Status messages are never included.
+    pub lcu_friend_presence: bool,
+    /// Whether matchmaking ready checks are accepted automatically; can be changed at runtime
+    /// with [`LiveDaemon::set_auto_accept`].
+    pub lcu_auto_accept: bool,
+    /// Whether raw LCU websocket frames can be followed with [`LiveDaemon::subscribe_lcu_raw`],
+    /// for reverse-engineering new endpoints; off by default.
+    pub lcu_raw_frames: bool,
+    /// How long a source may go without making progress before the watchdog restarts it;
+    /// `None` disables the watchdog.
+    pub source_stall_timeout: Option<Duration>,
+    /// Whether Live Client payloads with unmodelled fields are accepted or rejected.
+    pub deserialize_mode: DeserializeMode,
+    /// Player identities attached to events that reference a listed summoner.
+    pub roster: Roster,
+    /// Events kept by [`LiveDaemon::events_hub`] for backfill and delayed subscribers.
+    pub backfill_capacity: usize,
+    /// How many of the backfill events stay in memory when `backfill_spill_dir` is set.
+    pub backfill_memory_budget: usize,
+    /// Directory for backfill events beyond the memory budget; `None` keeps everything in
+    /// memory.
+    pub backfill_spill_dir: Option<PathBuf>,
+    /// How late an event may be delivered before it is marked `stale`: after publication, plus
+    /// the subscription's delay for delayed subscribers. `None` never marks events.
+    pub stale_after: Option<Duration>,
+    /// Caps on the player registry and unknown-field counters.
+    pub memory_budget: MemoryBudget,
+    /// Interval between self-checks that log stores close to their cap; `None` disables them.
+    pub memory_check_interval: Option<Duration>,
+    /// File the session state is saved to by [`LiveDaemon::save_state`] and restored from on
+    /// startup, so a restart mid-game does not replay the game so far; `None` disables it.
+    pub state_file: Option<PathBuf>,
+    /// Interval between HTTP/2 keepalive pings on transport connections; `None` disables them.
+    ///
+    /// Venue NATs often drop connections that stay quiet for a minute, which a subscription
+    /// idles for between fights.
+    pub http2_keepalive_interval: Option<Duration>,
+    /// How long to wait for a keepalive ping to be acknowledged before closing the connection.
+    pub http2_keepalive_timeout: Duration,
+    /// Limit on concurrent streams per connection; `None` leaves the transport default.
+    pub max_concurrent_streams: Option<u32>,
+    /// Initial HTTP/2 flow-control window per stream, in bytes; `None` leaves the default.
+    pub initial_stream_window_size: Option<u32>,
+    /// Initial HTTP/2 flow-control window per connection, in bytes; `None` leaves the default.
+    pub initial_connection_window_size: Option<u32>,
 }
 
 impl Default for DaemonConfig {
     fn default() -> Self {
         Self {
             heartbeat_interval: Duration::from_secs(1),
+            live_client_enabled: true,
             live_base_url: "https://127.0.0.1:2999".to_string(),
             poll_interval_combat: Duration::from_millis(150),
             poll_interval_normal: Duration::from_millis(750),
             poll_interval_idle: Duration::from_millis(1500),
             combat_cooldown: Duration::from_secs(5),
             idle_cooldown: Duration::from_secs(20),
+            economy_snapshot_interval: Some(Duration::from_secs(30)),
+            win_probability_interval: None,
+            enrichments: Enrichments::default(),
+            flattened_kills: true,
+            score_deltas: ScoreDeltas::default(),
+            per_unit_items: false,
+            high_frequency_events: false,
+            resource_thresholds: ResourceThresholds::default(),
             error_backoff: Duration::from_secs(1),
+            lcu_enabled: true,
             lcu_lockfile: None,
             lcu_discovery_interval: Duration::from_secs(1),
             lcu_retry_delay: Duration::from_secs(2),
+            lcu_lobby_chat: false,
+            lcu_friend_presence: false,
+            lcu_auto_accept: false,
+            lcu_raw_frames: false,
+            source_stall_timeout: Some(Duration::from_secs(30)),
+            deserialize_mode: DeserializeMode::default(),
+            roster: Roster::default(),
+            backfill_capacity: bus::BACKFILL_CAPACITY,
+            backfill_memory_budget: bus::BACKFILL_CAPACITY,
+            backfill_spill_dir: None,
+            stale_after: Some(Duration::from_secs(5)),
+            memory_budget: MemoryBudget::default(),
+            memory_check_interval: Some(Duration::from_secs(60)),
+            state_file: None,
+            http2_keepalive_interval: Some(Duration::from_secs(20)),
+            http2_keepalive_timeout: Duration::from_secs(10),
+            max_concurrent_streams: None,
+            initial_stream_window_size: None,
+            initial_connection_window_size: None,
         }
     }
 }
@@ -64,7 +264,17 @@ impl Default for DaemonConfig {
 pub struct LiveDaemon {
     config: DaemonConfig,
     http: Client,
-    seq: Arc<Mutex<u64>>,
+    unknown_fields: UnknownFieldStats,
+    bus: Arc<OnceLock<EventBus>>,
+    series: SeriesTracker,
+    vitals: Vitals,
+    registry_meter: RegistryMeter,
+    auto_accept: Arc<AtomicBool>,
+    game: bus::GameHandle,
+    #[cfg(feature = "lcu")]
+    lcu_raw: Option<broadcast::Sender<LcuRawFrame>>,
+    #[cfg(feature = "live-client")]
+    live_session: live_client::SessionHandle,
 }
 
 impl LiveDaemon {
@@ -82,26 +292,382 @@ impl LiveDaemon {
     /// Construct the daemon with a caller-provided `reqwest` client (useful for tests).
     pub fn with_client(config: DaemonConfig, http: Client) -> Self {
         Self {
+            unknown_fields: UnknownFieldStats::with_limit(config.memory_budget.max_unknown_fields),
+            auto_accept: Arc::new(AtomicBool::new(config.lcu_auto_accept)),
+            #[cfg(feature = "lcu")]
+            lcu_raw: config
+                .lcu_raw_frames
+                .then(|| broadcast::channel(LCU_RAW_CAPACITY).0),
             config,
             http,
-            seq: Arc::new(Mutex::new(0)),
+            bus: Arc::new(OnceLock::new()),
+            series: SeriesTracker::default(),
+            vitals: Vitals::default(),
+            registry_meter: RegistryMeter::default(),
+            game: bus::GameHandle::default(),
+            #[cfg(feature = "live-client")]
+            live_session: live_client::SessionHandle::default(),
         }
     }
 
+    /// Returns the configuration the daemon was created with.
+    pub fn config(&self) -> &DaemonConfig {
+        &self.config
+    }
+
     /// Returns a reference to the internal HTTP client.
     pub fn http_client(&self) -> &Client {
         &self.http
     }
 
+    /// Returns the counters of unknown Live Client fields observed so far.
+    pub fn unknown_field_stats(&self) -> &UnknownFieldStats {
+        &self.unknown_fields
+    }
+
+    /// Current size of the capped stores, with the backfill held in memory by `bus`.
+    pub fn memory_usage(&self, bus: &EventBus) -> MemoryUsage {
+        let (players, items, evicted_entries) = self.registry_meter.read();
+        MemoryUsage {
+            players,
+            items,
+            unknown_fields: self.unknown_fields.len(),
+            backfill_events: bus.backfill_in_memory(),
+            evicted_entries,
+            evicted_unknown_fields: self.unknown_fields.evicted(),
+        }
+    }
+
+    /// Set the best-of series context stamped onto `TeamInfo` and `GameEnded` events.
+    ///
+    /// The context persists across games: later games advance the game number and keep each
+    /// team's wins even if sides are swapped. `best_of == 0` clears it.
+    pub fn set_series(&self, settings: SeriesSettings) {
+        self.series.configure(settings);
+    }
+
+    /// Turn automatic acceptance of matchmaking ready checks on or off.
+    pub fn set_auto_accept(&self, enabled: bool) {
+        self.auto_accept.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Whether matchmaking ready checks are currently accepted automatically.
+    pub fn auto_accept(&self) -> bool {
+        self.auto_accept.load(Ordering::Relaxed)
+    }
+
     /// Spawn an asynchronous stream that polls the Live Client Data endpoints and emits
     /// normalized event batches with adaptive scheduling.
+    #[cfg(feature = "live-client")]
     pub fn live_events(&self) -> impl Stream<Item = Result<EventBatch>> + Send + 'static {
-        live_client::live_event_stream(self.config.clone(), self.http.clone())
+        live_client::live_event_stream(
+            self.config.clone(),
+            self.http.clone(),
+            self.unknown_fields.clone(),
+            self.series.clone(),
+            self.vitals.clone(),
+            self.registry_meter.clone(),
+            self.live_session.clone(),
+            self.game.clone(),
+        )
     }
 
     /// Spawn a websocket-backed stream that proxies LCU phase changes.
+    #[cfg(feature = "lcu")]
     pub fn lcu_events(&self) -> impl Stream<Item = Result<EventBatch>> + Send + 'static {
-        lcu::lcu_event_stream(self.config.clone(), self.http.clone())
+        lcu::lcu_event_stream(
+            self.config.clone(),
+            self.http.clone(),
+            self.vitals.clone(),
+            self.auto_accept.clone(),
+            self.lcu_raw.clone(),
+        )
+    }
+
+    /// Follow the raw LCU websocket frames, with credentials redacted; `None` unless
+    /// [`DaemonConfig::lcu_raw_frames`] is set and the LCU source is enabled.
+    #[cfg(feature = "lcu")]
+    pub fn subscribe_lcu_raw(&self) -> Option<broadcast::Receiver<LcuRawFrame>> {
+        if !self.config.lcu_enabled {
+            return None;
+        }
+        self.lcu_raw.as_ref().map(broadcast::Sender::subscribe)
+    }
+
+    /// Have the local League Client spectate the game the player `riot_id` (`name#tag`) is
+    /// in, as an observer would from the friends list.
+    #[cfg(feature = "lcu")]
+    pub async fn spectate(&self, riot_id: &str) -> Result<SpectateSession> {
+        anyhow::ensure!(
+            self.config.lcu_enabled,
+            "the League Client source is disabled"
+        );
+        lcu::spectate(&self.config, &self.http, riot_id).await
+    }
+
+    /// Wall-clock time (Unix ms) at which the running game's clock read zero, as last
+    /// estimated from the Live Client; `None` before the first game.
+    ///
+    /// Live Client events are timestamped with the game clock, so adding their `ts` to it gives
+    /// the wall-clock time they happened.
+    #[cfg(feature = "live-client")]
+    pub fn game_clock_anchor(&self) -> Option<u64> {
+        self.live_session.lock().game_clock_anchor()
+    }
+
+    /// Game the daemon's events are currently stamped with; a new id is drawn whenever the Live
+    /// Client shows a new game. `None` before the first one.
+    ///
+    /// Events from other sources carry the id of the last game detected before them, so LCU
+    /// events from champ select still belong to the previous game.
+    pub fn game_id(&self) -> Option<Uuid> {
+        *self.game.lock()
+    }
+
+    /// Shared bus fed by the Live Client and LCU sources, for embedding without gRPC.
+    ///
+    /// The first call restores the state saved to `state_file`, then spawns both sources, the
+    /// heartbeat task and the memory self-check on the current Tokio runtime; every clone of
+    /// the daemon returns the same bus afterwards.
+    pub fn events_hub(&self) -> EventBus {
+        self.bus
+            .get_or_init(|| {
+                let mut bus = self.new_bus();
+                if let Some(saved) = self.take_saved_state() {
+                    debug!(session = %saved.session, seq = saved.seq, "restoring saved state");
+                    bus = bus.continue_session(saved.session, saved.seq);
+                    #[cfg(feature = "live-client")]
+                    {
+                        *self.live_session.lock() = saved.live.unwrap_or_default();
+                    }
+                }
+                self.spawn_sources(&bus);
+                self.spawn_heartbeat(&bus);
+                self.spawn_memory_check(&bus);
+                bus
+            })
+            .clone()
+    }
+
+    fn take_saved_state(&self) -> Option<SavedState> {
+        let path = self.config.state_file.as_ref()?;
+        SavedState::take(path).unwrap_or_else(|error| {
+            warn!(?error, "ignoring saved state");
+            None
+        })
+    }
+
+    /// Save the session state to `state_file` for the next run to continue from; call it on
+    /// shutdown. Does nothing without a state file or before [`LiveDaemon::events_hub`] ran.
+    pub fn save_state(&self) -> Result<()> {
+        let (Some(path), Some(bus)) = (&self.config.state_file, self.bus.get()) else {
+            return Ok(());
+        };
+        SavedState {
+            saved_at_ms: state::now_ms(),
+            session: bus.session(),
+            seq: bus.last_seq(),
+            #[cfg(feature = "live-client")]
+            live: Some(self.live_session.lock().clone()),
+        }
+        .save(path)
+    }
+
+    fn new_bus(&self) -> EventBus {
+        self.try_new_bus().unwrap_or_else(|error| {
+            warn!(?error, "backfill spill disabled; keeping events in memory");
+            EventBus::with_capacity(bus::CHANNEL_CAPACITY, self.config.backfill_capacity)
+                .with_stale_after(self.config.stale_after)
+                .with_game(self.game.clone())
+        })
+    }
+
+    /// A bus as configured, failing when the spill directory cannot be used.
+    fn try_new_bus(&self) -> Result<EventBus> {
+        let config = &self.config;
+        let bus = match &config.backfill_spill_dir {
+            Some(dir) => EventBus::with_spill(
+                bus::CHANNEL_CAPACITY,
+                config.backfill_capacity,
+                config.backfill_memory_budget,
+                dir,
+            )?,
+            None => EventBus::with_capacity(bus::CHANNEL_CAPACITY, config.backfill_capacity),
+        };
+        Ok(bus
+            .with_stale_after(config.stale_after)
+            .with_game(self.game.clone()))
+    }
+
+    /// Check the configuration and the event path before serving.
+    ///
+    /// Validates the intervals and URLs, opens the backfill spill directory and sends a
+    /// synthetic kill through a scratch bus built like [`LiveDaemon::events_hub`], so a broken
+    /// setup fails at startup instead of at first blood. The live bus is left untouched.
+    pub async fn preflight(&self) -> Result<()> {
+        preflight::validate_config(&self.config).context("invalid configuration")?;
+        let bus = self
+            .try_new_bus()
+            .context("backfill spill directory is unusable")?;
+        let elapsed = preflight::round_trip(&bus, self.synthetic_kill("preflight"))
+            .await
+            .context("event bus self-test failed")?;
+        info!(
+            round_trip_us = elapsed.as_micros() as u64,
+            "preflight passed"
+        );
+        Ok(())
+    }
+
+    /// Exercise the running pipeline and report on each step, e.g. right before going live.
+    ///
+    /// Runs the preflight checks again, then publishes a `Diagnostic` event with source
+    /// `selfTest` on [`LiveDaemon::events_hub`] and waits for it to come back, so subscribers
+    /// see that one event.
+    pub async fn self_test(&self) -> SelfTestReport {
+        let hub = self.events_hub();
+        let subscribers = hub.subscriber_count();
+        let probe = Event::new(
+            EventKind::Diagnostic,
+            timestamp_ms(),
+            EventPayload::Diagnostic(DiagnosticEvent {
+                source: "selfTest".to_string(),
+                message: "self-test probe".to_string(),
+            }),
+        );
+        let checks = vec![
+            preflight::check("config", async { preflight::validate_config(&self.config) }).await,
+            preflight::check("scratchBus", async {
+                let bus = self.try_new_bus()?;
+                preflight::round_trip(&bus, self.synthetic_kill("self-test")).await?;
+                Ok(())
+            })
+            .await,
+            preflight::check("liveBus", async {
+                preflight::round_trip(&hub, probe).await?;
+                Ok(())
+            })
+            .await,
+        ];
+        SelfTestReport {
+            checks,
+            subscribers,
+            sources_connected: self.vitals.sources_connected(),
+            sources_disabled: self.sources_disabled(),
+        }
+    }
+
+    /// Names of the sources that never run: turned off in the configuration or not built in.
+    fn sources_disabled(&self) -> Vec<String> {
+        [
+            (
+                vitals::Source::LiveClient,
+                cfg!(feature = "live-client") && self.config.live_client_enabled,
+            ),
+            (
+                vitals::Source::Lcu,
+                cfg!(feature = "lcu") && self.config.lcu_enabled,
+            ),
+        ]
+        .into_iter()
+        .filter(|&(_, enabled)| !enabled)
+        .map(|(source, _)| source.name().to_string())
+        .collect()
+    }
+
+    /// Forward the enabled Live Client and LCU sources into `bus`.
+    ///
+    /// With `source_stall_timeout` set, a watchdog restarts a source that stops making progress
+    /// and publishes a `Diagnostic` event saying so.
+    pub fn spawn_sources(&self, bus: &EventBus) {
+        #[cfg(feature = "live-client")]
+        if self.config.live_client_enabled {
+            self.spawn_source(bus, vitals::Source::LiveClient, |daemon| {
+                daemon.live_events()
+            });
+        }
+        #[cfg(feature = "lcu")]
+        if self.config.lcu_enabled {
+            self.spawn_source(bus, vitals::Source::Lcu, |daemon| daemon.lcu_events());
+        }
+        #[cfg(not(any(feature = "live-client", feature = "lcu")))]
+        let _ = bus;
+        let disabled = self.sources_disabled();
+        if !disabled.is_empty() {
+            info!(?disabled, "event sources disabled");
+        }
+    }
+
+    #[cfg(any(feature = "live-client", feature = "lcu"))]
+    fn spawn_source<S>(&self, bus: &EventBus, source: vitals::Source, start: fn(&Self) -> S)
+    where
+        S: Stream<Item = Result<EventBatch>> + Send + 'static,
+    {
+        match self.config.source_stall_timeout {
+            Some(stall_timeout) => {
+                let daemon = self.clone();
+                watchdog::supervise(bus, self.vitals.clone(), source, stall_timeout, move || {
+                    start(&daemon)
+                });
+            }
+            None => {
+                bus.spawn_source(start(self));
+            }
+        }
+    }
+
+    /// Publish a heartbeat with the daemon's vitals into `bus` every `heartbeat_interval`.
+    pub fn spawn_heartbeat(&self, bus: &EventBus) -> Option<JoinHandle<()>> {
+        let interval = self.config.heartbeat_interval;
+        if interval.is_zero() {
+            return None;
+        }
+        let daemon = self.clone();
+        let bus = bus.clone();
+        Some(tokio::spawn(async move {
+            let mut ticker = tokio::time::interval_at(Instant::now() + interval, interval);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+            loop {
+                ticker.tick().await;
+                let heartbeat = daemon.heartbeat(Some(bus.dropped_events()));
+                bus.publish(Event::new(
+                    EventKind::Heartbeat,
+                    timestamp_ms(),
+                    EventPayload::Heartbeat(heartbeat),
+                ));
+            }
+        }))
+    }
+
+    /// Log the stores of [`LiveDaemon::memory_usage`] that are close to their cap every
+    /// `memory_check_interval`.
+    pub fn spawn_memory_check(&self, bus: &EventBus) -> Option<JoinHandle<()>> {
+        let interval = self.config.memory_check_interval?;
+        let daemon = self.clone();
+        let bus = bus.clone();
+        let backfill_budget = match self.config.backfill_spill_dir {
+            Some(_) => self.config.backfill_memory_budget,
+            None => self.config.backfill_capacity,
+        };
+        Some(tokio::spawn(async move {
+            let mut ticker = tokio::time::interval_at(Instant::now() + interval, interval);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+            loop {
+                ticker.tick().await;
+                let usage = daemon.memory_usage(&bus);
+                debug!(?usage, "memory self-check");
+                let budget = &daemon.config.memory_budget;
+                for (store, len, cap) in usage.near_budget(budget, backfill_budget) {
+                    warn!(store, len, cap, "store is close to its memory budget");
+                }
+            }
+        }))
+    }
+
+    fn heartbeat(&self, dropped_events: Option<u64>) -> HeartbeatEvent {
+        let mut heartbeat = self.vitals.heartbeat(dropped_events);
+        heartbeat.sources_disabled = self.sources_disabled();
+        heartbeat
     }
 
     /// Perform a lightweight bootstrap routine to prove that async runtime wiring works.
@@ -115,22 +681,15 @@ impl LiveDaemon {
             json!({"source": "stub"})
         });
 
-        let seq = {
-            let mut guard = self.seq.lock();
-            *guard += 1;
-            *guard
-        };
-
-        let event = Event {
-            kind: EventKind::Heartbeat,
-            ts: start.elapsed().as_millis() as u64,
-            payload: EventPayload::Heartbeat(HeartbeatEvent { seq }),
-        };
+        let dropped_events = self.bus.get().map(EventBus::dropped_events);
+        let event = Event::new(
+            EventKind::Heartbeat,
+            start.elapsed().as_millis() as u64,
+            EventPayload::Heartbeat(self.heartbeat(dropped_events)),
+        );
 
         debug!(?metadata, "bootstrap metadata ready");
-        Ok(EventBatch {
-            events: vec![event],
-        })
+        Ok(EventBatch::new(vec![event]))
     }
 
     /// Fetch basic metadata from the live client REST endpoint. For now this method returns
@@ -143,22 +702,56 @@ impl LiveDaemon {
         Ok(json!({"status": "unreachable"}))
     }
 
+    /// Build a manual correction to `team`'s kill score, for kills the Live Client missed.
+    pub fn adjust_score(&self, team: Team, kills: i32) -> Event {
+        let mut event = Event::new(
+            EventKind::ScoreAdjusted,
+            timestamp_ms(),
+            EventPayload::ScoreAdjustment(ScoreAdjustmentEvent { team, kills }),
+        );
+        event.manual = true;
+        event
+    }
+
+    /// Build a manual `GameEnded` result naming `winner`, correcting the series score if the
+    /// game had already been credited to the other team.
+    pub fn override_result(&self, winner: Team) -> Event {
+        let series = self.series.record_win(&winner);
+        let mut event = Event::new(
+            EventKind::GameEnded,
+            timestamp_ms(),
+            EventPayload::GameResult(GameResultEvent {
+                winner: Some(winner),
+                series,
+            }),
+        );
+        event.manual = true;
+        event
+    }
+
     /// Construct a synthetic kill event used by smoke-tests.
     pub fn synthetic_kill(&self, summoner: &str) -> Event {
-        Event {
-            kind: EventKind::Kill,
-            ts: 0,
-            payload: EventPayload::Player(PlayerEvent {
-                player: PlayerRef {
-                    summoner_name: summoner.to_string(),
-                    team: Team::Order,
-                    slot: 0,
-                },
+        let mut player = PlayerRef::new(summoner, Team::Order, 0);
+        player.identity = self.config.roster.identity(summoner).cloned();
+        Event::new(
+            EventKind::Kill,
+            0,
+            EventPayload::Player(PlayerEvent {
+                player,
+                bounty: None,
             }),
-        }
+        )
+        .with_source(EventSource::Synthetic)
     }
 }
 
+fn timestamp_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or(0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -174,10 +767,79 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn preflight_rejects_broken_settings() {
+        let daemon = LiveDaemon::new(DaemonConfig::default());
+        daemon.preflight().await.expect("preflight");
+
+        let zero_interval = LiveDaemon::new(DaemonConfig {
+            poll_interval_combat: Duration::ZERO,
+            ..DaemonConfig::default()
+        });
+        assert!(zero_interval.preflight().await.is_err());
+
+        let spill_file =
+            std::env::temp_dir().join(format!("levents-preflight-{}", std::process::id()));
+        std::fs::write(&spill_file, b"not a directory").unwrap();
+        let bad_spill = LiveDaemon::new(DaemonConfig {
+            backfill_spill_dir: Some(spill_file.clone()),
+            ..DaemonConfig::default()
+        });
+        assert!(bad_spill.preflight().await.is_err());
+        std::fs::remove_file(spill_file).unwrap();
+    }
+
+    #[tokio::test]
+    async fn self_test_reaches_live_subscribers() {
+        let daemon = LiveDaemon::new(DaemonConfig {
+            source_stall_timeout: None,
+            live_client_enabled: false,
+            lcu_enabled: false,
+            ..DaemonConfig::default()
+        });
+        let mut subscription = daemon
+            .events_hub()
+            .subscribe(EventFilter::kinds([EventKind::Diagnostic]));
+
+        let report = daemon.self_test().await;
+        assert!(report.passed(), "{report:?}");
+        assert_eq!(report.subscribers, 1);
+        let names: Vec<_> = report.checks.iter().map(|check| check.name).collect();
+        assert_eq!(names, ["config", "scratchBus", "liveBus"]);
+        assert_eq!(report.sources_disabled, ["liveClient", "lcu"]);
+        assert!(report.sources_connected.is_empty());
+
+        // Sources may report their own diagnostics first.
+        while let Some(event) = subscription.recv().await {
+            if let EventPayload::Diagnostic(DiagnosticEvent { source, .. }) = event.payload {
+                if source == "selfTest" {
+                    return;
+                }
+            }
+        }
+        panic!("self-test probe not delivered");
+    }
+
+    #[cfg(feature = "lcu")]
+    #[test]
+    fn raw_lcu_frames_follow_the_lcu_source() {
+        let raw = DaemonConfig {
+            lcu_raw_frames: true,
+            ..DaemonConfig::default()
+        };
+        assert!(LiveDaemon::new(raw.clone()).subscribe_lcu_raw().is_some());
+        let disabled = LiveDaemon::new(DaemonConfig {
+            lcu_enabled: false,
+            ..raw
+        });
+        assert!(disabled.subscribe_lcu_raw().is_none());
+    }
+
     #[test]
     fn synthetic_kill_contains_summoner() {
         let daemon = LiveDaemon::new(DaemonConfig::default());
         let event = daemon.synthetic_kill("Example");
+        assert_eq!(event.source, EventSource::Synthetic);
         if let EventPayload::Player(player) = event.payload {
             assert_eq!(player.player.summoner_name, "Example");
         } else {
@@ -1,30 +1,148 @@
-use super::{
-    DaemonConfig, Event, EventBatch, EventKind, EventPayload, GoldEvent, ItemEvent, LevelEvent,
-    PhaseEvent, PlayerEvent, PlayerRef, Team,
-};
-use levents_model::{AbilitySlot, SkillLevelEvent};
+use super::bounty::BountyTracker;
+use super::budget::{MemoryBudget, RegistryMeter};
+use super::bus::GameHandle;
+use super::decode::{audit_unknown_fields, UnknownFieldStats};
+use super::dragons::DragonTracker;
+use super::farm::FarmTracker;
+use super::lanes::{assign_lanes, LaneHints};
+use super::resources::{ResourceLevels, ResourceTracker};
+use super::series::SeriesTracker;
+use super::steals::StealTracker;
+use super::structures;
+use super::vitals::{Source, Vitals};
+use super::win_probability::{TeamStats, TeamTally};
+use super::{DaemonConfig, ScoreDeltas};
 use anyhow::{Context, Result};
 use async_stream::try_stream;
 use futures_core::Stream;
-use reqwest::Client;
-use serde::Deserialize;
-use std::collections::{HashMap, HashSet};
+use levents_model::{
+    AbilitySlot, ActiveRunesEvent, EconomyEvent, Event, EventBatch, EventKind, EventPayload,
+    EventSource, GameMetadataEvent, GameResultEvent, GoldEvent, ItemEvent, KillDetailEvent,
+    KillType, LaneAssignmentEvent, LevelEvent, Objective, ObjectiveKillEvent, PhaseEvent,
+    PlayerEvent, PlayerRef, ResourceEvent, ScoreEvent, SkillLevelEvent, SpecialKillEvent,
+    StructureEvent, Team, TeamWipeEvent, Uuid,
+};
+use parking_lot::Mutex;
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tokio::time::sleep;
-use tracing::{trace, warn};
+use tracing::{debug, trace, warn};
 use xxhash_rust::xxh3::xxh3_64;
 
+/// Game time (seconds) after which lanes are settled enough to infer assignments.
+const LANE_ASSIGNMENT_GAME_TIME: f64 = 180.0;
+
+/// Phase reported while the client is up but the game is still on the loading screen.
+const LOADING_PHASE: &str = "Loading";
+
+/// How far (seconds) the game clock may run backwards before the poller assumes a new game.
+/// Small steps back happen when a poll races the client's clock update.
+const GAME_CLOCK_REWIND: f64 = 10.0;
+
+const ACTIVE_PLAYER: &str = "activeplayer";
+const GAME_STATS: &str = "gamestats";
+
+/// Endpoints a poll can do without: the local player's skill levels. The player list, event
+/// data and the game clock, which new-game detection relies on, are never skipped.
+const SKIPPABLE_ENDPOINTS: [&str; 1] = [ACTIVE_PLAYER];
+
+/// Fields of each payload that the poller has no use for, so they are not reported as unknown.
+fn ignored_fields(payload: &str) -> &'static [&'static str] {
+    match payload {
+        "activeplayer" => &[
+            "currentGold",
+            "level",
+            "riotIdGameName",
+            "riotIdTagLine",
+            "teamRelativeColors",
+        ],
+        "playerlist" => &[
+            "isBot",
+            "rawSkinName",
+            "runes",
+            "screenPositionBottom",
+            "screenPositionCenter",
+            "skinName",
+        ],
+        "playerlist.items" => &["canUse", "consumable", "rawDescription", "rawDisplayName"],
+        _ => &[],
+    }
+}
+
+/// What the poller needs to pick a game up where it left off, after a daemon or source
+/// restart, without re-emitting everything that already happened.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct LiveSession {
+    last_event_id: Option<u64>,
+    #[serde(default)]
+    last_event: Option<EventFingerprint>,
+    #[serde(default)]
+    game_time: f64,
+    /// Wall-clock time (Unix ms) at which the game clock read zero.
+    #[serde(default)]
+    game_clock_anchor: Option<u64>,
+    #[serde(default)]
+    game_id: Option<Uuid>,
+    lanes_assigned: bool,
+    players: Vec<PlayerSnapshot>,
+}
+
+impl LiveSession {
+    pub(crate) fn game_clock_anchor(&self) -> Option<u64> {
+        self.game_clock_anchor
+    }
+}
+
+/// Latest [`LiveSession`] of the running poller, shared with the daemon handle.
+pub(crate) type SessionHandle = Arc<Mutex<LiveSession>>;
+
+#[allow(clippy::too_many_arguments)]
 pub(super) fn live_event_stream(
     config: DaemonConfig,
     http: Client,
+    unknown_fields: UnknownFieldStats,
+    series: SeriesTracker,
+    vitals: Vitals,
+    meter: RegistryMeter,
+    session: SessionHandle,
+    game: GameHandle,
 ) -> impl Stream<Item = Result<EventBatch>> + Send {
     try_stream! {
-        let mut ctx = PollContext::new(config, http);
+        let roster = config.roster.clone();
+        let enrichments = config.enrichments;
+        let mut ctx =
+            PollContext::new(config, http, unknown_fields, series, vitals.clone(), meter);
+        ctx.resume(session, game);
+        let mut interval = ctx.config.poll_interval_idle;
 
         loop {
+            let started = Instant::now();
             let outcome = ctx.poll_once().await?;
+            let elapsed = started.elapsed();
+            vitals.record_poll(elapsed);
+            if ctx.budget.record(elapsed, interval) {
+                vitals.record_over_budget();
+                debug!(
+                    ?elapsed,
+                    ?interval,
+                    skipped = ?ctx.budget.skipped(),
+                    "live client poll overran its interval"
+                );
+            }
+            interval = outcome.next_delay;
             if !outcome.events.is_empty() {
-                yield EventBatch { events: outcome.events };
+                let mut batch =
+                    EventBatch::new(outcome.events).with_source(EventSource::LiveClient);
+                if enrichments.identities {
+                    roster.enrich(&mut batch);
+                }
+                enrichments.strip(&mut batch);
+                yield batch;
             }
             sleep(outcome.next_delay).await;
         }
@@ -37,28 +155,214 @@ struct PollContext {
     digest: DigestState,
     players: PlayerRegistry,
     activity: ActivityState,
-    active_skills: Option<ActiveAbilitiesSnapshot>,
+    active_player: Option<ActivePlayerSnapshot>,
+    /// Mode and map last reported this game.
+    game_metadata: Option<GameMetadataEvent>,
+    unknown_fields: UnknownFieldStats,
+    last_economy_snapshot: Instant,
+    tally: TeamTally,
+    last_win_probability: Instant,
+    bounties: BountyTracker,
+    dragons: DragonTracker,
+    farm: FarmTracker,
+    /// Latest in-game clock reported by `/liveclientdata/gamestats`, in seconds.
+    game_time: f64,
+    /// Wall-clock time (Unix ms) at which `game_time` read zero, as of the last `gamestats`.
+    game_clock_anchor: Option<u64>,
+    /// Id stamped onto this game's events; drawn with the first of them.
+    game_id: Option<Uuid>,
+    /// Where the current `game_id` is shared with the bus.
+    game: GameHandle,
+    lanes_assigned: bool,
+    team_info_sent: bool,
+    series: SeriesTracker,
+    vitals: Vitals,
+    session: SessionHandle,
+    endpoints: EndpointHealth,
+    budget: PollBudget,
+    resources: ResourceTracker,
+    steals: StealTracker,
 }
 
 impl PollContext {
-    fn new(config: DaemonConfig, http: Client) -> Self {
+    fn new(
+        config: DaemonConfig,
+        http: Client,
+        unknown_fields: UnknownFieldStats,
+        series: SeriesTracker,
+        vitals: Vitals,
+        meter: RegistryMeter,
+    ) -> Self {
         Self {
+            players: PlayerRegistry::with_budget(config.memory_budget, meter)
+                .with_score_deltas(config.score_deltas)
+                .with_per_unit_items(config.per_unit_items),
+            resources: ResourceTracker::new(config.resource_thresholds),
+            steals: StealTracker::default(),
             http,
             config,
             digest: DigestState::default(),
-            players: PlayerRegistry::default(),
             activity: ActivityState::default(),
-            active_skills: None,
+            active_player: None,
+            game_metadata: None,
+            unknown_fields,
+            last_economy_snapshot: Instant::now(),
+            tally: TeamTally::default(),
+            last_win_probability: Instant::now(),
+            bounties: BountyTracker::default(),
+            dragons: DragonTracker::default(),
+            farm: FarmTracker::default(),
+            game_time: 0.0,
+            game_clock_anchor: None,
+            game_id: None,
+            game: GameHandle::default(),
+            lanes_assigned: false,
+            team_info_sent: false,
+            series,
+            vitals,
+            session: SessionHandle::default(),
+            endpoints: EndpointHealth::default(),
+            budget: PollBudget::default(),
+        }
+    }
+
+    /// Continue from the state last published to `session` and keep it up to date, sharing
+    /// the game id through `game`.
+    fn resume(&mut self, session: SessionHandle, game: GameHandle) {
+        let LiveSession {
+            last_event_id,
+            last_event,
+            game_time,
+            game_clock_anchor,
+            game_id,
+            lanes_assigned,
+            players,
+        } = session.lock().clone();
+        if !players.is_empty() || last_event_id.is_some() {
+            debug!(
+                players = players.len(),
+                ?last_event_id,
+                "resuming live session"
+            );
+        }
+        self.digest.last_event_id = last_event_id;
+        self.digest.last_event = last_event;
+        self.game_time = game_time;
+        self.game_clock_anchor = game_clock_anchor;
+        self.lanes_assigned = lanes_assigned;
+        self.players.restore(players);
+        self.session = session;
+        if game_id.is_some() {
+            *game.lock() = game_id;
+        }
+        self.game_id = game_id;
+        self.game = game;
+    }
+
+    /// Forget the event digest and every per-game tracker.
+    fn reset_game(&mut self) {
+        self.digest.last_event_id = None;
+        self.digest.last_event = None;
+        self.tally = TeamTally::default();
+        self.bounties = BountyTracker::default();
+        self.dragons = DragonTracker::default();
+        self.farm = FarmTracker::default();
+        self.lanes_assigned = false;
+        self.team_info_sent = false;
+        self.active_player = None;
+        self.digest.active_hash = None;
+        self.game_metadata = None;
+        self.game_clock_anchor = None;
+        self.resources.clear();
+        self.steals.clear();
+        self.game_id = None;
+    }
+
+    /// Stamp `events` with the current game's id, drawing one for a new game.
+    fn stamp_game(&mut self, events: &mut [Event]) {
+        if events.is_empty() {
+            return;
+        }
+        let game_id = *self.game_id.get_or_insert_with(|| {
+            let game_id = Uuid::new_v4();
+            debug!(%game_id, "new game session");
+            *self.game.lock() = Some(game_id);
+            game_id
+        });
+        for event in events {
+            event.game_id = Some(game_id);
+        }
+    }
+
+    fn publish_session(&self) {
+        *self.session.lock() = LiveSession {
+            last_event_id: self.digest.last_event_id,
+            last_event: self.digest.last_event.clone(),
+            game_time: self.game_time,
+            game_clock_anchor: self.game_clock_anchor,
+            game_id: self.game_id,
+            lanes_assigned: self.lanes_assigned,
+            players: self.players.players.values().cloned().collect(),
+        };
+    }
+
+    fn audit(&self, payload: &'static str, unknown: &HashMap<String, Value>) -> Result<()> {
+        audit_unknown_fields(
+            self.config.deserialize_mode,
+            &self.unknown_fields,
+            payload,
+            ignored_fields(payload),
+            unknown,
+        )
+    }
+
+    fn audit_player_list(&self, list: &[PlayerListEntry]) -> Result<()> {
+        for entry in list {
+            self.audit("playerlist", &entry.unknown)?;
+            for item in &entry.items {
+                self.audit("playerlist.items", &item.unknown)?;
+            }
         }
+        Ok(())
     }
 
     async fn poll_once(&mut self) -> Result<PollOutcome> {
         let base = self.config.live_base_url.trim_end_matches('/');
-        let active_url = format!("{base}/liveclientdata/activeplayer");
+        let active_url = format!("{base}/liveclientdata/{ACTIVE_PLAYER}");
         let players_url = format!("{base}/liveclientdata/playerlist");
         let events_url = format!("{base}/liveclientdata/eventdata");
+        let stats_url = format!("{base}/liveclientdata/{GAME_STATS}");
+
+        let players_resp = fetch_endpoint(&self.http, &players_url).await;
+        let events_resp = fetch_endpoint(&self.http, &events_url).await;
+        let players_state = Availability::of(&players_resp);
+        let events_state = Availability::of(&events_resp);
+        let now_ms = timestamp_ms();
+        let mut events = Vec::new();
+
+        self.vitals
+            .set_connected(Source::LiveClient, players_state != Availability::Down);
+        if self.endpoints.observe(players_state, events_state) {
+            // Whatever was tracked belonged to the last game.
+            self.reset_game();
+            events.push(Event::new(
+                EventKind::PhaseChange,
+                now_ms,
+                EventPayload::Phase(PhaseEvent {
+                    phase: LOADING_PHASE.to_string(),
+                }),
+            ));
+        }
+        if self.endpoints.is_loading() {
+            // The client answers but has no game data yet; keep polling without backing off.
+            self.stamp_game(&mut events);
+            return Ok(PollOutcome {
+                events,
+                next_delay: self.config.poll_interval_idle,
+            });
+        }
 
-        let players_resp = match fetch_endpoint(&self.http, &players_url).await {
+        let players_resp = match players_resp {
             Ok(resp) => resp,
             Err(error) => {
                 warn!(?error, "live client playerlist fetch failed");
@@ -67,8 +371,10 @@ impl PollContext {
             }
         };
 
-        let events_resp = match fetch_endpoint(&self.http, &events_url).await {
-            Ok(resp) => resp,
+        // Event data can lag behind the playerlist right after loading; skip it until it exists.
+        let events_resp = match events_resp {
+            Ok(resp) => Some(resp),
+            Err(_) if events_state == Availability::Loading => None,
             Err(error) => {
                 warn!(?error, "live client eventdata fetch failed");
                 let delay = self.activity.on_error(&self.config);
@@ -76,28 +382,60 @@ impl PollContext {
             }
         };
 
-        let now_ms = timestamp_ms();
-        let mut events = Vec::new();
-
         // Fetch active player; if content changed, try to derive skill-level events for the local player.
-        match fetch_endpoint(&self.http, &active_url).await {
-            Ok(resp) => {
-                if self.digest.active_hash != Some(resp.hash) {
-                    let mut diff = self.diff_active_abilities(&resp.body, now_ms);
-                    events.append(&mut diff);
-                    self.digest.active_hash = Some(resp.hash);
+        if !self.budget.skips(ACTIVE_PLAYER) {
+            match fetch_endpoint(&self.http, &active_url).await {
+                Ok(resp) => {
+                    if self.digest.active_hash != Some(resp.hash) {
+                        let mut diff = self.diff_active_player(&resp.body, now_ms);
+                        events.append(&mut diff);
+                        // Retry until the local player shows up in the player list.
+                        if self.active_player.is_some() {
+                            self.digest.active_hash = Some(resp.hash);
+                        }
+                    }
+                }
+                Err(error) => {
+                    trace!(?error, "live client activeplayer probe failed");
                 }
             }
+        }
+
+        match fetch_endpoint(&self.http, &stats_url).await {
+            Ok(resp) => match serde_json::from_slice::<GameStatsResponse>(&resp.body) {
+                Ok(stats) => {
+                    if let Err(error) = self.audit(GAME_STATS, &stats.unknown) {
+                        warn!(?error, "gamestats payload rejected");
+                    } else {
+                        events.extend(self.apply_game_stats(&stats, now_ms));
+                    }
+                }
+                Err(error) => trace!(?error, "failed to parse gamestats response"),
+            },
             Err(error) => {
-                trace!(?error, "live client activeplayer probe failed");
+                trace!(?error, "live client gamestats probe failed");
             }
         }
 
+        let digest_before = (self.digest.players_hash, self.digest.events_hash);
         if self.digest.players_hash != Some(players_resp.hash) {
-            match parse_player_list(&players_resp.body) {
+            match parse_player_list(&players_resp.body)
+                .and_then(|list| self.audit_player_list(&list).map(|_| list))
+            {
                 Ok(list) => {
+                    let levels: Vec<(String, ResourceLevels)> = list
+                        .iter()
+                        .filter_map(|entry| {
+                            let levels = entry.champion_stats.as_ref()?.levels()?;
+                            let name = entry
+                                .riot_id()
+                                .unwrap_or_else(|| entry.summoner_name.clone());
+                            Some((name, levels))
+                        })
+                        .collect();
                     let mut diff_events = self.players.apply(list, now_ms);
                     events.append(&mut diff_events);
+                    events.extend(self.diff_resources(levels, now_ms));
                     self.digest.players_hash = Some(players_resp.hash);
                 }
                 Err(error) => {
@@ -108,11 +446,18 @@ impl PollContext {
             }
         }
 
-        if self.digest.events_hash != Some(events_resp.hash) {
-            match parse_event_list(&events_resp.body) {
+        if let Some(events_resp) =
+            events_resp.filter(|resp| self.digest.events_hash != Some(resp.hash))
+        {
+            match parse_event_list(&events_resp.body).and_then(|list| {
+                list.iter()
+                    .try_for_each(|raw| self.audit("eventdata", &raw.unknown))
+                    .map(|_| list)
+            }) {
                 Ok(mut raw_events) => {
                     if self.digest.should_reset(&raw_events) {
-                        self.digest.last_event_id = None;
+                        debug!("event list started over; starting a new game");
+                        self.reset_game();
                     }
 
                     let next_expected = self.digest.next_event_id();
@@ -121,12 +466,32 @@ impl PollContext {
                         .filter(|raw| raw.event_id >= next_expected)
                         .collect();
 
-                    if let Some(max_id) = new_events.iter().map(|ev| ev.event_id).max() {
-                        self.digest.last_event_id = Some(max_id);
+                    if let Some(last) = new_events.iter().max_by_key(|ev| ev.event_id) {
+                        self.digest.last_event_id = Some(last.event_id);
+                        self.digest.last_event = Some(EventFingerprint::of(last));
                     }
 
-                    let mut normalized = normalize_events(&new_events, &self.players);
+                    tally_objectives(&mut self.tally, &new_events, &self.players);
+                    events.extend(track_dragons(&mut self.dragons, &new_events, &self.players));
+                    events.extend(detect_team_wipes(
+                        &new_events,
+                        &self.players,
+                        self.game_time,
+                    ));
+                    let mut normalized = normalize_events(
+                        &new_events,
+                        &self.players,
+                        &mut self.bounties,
+                        self.config.flattened_kills,
+                    );
+                    self.steals.annotate(&mut normalized);
                     events.append(&mut normalized);
+                    let local_team = self
+                        .active_player
+                        .as_ref()
+                        .and_then(|active| self.players.player_ref(&active.summoner_name))
+                        .map(|reference| reference.team);
+                    events.extend(detect_game_end(&new_events, local_team, &self.series));
                     self.digest.events_hash = Some(events_resp.hash);
                 }
                 Err(error) => {
@@ -137,6 +502,61 @@ impl PollContext {
             }
         }
 
+        if let Some(interval) = self.config.economy_snapshot_interval {
+            if self.last_economy_snapshot.elapsed() >= interval {
+                events.extend(self.players.economy_events(now_ms));
+                self.last_economy_snapshot = Instant::now();
+            }
+        }
+
+        if !self.team_info_sent && !self.players.is_empty() {
+            let mut info = self.config.roster.team_info(self.players.references());
+            let teams = info.clone().unwrap_or_default();
+            if let Some(series) = self.series.begin_game(&teams) {
+                info.get_or_insert(teams).series = Some(series);
+            }
+            if let Some(info) = info {
+                events.push(Event::new(
+                    EventKind::TeamInfo,
+                    now_ms,
+                    EventPayload::TeamInfo(info),
+                ));
+            }
+            self.team_info_sent = true;
+        }
+
+        if self.config.enrichments.lanes
+            && !self.lanes_assigned
+            && self.game_time >= LANE_ASSIGNMENT_GAME_TIME
+            && !self.players.is_empty()
+        {
+            debug!(game_time = self.game_time, "assigning lanes");
+            events.extend(self.players.assign_lanes(now_ms));
+            self.lanes_assigned = true;
+        }
+
+        if !self.players.is_empty() {
+            let scores = self.players.creep_scores();
+            events.extend(self.farm.sample(self.game_time, scores.into_iter(), now_ms));
+        }
+
+        if let Some(interval) = self.config.win_probability_interval {
+            if self.last_win_probability.elapsed() >= interval && !self.players.is_empty() {
+                for team in [Team::Order, Team::Chaos] {
+                    if let Some(stats) = self.tally.stats_mut(&team) {
+                        stats.gold = self.players.team_gold(&team);
+                    }
+                }
+                events.push(self.tally.event(now_ms));
+                self.last_win_probability = Instant::now();
+            }
+        }
+
+        self.stamp_game(&mut events);
+        if digest_before != (self.digest.players_hash, self.digest.events_hash) {
+            self.publish_session();
+        }
+
         events.sort_by_key(|event| event.ts);
         deduplicate_events(&mut events);
 
@@ -146,20 +566,33 @@ impl PollContext {
 }
 
 #[derive(Clone, Debug)]
-struct ActiveAbilitiesSnapshot {
+struct ActivePlayerSnapshot {
     summoner_name: String,
     q: u8,
     w: u8,
     e: u8,
     r: u8,
+    runes: Option<ActiveRunes>,
+    resource: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct ActiveRunes {
+    primary_style_id: u32,
+    sub_style_id: u32,
+    perk_ids: Vec<u32>,
 }
 
 #[derive(Deserialize)]
 struct ActivePlayerAbilities {
-    #[serde(rename = "Q")] q: ActiveAbility,
-    #[serde(rename = "W")] w: ActiveAbility,
-    #[serde(rename = "E")] e: ActiveAbility,
-    #[serde(rename = "R")] r: ActiveAbility,
+    #[serde(rename = "Q")]
+    q: ActiveAbility,
+    #[serde(rename = "W")]
+    w: ActiveAbility,
+    #[serde(rename = "E")]
+    e: ActiveAbility,
+    #[serde(rename = "R")]
+    r: ActiveAbility,
 }
 
 #[derive(Deserialize)]
@@ -173,11 +606,76 @@ struct ActiveAbility {
 struct ActivePlayerResponse {
     #[serde(rename = "summonerName")]
     summoner_name: String,
+    #[serde(rename = "riotId", default)]
+    riot_id: Option<String>,
     abilities: ActivePlayerAbilities,
+    #[serde(rename = "fullRunes")]
+    full_runes: Option<FullRunes>,
+    #[serde(rename = "championStats")]
+    champion_stats: Option<ChampionStats>,
+    #[serde(flatten)]
+    unknown: HashMap<String, Value>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FullRunes {
+    #[serde(default)]
+    general_runes: Vec<RuneEntry>,
+    primary_rune_tree: Option<RuneEntry>,
+    secondary_rune_tree: Option<RuneEntry>,
+    #[serde(default)]
+    stat_runes: Vec<RuneEntry>,
+}
+
+#[derive(Deserialize)]
+struct RuneEntry {
+    id: u32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct ChampionStats {
+    resource_type: Option<String>,
+    current_health: Option<f32>,
+    max_health: Option<f32>,
+    resource_value: Option<f32>,
+    resource_max: Option<f32>,
+}
+
+impl ChampionStats {
+    /// Health and resource bars, when the client reports health.
+    fn levels(&self) -> Option<ResourceLevels> {
+        Some(ResourceLevels {
+            health: self.current_health?,
+            max_health: self.max_health?,
+            resource: self.resource_value.unwrap_or_default(),
+            max_resource: self.resource_max.unwrap_or_default(),
+        })
+    }
+}
+
+impl FullRunes {
+    /// The page in the order of the LCU perk list: keystone and general runes, then shards.
+    fn page(self) -> Option<ActiveRunes> {
+        let perk_ids: Vec<u32> = self
+            .general_runes
+            .iter()
+            .chain(&self.stat_runes)
+            .map(|rune| rune.id)
+            .collect();
+        (!perk_ids.is_empty()).then(|| ActiveRunes {
+            primary_style_id: self.primary_rune_tree.map_or(0, |tree| tree.id),
+            sub_style_id: self.secondary_rune_tree.map_or(0, |tree| tree.id),
+            perk_ids,
+        })
+    }
 }
 
 impl PollContext {
-    fn diff_active_abilities(&mut self, body: &[u8], ts_ms: u64) -> Vec<Event> {
+    /// Skill level-ups of the active player, and its runes and resource type when they are
+    /// first seen or change.
+    fn diff_active_player(&mut self, body: &[u8], ts_ms: u64) -> Vec<Event> {
         let mut out = Vec::new();
         let parsed: Result<ActivePlayerResponse, _> = serde_json::from_slice(body);
         let parsed = match parsed {
@@ -185,21 +683,50 @@ impl PollContext {
             Err(_) => return out,
         };
 
+        if let Err(error) = self.audit("activeplayer", &parsed.unknown) {
+            warn!(?error, "activeplayer payload rejected");
+            return out;
+        }
+
         // Resolve PlayerRef if we know this player from the registry
-        let player_ref = match self.players.player_ref(&parsed.summoner_name) {
+        let name = parsed
+            .riot_id
+            .filter(|riot_id| !riot_id.is_empty())
+            .unwrap_or(parsed.summoner_name);
+        self.players.set_active(&name);
+        let player_ref = match self.players.player_ref(&name) {
             Some(reference) => reference,
             None => return out,
         };
 
-        let current = ActiveAbilitiesSnapshot {
-            summoner_name: parsed.summoner_name.clone(),
+        let current = ActivePlayerSnapshot {
+            summoner_name: name,
             q: parsed.abilities.q.level,
             w: parsed.abilities.w.level,
             e: parsed.abilities.e.level,
             r: parsed.abilities.r.level,
+            runes: parsed.full_runes.and_then(FullRunes::page),
+            resource: parsed
+                .champion_stats
+                .as_ref()
+                .and_then(|stats| stats.resource_type.clone())
+                .filter(|resource| !resource.is_empty()),
         };
-
-        if let Some(prev) = &self.active_skills {
+        let levels = parsed
+            .champion_stats
+            .as_ref()
+            .and_then(ChampionStats::levels);
+        out.extend(self.diff_resources(
+            levels.map(|levels| (registry_key(&player_ref), levels)),
+            ts_ms,
+        ));
+
+        // Only diff against a snapshot of the same player; a new game may swap the local summoner.
+        let previous = self
+            .active_player
+            .as_ref()
+            .filter(|prev| prev.summoner_name == current.summoner_name);
+        if let Some(prev) = previous {
             let diffs = [
                 (AbilitySlot::Q, current.q, prev.q),
                 (AbilitySlot::W, current.w, prev.w),
@@ -209,22 +736,130 @@ impl PollContext {
 
             for (slot, now, before) in diffs {
                 if now > before {
-                    out.push(Event {
-                        kind: EventKind::SkillLevelUp,
-                        ts: ts_ms,
-                        payload: EventPayload::PlayerSkillLevel(SkillLevelEvent {
+                    out.push(Event::new(
+                        EventKind::SkillLevelUp,
+                        ts_ms,
+                        EventPayload::PlayerSkillLevel(SkillLevelEvent {
                             player: player_ref.clone(),
                             ability: slot,
                             level: now,
                         }),
-                    });
+                    ));
                 }
             }
         }
 
-        self.active_skills = Some(current);
+        if let Some(runes) = current
+            .runes
+            .as_ref()
+            .filter(|runes| previous.and_then(|prev| prev.runes.as_ref()) != Some(*runes))
+        {
+            out.push(Event::new(
+                EventKind::ActivePlayerRunes,
+                ts_ms,
+                EventPayload::ActiveRunes(ActiveRunesEvent {
+                    player: player_ref.clone(),
+                    primary_style_id: runes.primary_style_id,
+                    sub_style_id: runes.sub_style_id,
+                    perk_ids: runes.perk_ids.clone(),
+                }),
+            ));
+        }
+        if let Some(resource) = current
+            .resource
+            .as_ref()
+            .filter(|resource| previous.and_then(|prev| prev.resource.as_ref()) != Some(*resource))
+        {
+            out.push(Event::new(
+                EventKind::ActivePlayerResource,
+                ts_ms,
+                EventPayload::Resource(ResourceEvent {
+                    player: player_ref,
+                    resource: resource.clone(),
+                }),
+            ));
+        }
+
+        self.active_player = Some(current);
         out
     }
+
+    /// `ResourceChanged` events for bars that moved past their thresholds, when high-frequency
+    /// events are on; `levels` are keyed like the player registry.
+    fn diff_resources(
+        &mut self,
+        levels: impl IntoIterator<Item = (String, ResourceLevels)>,
+        ts_ms: u64,
+    ) -> Vec<Event> {
+        if !self.config.high_frequency_events {
+            return Vec::new();
+        }
+        levels
+            .into_iter()
+            .filter_map(|(name, levels)| {
+                let player = self.players.player_ref(&name)?;
+                self.resources.observe(&name, &player, levels, ts_ms)
+            })
+            .collect()
+    }
+
+    /// Follow the game clock in `stats`, starting a new game when it runs backwards.
+    fn apply_game_stats(&mut self, stats: &GameStatsResponse, now_ms: u64) -> Option<Event> {
+        if stats.game_time + GAME_CLOCK_REWIND < self.game_time {
+            debug!(
+                from = self.game_time,
+                to = stats.game_time,
+                "game clock went backwards; starting a new game"
+            );
+            self.reset_game();
+            // Baseline the new roster instead of diffing it against the old one.
+            self.players.clear();
+            self.digest.players_hash = None;
+            self.digest.events_hash = None;
+        }
+        self.game_time = stats.game_time;
+        // Re-anchored every poll, so a paused game clock is caught up.
+        self.game_clock_anchor = Some(now_ms.saturating_sub(seconds_to_millis(stats.game_time)));
+        self.session.lock().game_clock_anchor = self.game_clock_anchor;
+        self.diff_game_metadata(stats, now_ms)
+    }
+
+    /// A `gameMetadata` event when `stats` report another mode or map than last seen this game.
+    fn diff_game_metadata(&mut self, stats: &GameStatsResponse, ts_ms: u64) -> Option<Event> {
+        if stats.game_mode.is_empty() {
+            return None;
+        }
+        let metadata = GameMetadataEvent {
+            game_mode: stats.game_mode.clone(),
+            map_number: stats.map_number,
+            map_name: stats.map_name.clone(),
+            // `Default` until the rift transforms.
+            map_terrain: Some(stats.map_terrain.clone())
+                .filter(|terrain| !terrain.is_empty() && terrain != "Default"),
+            game_time_ms: seconds_to_millis(stats.game_time),
+        };
+        if self
+            .game_metadata
+            .as_ref()
+            .is_some_and(|last| same_game_metadata(last, &metadata))
+        {
+            return None;
+        }
+        self.game_metadata = Some(metadata.clone());
+        Some(Event::new(
+            EventKind::GameMetadata,
+            ts_ms,
+            EventPayload::GameMetadata(metadata),
+        ))
+    }
+}
+
+/// Whether `a` and `b` name the same mode and map, whatever the game clock.
+fn same_game_metadata(a: &GameMetadataEvent, b: &GameMetadataEvent) -> bool {
+    a.game_mode == b.game_mode
+        && a.map_number == b.map_number
+        && a.map_name == b.map_name
+        && a.map_terrain == b.map_terrain
 }
 
 #[derive(Default)]
@@ -233,6 +868,25 @@ struct DigestState {
     players_hash: Option<u64>,
     events_hash: Option<u64>,
     last_event_id: Option<u64>,
+    /// What the event with `last_event_id` looked like, to notice a new game reusing the id.
+    last_event: Option<EventFingerprint>,
+}
+
+/// Name and game time of an event; ids restart every game, the pair practically never repeats.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EventFingerprint {
+    name: String,
+    time: f64,
+}
+
+impl EventFingerprint {
+    fn of(event: &RawEvent) -> Self {
+        Self {
+            name: event.event_name.clone(),
+            time: event.event_time,
+        }
+    }
 }
 
 impl DigestState {
@@ -240,24 +894,78 @@ impl DigestState {
         self.last_event_id.map(|value| value + 1).unwrap_or(0)
     }
 
+    /// Whether `events` belong to a new game: the event last seen is listed with another name
+    /// or time, or the list starts over with a fresh `GameStart` and no longer reaches it.
+    /// Every list opens with `GameStart`, so that alone proves nothing.
+    ///
+    /// A game that loads fast can list more events than the previous one by the first poll;
+    /// only the fingerprint catches that.
     fn should_reset(&self, events: &[RawEvent]) -> bool {
-        if self.last_event_id.is_none() {
+        let Some(last_event_id) = self.last_event_id else {
             return false;
+        };
+
+        if let Some(seen) = &self.last_event {
+            let listed = events.iter().find(|event| event.event_id == last_event_id);
+            if listed.is_some_and(|event| EventFingerprint::of(event) != *seen) {
+                return true;
+            }
         }
 
-        events
-            .iter()
-            .any(|event| event.event_id == 0 && event.event_time < 5.0)
+        events.iter().all(|event| event.event_id < last_event_id)
+            && events
+                .iter()
+                .any(|event| event.event_id == 0 && event.event_time < 5.0)
     }
 }
 
 #[derive(Default)]
 struct PlayerRegistry {
     players: HashMap<String, PlayerSnapshot>,
+    budget: MemoryBudget,
+    meter: RegistryMeter,
+    score_deltas: ScoreDeltas,
+    per_unit_items: bool,
+    /// Summoner the Live Client runs for, once `activeplayer` named them.
+    active_player: Option<String>,
 }
 
 impl PlayerRegistry {
-    fn apply(&mut self, entries: Vec<PlayerListEntry>, ts_ms: u64) -> Vec<Event> {
+    fn with_budget(budget: MemoryBudget, meter: RegistryMeter) -> Self {
+        Self {
+            players: HashMap::new(),
+            budget,
+            meter,
+            score_deltas: ScoreDeltas::default(),
+            per_unit_items: false,
+            active_player: None,
+        }
+    }
+
+    /// Mark `name` as the active player on their reference, now and in later playerlists.
+    fn set_active(&mut self, name: &str) {
+        if self.active_player.as_deref() == Some(name) {
+            return;
+        }
+        for snapshot in self.players.values_mut() {
+            snapshot.reference.active_player = snapshot.reference.answers_to(name);
+        }
+        self.active_player = Some(name.to_string());
+    }
+
+    fn with_score_deltas(mut self, score_deltas: ScoreDeltas) -> Self {
+        self.score_deltas = score_deltas;
+        self
+    }
+
+    fn with_per_unit_items(mut self, per_unit_items: bool) -> Self {
+        self.per_unit_items = per_unit_items;
+        self
+    }
+
+    fn apply(&mut self, mut entries: Vec<PlayerListEntry>, ts_ms: u64) -> Vec<Event> {
+        let mut evicted = entries.len().saturating_sub(self.budget.max_players);
+        entries.truncate(self.budget.max_players);
         let mut new_players = HashMap::with_capacity(entries.len());
         let mut events = Vec::new();
         let mut used_slots: HashSet<u8> = self
@@ -268,8 +976,14 @@ impl PlayerRegistry {
 
         for entry in entries {
             let team = parse_team(&entry.team);
-            let name = entry.summoner_name.clone();
-            let previous = self.players.get(&name);
+            let name = entry
+                .riot_id()
+                .unwrap_or_else(|| entry.summoner_name.clone());
+            // Entries saved before the client reported Riot IDs are keyed on the summoner name.
+            let previous = self
+                .players
+                .get(&name)
+                .or_else(|| self.players.get(&entry.summoner_name));
 
             let slot = previous
                 .map(|snapshot| snapshot.reference.slot)
@@ -277,9 +991,16 @@ impl PlayerRegistry {
 
             used_slots.insert(slot);
 
-            let snapshot = PlayerSnapshot::from_entry(entry, team, slot);
+            let mut snapshot = PlayerSnapshot::from_entry(entry, team, slot);
+            snapshot.reference.active_player = self
+                .active_player
+                .as_deref()
+                .is_some_and(|active| snapshot.reference.answers_to(active));
+            evicted += snapshot.cap_items(self.budget.max_items_per_player);
             if let Some(prev) = previous {
-                let mut diff = snapshot.diff(prev, ts_ms);
+                snapshot.reference.lane = prev.reference.lane.clone();
+                snapshot.accumulate_economy(prev);
+                let mut diff = snapshot.diff(prev, &self.score_deltas, self.per_unit_items, ts_ms);
                 events.append(&mut diff);
             }
 
@@ -287,32 +1008,190 @@ impl PlayerRegistry {
         }
 
         self.players = new_players;
+        if evicted > 0 {
+            warn!(
+                evicted,
+                "playerlist exceeds the memory budget; extra entries ignored"
+            );
+            self.meter.evicted(evicted);
+        }
+        let items = self.players.values().map(|snapshot| snapshot.items.len());
+        self.meter.set(self.players.len(), items.sum());
         events
     }
 
+    fn economy_events(&self, ts_ms: u64) -> Vec<Event> {
+        let mut snapshots: Vec<&PlayerSnapshot> = self.players.values().collect();
+        snapshots.sort_by_key(|snapshot| snapshot.reference.slot);
+        snapshots
+            .into_iter()
+            .map(|snapshot| {
+                Event::new(
+                    EventKind::EconomySnapshot,
+                    ts_ms,
+                    EventPayload::PlayerEconomy(EconomyEvent {
+                        player: snapshot.reference.clone(),
+                        earned: snapshot.gold_earned,
+                        spent: snapshot.gold_spent,
+                        current: snapshot.current_gold,
+                    }),
+                )
+            })
+            .collect()
+    }
+
+    fn references(&self) -> impl Iterator<Item = &PlayerRef> {
+        self.players.values().map(|snapshot| &snapshot.reference)
+    }
+
+    /// Creep score of every player, ordered by slot.
+    fn creep_scores(&self) -> Vec<(&PlayerRef, u32)> {
+        let mut scores: Vec<(&PlayerRef, u32)> = self
+            .players
+            .values()
+            .map(|snapshot| (&snapshot.reference, snapshot.creep_score))
+            .collect();
+        scores.sort_by_key(|(reference, _)| reference.slot);
+        scores
+    }
+
+    /// Infer every player's lane, stamp it on their reference and report the assignment.
+    fn assign_lanes(&mut self, ts_ms: u64) -> Vec<Event> {
+        for team in [Team::Order, Team::Chaos] {
+            let mut members: Vec<&mut PlayerSnapshot> = self
+                .players
+                .values_mut()
+                .filter(|snapshot| snapshot.reference.team == team)
+                .collect();
+            members.sort_by_key(|snapshot| snapshot.reference.slot);
+
+            let hints: Vec<LaneHints> = members
+                .iter()
+                .map(|snapshot| snapshot.lane_hints())
+                .collect();
+            for (snapshot, lane) in members.into_iter().zip(assign_lanes(&hints)) {
+                snapshot.reference.lane = Some(lane);
+            }
+        }
+
+        let mut players: Vec<PlayerRef> = self
+            .players
+            .values()
+            .filter(|snapshot| snapshot.reference.lane.is_some())
+            .map(|snapshot| snapshot.reference.clone())
+            .collect();
+        if players.is_empty() {
+            return Vec::new();
+        }
+        players.sort_by_key(|reference| reference.slot);
+
+        vec![Event::new(
+            EventKind::LaneAssignment,
+            ts_ms,
+            EventPayload::LaneAssignment(LaneAssignmentEvent { players }),
+        )]
+    }
+
+    fn is_empty(&self) -> bool {
+        self.players.is_empty()
+    }
+
+    fn clear(&mut self) {
+        self.players.clear();
+        self.meter.set(0, 0);
+    }
+
+    /// Replace the registry with snapshots saved from an earlier run.
+    fn restore(&mut self, players: Vec<PlayerSnapshot>) {
+        self.players = players
+            .into_iter()
+            .take(self.budget.max_players)
+            .map(|snapshot| (registry_key(&snapshot.reference), snapshot))
+            .collect();
+        let items = self.players.values().map(|snapshot| snapshot.items.len());
+        self.meter.set(self.players.len(), items.sum());
+    }
+
+    /// Shortest remaining respawn timer among dead members of `team`.
+    fn shortest_respawn(&self, team: &Team) -> Option<f64> {
+        self.players
+            .values()
+            .filter(|snapshot| snapshot.reference.team == *team && snapshot.is_dead)
+            .map(|snapshot| snapshot.respawn_timer)
+            .min_by(f64::total_cmp)
+    }
+
+    /// Gold held plus the value of items owned by every player on `team`.
+    fn team_gold(&self, team: &Team) -> i64 {
+        self.players
+            .values()
+            .filter(|snapshot| &snapshot.reference.team == team)
+            .map(|snapshot| i64::from(snapshot.current_gold) + snapshot.inventory_value())
+            .sum()
+    }
+
+    /// Player named `name` by their Riot ID, its game name or their summoner name; event data
+    /// names players by whichever the client version uses.
     fn player_ref(&self, name: &str) -> Option<PlayerRef> {
         self.players
             .get(name)
+            .or_else(|| {
+                self.players
+                    .values()
+                    .find(|snapshot| snapshot.reference.answers_to(name))
+            })
             .map(|snapshot| snapshot.reference.clone())
     }
 }
 
-#[derive(Clone)]
+/// Registry key of a player: the Riot ID, or the summoner name on clients without one.
+fn registry_key(reference: &PlayerRef) -> String {
+    reference
+        .riot_id
+        .clone()
+        .unwrap_or_else(|| reference.summoner_name.clone())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 struct PlayerSnapshot {
     reference: PlayerRef,
     level: u8,
     current_gold: i32,
     is_dead: bool,
-    items: HashMap<u32, ItemEntry>,
+    /// Seconds until respawn as of the latest playerlist; zero while alive.
+    respawn_timer: f64,
+    creep_score: u32,
+    vision_score: f32,
+    /// Scores as of the last `CsDelta` and `VisionScoreDelta` events.
+    #[serde(default)]
+    reported_creep_score: u32,
+    #[serde(default)]
+    reported_vision_score: f32,
+    /// Inventory keyed by slot index.
+    items: BTreeMap<u8, ItemEntry>,
+    gold_earned: u32,
+    gold_spent: u32,
+    position: Option<String>,
+    spells: Vec<String>,
 }
 
 impl PlayerSnapshot {
     fn from_entry(entry: PlayerListEntry, team: Team, slot: u8) -> Self {
-        let reference = PlayerRef {
-            summoner_name: entry.summoner_name.clone(),
-            team,
-            slot,
-        };
+        let mut reference = PlayerRef::new(entry.summoner_name.clone(), team, slot);
+        reference.riot_id = entry.riot_id();
+        reference.champion_name = entry.champion_name();
+        reference.skin_id = entry.skin_id;
+        let spells = entry
+            .summoner_spells
+            .map(|spells| {
+                [spells.first, spells.second]
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|spell| spell.raw_display_name.or(spell.display_name))
+                    .collect()
+            })
+            .unwrap_or_default();
 
         let current_gold = entry
             .current_gold
@@ -325,115 +1204,298 @@ impl PlayerSnapshot {
             level: entry.level.min(u8::MAX as u32) as u8,
             current_gold,
             is_dead: entry.is_dead,
+            respawn_timer: entry.respawn_timer,
+            creep_score: entry.scores.creep_score,
+            vision_score: entry.scores.ward_score,
+            reported_creep_score: entry.scores.creep_score,
+            reported_vision_score: entry.scores.ward_score,
             items: fold_items(entry.items),
+            gold_earned: 0,
+            gold_spent: 0,
+            position: entry.position.filter(|position| !position.is_empty()),
+            spells,
+        }
+    }
+
+    /// Drop items beyond the first `max` slots and return how many were dropped.
+    fn cap_items(&mut self, max: usize) -> usize {
+        let mut dropped = 0;
+        while self.items.len() > max {
+            self.items.pop_last();
+            dropped += 1;
+        }
+        dropped
+    }
+
+    fn lane_hints(&self) -> LaneHints {
+        LaneHints {
+            position: self.position.clone(),
+            spells: self.spells.clone(),
+            item_ids: self.items.values().map(|entry| entry.item_id).collect(),
         }
     }
 
-    fn diff(&self, previous: &PlayerSnapshot, ts_ms: u64) -> Vec<Event> {
+    /// Carry the economy totals forward from `previous` and add this poll's purchases.
+    ///
+    /// Inventory value only grows through purchases (completing an item consumes components
+    /// worth part of its price), so a positive value delta is treated as gold spent. Sells,
+    /// undos and consumed stacks shrink the inventory without counting as negative spend.
+    fn accumulate_economy(&mut self, previous: &PlayerSnapshot) {
+        let spent = (self.inventory_value() - previous.inventory_value()).max(0);
+        let gold_delta = i64::from(self.current_gold) - i64::from(previous.current_gold);
+        let earned = (gold_delta + spent).max(0);
+
+        self.gold_spent = previous.gold_spent.saturating_add(clamp_u32(spent));
+        self.gold_earned = previous.gold_earned.saturating_add(clamp_u32(earned));
+    }
+
+    fn inventory_value(&self) -> i64 {
+        self.items
+            .values()
+            .map(|entry| i64::from(entry.price) * i64::from(entry.count))
+            .sum()
+    }
+
+    fn diff(
+        &mut self,
+        previous: &PlayerSnapshot,
+        deltas: &ScoreDeltas,
+        per_unit_items: bool,
+        ts_ms: u64,
+    ) -> Vec<Event> {
         let mut events = Vec::new();
 
         if self.level > previous.level {
-            events.push(Event {
-                kind: EventKind::LevelUp,
-                ts: ts_ms,
-                payload: EventPayload::PlayerLevel(LevelEvent {
+            events.push(Event::new(
+                EventKind::LevelUp,
+                ts_ms,
+                EventPayload::PlayerLevel(LevelEvent {
                     player: self.reference.clone(),
                     level: self.level,
                 }),
-            });
+            ));
         }
 
         let gold_delta = self.current_gold - previous.current_gold;
         if gold_delta != 0 {
-            events.push(Event {
-                kind: EventKind::GoldDelta,
-                ts: ts_ms,
-                payload: EventPayload::PlayerGold(GoldEvent {
+            events.push(Event::new(
+                EventKind::GoldDelta,
+                ts_ms,
+                EventPayload::PlayerGold(GoldEvent {
                     player: self.reference.clone(),
                     delta: gold_delta,
                     total: self.current_gold,
                 }),
-            });
+            ));
         }
 
         if previous.is_dead && !self.is_dead {
-            events.push(Event {
-                kind: EventKind::Respawn,
-                ts: ts_ms,
-                payload: EventPayload::Player(PlayerEvent {
+            events.push(Event::new(
+                EventKind::Respawn,
+                ts_ms,
+                EventPayload::Player(PlayerEvent {
                     player: self.reference.clone(),
+                    bounty: None,
                 }),
-            });
+            ));
         }
 
-        events.extend(self.diff_items(previous, ts_ms));
+        events.extend(self.diff_items(previous, per_unit_items, ts_ms));
+        events.extend(self.diff_scores(previous, deltas, ts_ms));
 
         events
     }
 
-    fn diff_items(&self, previous: &PlayerSnapshot, ts_ms: u64) -> Vec<Event> {
+    /// `CsDelta` and `VisionScoreDelta` events for scores that grew by at least their minimum
+    /// delta since they were last reported.
+    fn diff_scores(
+        &mut self,
+        previous: &PlayerSnapshot,
+        deltas: &ScoreDeltas,
+        ts_ms: u64,
+    ) -> Vec<Event> {
         let mut events = Vec::new();
-        let mut remaining = previous.items.clone();
+        // A score below the last reported one means the client started over; rebase on it.
+        self.reported_creep_score = previous.reported_creep_score.min(self.creep_score);
+        self.reported_vision_score = previous.reported_vision_score.min(self.vision_score);
+
+        let creep_delta = self.creep_score - self.reported_creep_score;
+        if deltas
+            .creep_score
+            .is_some_and(|min| creep_delta > 0 && creep_delta >= min)
+        {
+            events.push(self.score_event(
+                EventKind::CsDelta,
+                creep_delta as f32,
+                self.creep_score as f32,
+                ts_ms,
+            ));
+            self.reported_creep_score = self.creep_score;
+        }
+
+        let vision_delta = self.vision_score - self.reported_vision_score;
+        if deltas
+            .vision_score
+            .is_some_and(|min| vision_delta > 0.0 && vision_delta >= min)
+        {
+            events.push(self.score_event(
+                EventKind::VisionScoreDelta,
+                vision_delta,
+                self.vision_score,
+                ts_ms,
+            ));
+            self.reported_vision_score = self.vision_score;
+        }
+
+        events
+    }
 
-        for (item_id, new_entry) in &self.items {
-            match remaining.remove(item_id) {
-                Some(old_entry) => {
-                    if new_entry.count > old_entry.count {
-                        push_item_events(
+    fn score_event(&self, kind: EventKind, delta: f32, total: f32, ts_ms: u64) -> Event {
+        Event::new(
+            kind,
+            ts_ms,
+            EventPayload::PlayerScore(ScoreEvent {
+                player: self.reference.clone(),
+                delta,
+                total,
+            }),
+        )
+    }
+
+    /// Item events between `previous` and this snapshot; `per_unit` sends one event per unit
+    /// added or removed instead of one per slot.
+    fn diff_items(&self, previous: &PlayerSnapshot, per_unit: bool, ts_ms: u64) -> Vec<Event> {
+        let mut events = Vec::new();
+        let mut removed: Vec<(u8, &ItemEntry)> = Vec::new();
+        let mut added: Vec<(u8, &ItemEntry)> = Vec::new();
+
+        for (slot, old_entry) in &previous.items {
+            match self.items.get(slot) {
+                Some(new_entry) if new_entry.item_id == old_entry.item_id => {
+                    if per_unit && new_entry.count > old_entry.count {
+                        push_item_units(
                             &mut events,
                             ts_ms,
                             &self.reference,
                             EventKind::ItemAdded,
-                            *item_id,
-                            new_entry.name.clone().or(old_entry.name.clone()),
+                            *slot,
+                            new_entry,
                             new_entry.count - old_entry.count,
                         );
-                    } else if new_entry.count < old_entry.count {
-                        push_item_events(
+                    } else if per_unit && new_entry.count < old_entry.count {
+                        push_item_units(
                             &mut events,
                             ts_ms,
                             &self.reference,
                             EventKind::ItemRemoved,
-                            *item_id,
-                            old_entry.name.clone().or(new_entry.name.clone()),
+                            *slot,
+                            new_entry,
                             old_entry.count - new_entry.count,
                         );
+                    } else if new_entry.count != old_entry.count {
+                        push_item_event(
+                            &mut events,
+                            ts_ms,
+                            &self.reference,
+                            EventKind::ItemStackChanged,
+                            *slot,
+                            new_entry,
+                            None,
+                        );
                     }
                 }
-                None => {
-                    push_item_events(
-                        &mut events,
-                        ts_ms,
-                        &self.reference,
-                        EventKind::ItemAdded,
-                        *item_id,
-                        new_entry.name.clone(),
-                        new_entry.count,
-                    );
-                }
+                _ => removed.push((*slot, old_entry)),
+            }
+        }
+
+        for (slot, new_entry) in &self.items {
+            match previous.items.get(slot) {
+                Some(old_entry) if old_entry.item_id == new_entry.item_id => {}
+                _ => added.push((*slot, new_entry)),
             }
         }
 
-        for (item_id, old_entry) in remaining {
-            push_item_events(
+        // An item that left one slot and appeared in another was moved, not sold and rebought.
+        added.retain(|(slot, new_entry)| {
+            let Some(index) = removed
+                .iter()
+                .position(|(_, old_entry)| old_entry.item_id == new_entry.item_id)
+            else {
+                return true;
+            };
+            let (previous_slot, _) = removed.remove(index);
+            push_item_event(
                 &mut events,
                 ts_ms,
                 &self.reference,
-                EventKind::ItemRemoved,
-                item_id,
-                old_entry.name,
-                old_entry.count,
+                EventKind::ItemMoved,
+                *slot,
+                new_entry,
+                Some(previous_slot),
             );
+            false
+        });
+
+        for (slot, new_entry) in added {
+            if per_unit {
+                push_item_units(
+                    &mut events,
+                    ts_ms,
+                    &self.reference,
+                    EventKind::ItemAdded,
+                    slot,
+                    new_entry,
+                    new_entry.count,
+                );
+            } else {
+                push_item_event(
+                    &mut events,
+                    ts_ms,
+                    &self.reference,
+                    EventKind::ItemAdded,
+                    slot,
+                    new_entry,
+                    None,
+                );
+            }
+        }
+
+        for (slot, old_entry) in removed {
+            if per_unit {
+                push_item_units(
+                    &mut events,
+                    ts_ms,
+                    &self.reference,
+                    EventKind::ItemRemoved,
+                    slot,
+                    old_entry,
+                    old_entry.count,
+                );
+            } else {
+                push_item_event(
+                    &mut events,
+                    ts_ms,
+                    &self.reference,
+                    EventKind::ItemRemoved,
+                    slot,
+                    old_entry,
+                    None,
+                );
+            }
         }
 
         events
     }
 }
 
-#[derive(Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 struct ItemEntry {
+    item_id: u32,
     count: u32,
     name: Option<String>,
+    /// Unit price reported by the client.
+    price: u32,
 }
 
 struct ActivityState {
@@ -497,6 +1559,40 @@ enum PollActivity {
     Idle,
 }
 
+/// Sheds optional endpoints while polls take longer than the interval they run at, so a
+/// client that answers slowly (e.g. while the game thrashes the disk) cannot leave the
+/// poller permanently behind.
+#[derive(Debug, Default)]
+struct PollBudget {
+    /// How many of [`SKIPPABLE_ENDPOINTS`] the next poll leaves out.
+    skipped: usize,
+}
+
+impl PollBudget {
+    /// Account for a poll that took `elapsed` at `interval`; returns whether it overran.
+    ///
+    /// Every overrun sheds one more endpoint. One comes back only once a poll takes less than
+    /// half the interval, so the poller does not flap between skipping and overrunning.
+    fn record(&mut self, elapsed: Duration, interval: Duration) -> bool {
+        if elapsed > interval {
+            self.skipped = (self.skipped + 1).min(SKIPPABLE_ENDPOINTS.len());
+            return true;
+        }
+        if elapsed < interval / 2 {
+            self.skipped = self.skipped.saturating_sub(1);
+        }
+        false
+    }
+
+    fn skipped(&self) -> &[&'static str] {
+        &SKIPPABLE_ENDPOINTS[..self.skipped]
+    }
+
+    fn skips(&self, endpoint: &str) -> bool {
+        self.skipped().contains(&endpoint)
+    }
+}
+
 struct PollOutcome {
     events: Vec<Event>,
     next_delay: Duration,
@@ -515,6 +1611,18 @@ impl PollOutcome {
 struct PlayerListEntry {
     #[serde(rename = "summonerName")]
     summoner_name: String,
+    #[serde(rename = "riotId", default)]
+    riot_id: Option<String>,
+    #[serde(rename = "riotIdGameName", default)]
+    riot_id_game_name: Option<String>,
+    #[serde(rename = "riotIdTagLine", default)]
+    riot_id_tag_line: Option<String>,
+    #[serde(rename = "championName", default)]
+    champion_name: Option<String>,
+    #[serde(rename = "rawChampionName", default)]
+    raw_champion_name: Option<String>,
+    #[serde(rename = "skinID", default)]
+    skin_id: Option<u32>,
     #[serde(rename = "team")]
     team: String,
     #[serde(rename = "level")]
@@ -523,16 +1631,107 @@ struct PlayerListEntry {
     current_gold: Option<f64>,
     #[serde(rename = "isDead")]
     is_dead: bool,
+    #[serde(rename = "respawnTimer", default)]
+    respawn_timer: f64,
+    #[serde(default)]
+    scores: PlayerScores,
     #[serde(default)]
     items: Vec<PlayerItemEntry>,
+    #[serde(rename = "position", default)]
+    position: Option<String>,
+    #[serde(rename = "summonerSpells", default)]
+    summoner_spells: Option<SummonerSpells>,
+    /// Only reported for every player on newer patches.
+    #[serde(rename = "championStats", default)]
+    champion_stats: Option<ChampionStats>,
+    #[serde(flatten)]
+    unknown: HashMap<String, Value>,
 }
 
-#[derive(Debug, Deserialize, Clone)]
-struct PlayerItemEntry {
-    #[serde(rename = "itemID")]
+impl PlayerListEntry {
+    /// `gameName#tagLine`, from `riotId` or its two halves.
+    fn riot_id(&self) -> Option<String> {
+        let riot_id = self.riot_id.clone().filter(|riot_id| !riot_id.is_empty());
+        riot_id.or_else(|| match (&self.riot_id_game_name, &self.riot_id_tag_line) {
+            (Some(game), Some(tag)) if !game.is_empty() && !tag.is_empty() => {
+                Some(format!("{game}#{tag}"))
+            }
+            _ => None,
+        })
+    }
+
+    /// Localized champion name, or the internal one from `rawChampionName`
+    /// (`game_character_displayname_MonkeyKing`) when the client left it empty.
+    fn champion_name(&self) -> Option<String> {
+        let name = self.champion_name.clone().filter(|name| !name.is_empty());
+        name.or_else(|| {
+            let raw = self.raw_champion_name.as_deref()?;
+            let key = raw
+                .strip_prefix("game_character_displayname_")
+                .unwrap_or(raw);
+            (!key.is_empty()).then(|| key.to_string())
+        })
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Clone)]
+struct PlayerScores {
+    #[serde(rename = "creepScore", default)]
+    creep_score: u32,
+    #[serde(rename = "wardScore", default)]
+    ward_score: f32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct SummonerSpells {
+    #[serde(rename = "summonerSpellOne")]
+    first: Option<SummonerSpell>,
+    #[serde(rename = "summonerSpellTwo")]
+    second: Option<SummonerSpell>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct SummonerSpell {
+    #[serde(rename = "displayName")]
+    display_name: Option<String>,
+    #[serde(rename = "rawDisplayName")]
+    raw_display_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GameStatsResponse {
+    #[serde(rename = "gameTime")]
+    game_time: f64,
+    #[serde(rename = "gameMode", default)]
+    game_mode: String,
+    #[serde(rename = "mapNumber", default)]
+    map_number: u32,
+    #[serde(rename = "mapName", default)]
+    map_name: String,
+    #[serde(rename = "mapTerrain", default)]
+    map_terrain: String,
+    #[serde(flatten)]
+    unknown: HashMap<String, Value>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct PlayerItemEntry {
+    #[serde(rename = "itemID")]
     item_id: u32,
     #[serde(rename = "displayName")]
     display_name: Option<String>,
+    #[serde(rename = "slot")]
+    slot: Option<u8>,
+    #[serde(rename = "count", default = "default_item_count")]
+    count: u32,
+    #[serde(rename = "price", default)]
+    price: u32,
+    #[serde(flatten)]
+    unknown: HashMap<String, Value>,
+}
+
+fn default_item_count() -> u32 {
+    1
 }
 
 #[derive(Debug, Deserialize)]
@@ -541,7 +1740,7 @@ struct EventListResponse {
     events: Vec<RawEvent>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Default, Deserialize)]
 struct RawEvent {
     #[serde(rename = "EventID")]
     event_id: u64,
@@ -557,12 +1756,33 @@ struct RawEvent {
     assisters: Vec<String>,
     #[serde(rename = "SummonerName")]
     summoner_name: Option<String>,
+    #[serde(rename = "TurretKilled")]
+    turret_killed: Option<String>,
+    #[serde(rename = "InhibKilled")]
+    inhib_killed: Option<String>,
+    #[serde(rename = "DragonType")]
+    dragon_type: Option<String>,
+    #[serde(rename = "AcingTeam")]
+    acing_team: Option<String>,
+    /// `Double` to `Penta`, `FirstBlood` or `Shutdown` on `ChampionSpecialKill`.
+    #[serde(rename = "KillType")]
+    kill_type: Option<String>,
+    #[serde(rename = "KillStreak")]
+    kill_streak: Option<u32>,
+    /// `True` or `False` on epic monster kills; a string in the client's payload.
+    #[serde(rename = "Stolen")]
+    stolen: Option<Value>,
+    /// `Win` or `Lose` on `GameEnd`, from the local player's point of view.
+    #[serde(rename = "Result")]
+    result: Option<String>,
     #[serde(rename = "Level")]
     _level: Option<u32>,
     #[serde(rename = "ItemID")]
     _item_id: Option<u32>,
     #[serde(rename = "ItemName")]
     _item_name: Option<String>,
+    #[serde(flatten)]
+    unknown: HashMap<String, Value>,
 }
 
 #[derive(Debug)]
@@ -571,6 +1791,73 @@ struct FetchResponse {
     body: Vec<u8>,
 }
 
+/// The client answered, but without the data yet: the endpoint 404s (or 503s) on the loading
+/// screen.
+#[derive(Debug)]
+struct NotReady {
+    url: String,
+    status: StatusCode,
+}
+
+impl std::fmt::Display for NotReady {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GET {} -> {} (not ready yet)", self.url, self.status)
+    }
+}
+
+impl std::error::Error for NotReady {}
+
+/// Whether a Live Client endpoint is serving data.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum Availability {
+    #[default]
+    Unknown,
+    Up,
+    /// The client is running but has not loaded this data yet.
+    Loading,
+    /// The request failed outright: no client, a broken response or an unexpected status.
+    Down,
+}
+
+impl Availability {
+    fn of(result: &Result<FetchResponse>) -> Self {
+        match result {
+            Ok(_) => Availability::Up,
+            Err(error) if error.is::<NotReady>() => Availability::Loading,
+            Err(_) => Availability::Down,
+        }
+    }
+}
+
+/// Latest availability of the endpoints every poll depends on.
+#[derive(Debug, Default)]
+struct EndpointHealth {
+    players: Availability,
+    events: Availability,
+}
+
+impl EndpointHealth {
+    /// Record the latest availability; returns whether the game just went to the loading screen.
+    fn observe(&mut self, players: Availability, events: Availability) -> bool {
+        let was_loading = self.is_loading();
+        for (endpoint, state, next) in [
+            ("playerlist", &mut self.players, players),
+            ("eventdata", &mut self.events, events),
+        ] {
+            if *state != next {
+                debug!(endpoint, from = ?*state, to = ?next, "live client endpoint availability changed");
+                *state = next;
+            }
+        }
+        self.is_loading() && !was_loading
+    }
+
+    /// The client is up but the roster has not loaded, as on the loading screen.
+    fn is_loading(&self) -> bool {
+        self.players == Availability::Loading && self.events != Availability::Down
+    }
+}
+
 async fn fetch_endpoint(client: &Client, url: &str) -> Result<FetchResponse> {
     let response = client
         .get(url)
@@ -578,8 +1865,16 @@ async fn fetch_endpoint(client: &Client, url: &str) -> Result<FetchResponse> {
         .await
         .with_context(|| format!("request failed: GET {url}"))?;
 
-    if !response.status().is_success() {
-        anyhow::bail!("GET {url} -> {}", response.status());
+    let status = response.status();
+    if status == StatusCode::NOT_FOUND || status == StatusCode::SERVICE_UNAVAILABLE {
+        return Err(NotReady {
+            url: url.to_string(),
+            status,
+        }
+        .into());
+    }
+    if !status.is_success() {
+        anyhow::bail!("GET {url} -> {status}");
     }
 
     let body = response.bytes().await?.to_vec();
@@ -622,58 +1917,240 @@ fn allocate_slot(team: Team, used: &mut HashSet<u8>) -> u8 {
     start
 }
 
-fn fold_items(items: Vec<PlayerItemEntry>) -> HashMap<u32, ItemEntry> {
-    let mut map = HashMap::new();
-    for item in items {
+fn fold_items(items: Vec<PlayerItemEntry>) -> BTreeMap<u8, ItemEntry> {
+    let mut map = BTreeMap::new();
+    for (index, item) in items.into_iter().enumerate() {
         if item.item_id == 0 {
             continue;
         }
 
-        let entry = map.entry(item.item_id).or_insert(ItemEntry {
-            count: 0,
-            name: item.display_name.clone(),
-        });
-        entry.count += 1;
-        if entry.name.is_none() {
-            entry.name = item.display_name.clone();
-        }
+        // Older clients omit the slot; fall back to the position within the list.
+        let slot = item
+            .slot
+            .unwrap_or_else(|| index.min(u8::MAX as usize) as u8);
+        map.insert(
+            slot,
+            ItemEntry {
+                item_id: item.item_id,
+                count: item.count.max(1),
+                name: item.display_name,
+                price: item.price,
+            },
+        );
     }
     map
 }
 
-fn normalize_events(raw_events: &[RawEvent], registry: &PlayerRegistry) -> Vec<Event> {
+/// Count kills and objectives per team for the win-probability estimate.
+fn tally_objectives(tally: &mut TeamTally, raw_events: &[RawEvent], registry: &PlayerRegistry) {
+    let killer_team = |raw: &RawEvent| {
+        raw.killer_name
+            .as_deref()
+            .and_then(|name| registry.player_ref(name))
+            .map(|reference| reference.team)
+    };
+
+    for raw in raw_events {
+        let (team, stat): (Option<Team>, fn(&mut TeamStats) -> &mut u32) =
+            match raw.event_name.as_str() {
+                "ChampionKill" => (killer_team(raw), |stats| &mut stats.kills),
+                "DragonKill" => (killer_team(raw), |stats| &mut stats.dragons),
+                "BaronKill" => (killer_team(raw), |stats| &mut stats.barons),
+                // Turret ids encode the owning team (`Turret_T1_...` is Order's), and minions
+                // often take the last hit, so credit the opposing team instead of the killer.
+                "TurretKilled" => (
+                    raw.turret_killed.as_deref().and_then(turret_destroyer),
+                    |stats| &mut stats.towers,
+                ),
+                _ => continue,
+            };
+
+        if let Some(stats) = team.and_then(|team| tally.stats_mut(&team)) {
+            *stat(stats) += 1;
+        }
+    }
+}
+
+/// Feed dragon kills into the soul/elder state machine.
+fn track_dragons(
+    tracker: &mut DragonTracker,
+    raw_events: &[RawEvent],
+    registry: &PlayerRegistry,
+) -> Vec<Event> {
+    let mut events = Vec::new();
+    for raw in raw_events {
+        if raw.event_name != "DragonKill" {
+            continue;
+        }
+        let team = raw
+            .killer_name
+            .as_deref()
+            .and_then(|name| registry.player_ref(name))
+            .map(|reference| reference.team);
+        if let (Some(team), Some(dragon_type)) = (team, raw.dragon_type.as_deref()) {
+            events.extend(tracker.record(team, dragon_type, seconds_to_millis(raw.event_time)));
+        }
+    }
+    events
+}
+
+/// Turn `Ace` announcements into [`EventKind::TeamWiped`] events for the aced team.
+///
+/// The playerlist only exposes the remaining respawn timers as of this poll, so the game time
+/// elapsed since the ace is added back to estimate the full wipe duration.
+fn detect_team_wipes(
+    raw_events: &[RawEvent],
+    registry: &PlayerRegistry,
+    game_time: f64,
+) -> Vec<Event> {
+    let mut events = Vec::new();
+    for raw in raw_events {
+        if raw.event_name != "Ace" {
+            continue;
+        }
+        let team = match raw.acing_team.as_deref().map(parse_team) {
+            Some(Team::Order) => Team::Chaos,
+            Some(Team::Chaos) => Team::Order,
+            _ => continue,
+        };
+
+        let since_ace = (game_time - raw.event_time).max(0.0);
+        let remaining = registry.shortest_respawn(&team).unwrap_or(0.0);
+        events.push(Event::new(
+            EventKind::TeamWiped,
+            seconds_to_millis(raw.event_time),
+            EventPayload::TeamWipe(TeamWipeEvent {
+                team,
+                duration_estimate_ms: seconds_to_millis(since_ace + remaining),
+            }),
+        ));
+    }
+    events
+}
+
+/// Turn `GameEnd` into a `GameEnded` result and count it towards the configured series.
+///
+/// The Live Client reports the result relative to the local player, so the winner stays unknown
+/// when that player's team could not be resolved.
+fn detect_game_end(
+    raw_events: &[RawEvent],
+    local_team: Option<Team>,
+    series: &SeriesTracker,
+) -> Option<Event> {
+    let raw = raw_events.iter().find(|raw| raw.event_name == "GameEnd")?;
+    let winner = match (local_team, raw.result.as_deref()) {
+        (Some(team), Some("Win")) => Some(team),
+        (Some(Team::Order), Some("Lose")) => Some(Team::Chaos),
+        (Some(Team::Chaos), Some("Lose")) => Some(Team::Order),
+        _ => None,
+    };
+    let series = match &winner {
+        Some(team) => series.record_win(team),
+        None => series.info(),
+    };
+
+    Some(Event::new(
+        EventKind::GameEnded,
+        seconds_to_millis(raw.event_time),
+        EventPayload::GameResult(GameResultEvent { winner, series }),
+    ))
+}
+
+fn turret_destroyer(turret: &str) -> Option<Team> {
+    match turret.split('_').nth(1)? {
+        "T1" => Some(Team::Chaos),
+        "T2" => Some(Team::Order),
+        _ => None,
+    }
+}
+
+/// Turn raw Live Client events into model events; `flattened_kills` adds the separate
+/// kill, death and assist events of every champion kill.
+fn normalize_events(
+    raw_events: &[RawEvent],
+    registry: &PlayerRegistry,
+    bounties: &mut BountyTracker,
+    flattened_kills: bool,
+) -> Vec<Event> {
     let mut events = Vec::new();
 
     for raw in raw_events {
         let timestamp = seconds_to_millis(raw.event_time);
         match raw.event_name.as_str() {
-            "ChampionKill" | "ChampionSpecialKill" => {
-                if let Some(name) = raw.killer_name.as_ref() {
-                    let reference = resolve_player(registry, name);
-                    events.push(Event {
-                        kind: EventKind::Kill,
-                        ts: timestamp,
-                        payload: EventPayload::Player(PlayerEvent { player: reference }),
-                    });
-                }
-                if let Some(name) = raw.victim_name.as_ref() {
-                    let reference = resolve_player(registry, name);
-                    events.push(Event {
-                        kind: EventKind::Death,
-                        ts: timestamp,
-                        payload: EventPayload::Player(PlayerEvent { player: reference }),
-                    });
+            "ChampionKill" => {
+                let bounty = raw.victim_name.as_deref().map(|name| bounties.bounty(name));
+
+                let killer = raw
+                    .killer_name
+                    .as_deref()
+                    .map(|name| resolve_player(registry, name));
+                let victim = raw
+                    .victim_name
+                    .as_deref()
+                    .map(|name| resolve_player(registry, name));
+                let assisters: Vec<PlayerRef> = raw
+                    .assisters
+                    .iter()
+                    .filter(|assister| !assister.is_empty())
+                    .map(|assister| resolve_player(registry, assister))
+                    .collect();
+
+                if let Some(victim) = &victim {
+                    let credited = raw
+                        .killer_name
+                        .as_deref()
+                        .and_then(|name| registry.player_ref(name));
+                    events.extend(bounties.record_kill(credited.as_ref(), victim, timestamp));
                 }
-                for assister in &raw.assisters {
-                    if assister.is_empty() {
-                        continue;
+                if flattened_kills {
+                    let flattened = [
+                        (EventKind::Kill, killer.clone(), bounty),
+                        (EventKind::Death, victim.clone(), None),
+                    ]
+                    .into_iter()
+                    .chain(
+                        assisters
+                            .iter()
+                            .map(|assister| (EventKind::Assist, Some(assister.clone()), None)),
+                    );
+                    for (kind, player, bounty) in flattened {
+                        if let Some(player) = player {
+                            events.push(Event::new(
+                                kind,
+                                timestamp,
+                                EventPayload::Player(PlayerEvent { player, bounty }),
+                            ));
+                        }
                     }
-                    let reference = resolve_player(registry, assister);
-                    events.push(Event {
-                        kind: EventKind::Assist,
-                        ts: timestamp,
-                        payload: EventPayload::Player(PlayerEvent { player: reference }),
-                    });
+                }
+                if let Some(victim) = victim {
+                    events.push(Event::new(
+                        EventKind::ChampionKill,
+                        timestamp,
+                        EventPayload::KillDetail(KillDetailEvent {
+                            killer,
+                            victim,
+                            assisters,
+                            bounty,
+                        }),
+                    ));
+                }
+            }
+            // Special kills repeat a kill already in the feed, so they neither move streaks nor
+            // count as kills again.
+            "ChampionSpecialKill" | "Multikill" => {
+                if let Some(event) = special_kill_event(raw, registry, timestamp) {
+                    events.push(event);
+                } else {
+                    trace!(kill_type = ?raw.kill_type, "unhandled special kill");
+                }
+            }
+            "DragonKill" | "HeraldKill" | "BaronKill" | "HordeKill" => {
+                events.push(objective_kill_event(raw, registry, timestamp));
+            }
+            "TurretKilled" | "InhibKilled" => {
+                if let Some(event) = structure_event(raw, registry, timestamp) {
+                    events.push(event);
                 }
             }
             "LevelUp" | "ItemPurchased" | "ItemDestroyed" | "ItemSold" | "ItemUndo" => {
@@ -682,21 +2159,24 @@ fn normalize_events(raw_events: &[RawEvent], registry: &PlayerRegistry) -> Vec<E
             "Respawn" => {
                 if let Some(name) = raw.summoner_name.as_ref() {
                     let reference = resolve_player(registry, name);
-                    events.push(Event {
-                        kind: EventKind::Respawn,
-                        ts: timestamp,
-                        payload: EventPayload::Player(PlayerEvent { player: reference }),
-                    });
+                    events.push(Event::new(
+                        EventKind::Respawn,
+                        timestamp,
+                        EventPayload::Player(PlayerEvent {
+                            player: reference,
+                            bounty: None,
+                        }),
+                    ));
                 }
             }
             phase if is_phase_change(phase) => {
-                events.push(Event {
-                    kind: EventKind::PhaseChange,
-                    ts: timestamp,
-                    payload: EventPayload::Phase(PhaseEvent {
+                events.push(Event::new(
+                    EventKind::PhaseChange,
+                    timestamp,
+                    EventPayload::Phase(PhaseEvent {
                         phase: phase.to_string(),
                     }),
-                });
+                ));
             }
             _ => {
                 trace!(name = %raw.event_name, "unhandled live event");
@@ -707,36 +2187,169 @@ fn normalize_events(raw_events: &[RawEvent], registry: &PlayerRegistry) -> Vec<E
     events
 }
 
+/// An `ObjectiveKill` for a dragon, herald, baron or void grub kill.
+fn objective_kill_event(raw: &RawEvent, registry: &PlayerRegistry, timestamp: u64) -> Event {
+    let objective = match raw.event_name.as_str() {
+        "DragonKill" => Objective::Dragon,
+        "HeraldKill" => Objective::Herald,
+        "BaronKill" => Objective::Baron,
+        _ => Objective::Grubs,
+    };
+    let killer = raw
+        .killer_name
+        .as_deref()
+        .and_then(|name| registry.player_ref(name));
+    let stolen = match &raw.stolen {
+        Some(Value::Bool(stolen)) => *stolen,
+        Some(Value::String(stolen)) => stolen.eq_ignore_ascii_case("true"),
+        _ => false,
+    };
+    Event::new(
+        EventKind::ObjectiveKill,
+        timestamp,
+        EventPayload::ObjectiveKill(ObjectiveKillEvent {
+            objective,
+            dragon_type: raw
+                .dragon_type
+                .clone()
+                .filter(|_| objective == Objective::Dragon),
+            stolen,
+            // Refined by the steal tracker once the kills around it are known.
+            steal_suspected: stolen,
+            team: killer.as_ref().map(|killer| killer.team.clone()),
+            killer,
+        }),
+    )
+}
+
+/// A `StructureDestroyed` for a turret or inhibitor, if its id follows the map layout.
+fn structure_event(raw: &RawEvent, registry: &PlayerRegistry, timestamp: u64) -> Option<Event> {
+    let id = match raw.event_name.as_str() {
+        "TurretKilled" => raw.turret_killed.as_deref(),
+        _ => raw.inhib_killed.as_deref(),
+    }?;
+    let Some(parsed) = structures::parse(id) else {
+        trace!(%id, "unknown structure");
+        return None;
+    };
+    Some(Event::new(
+        EventKind::StructureDestroyed,
+        timestamp,
+        EventPayload::Structure(StructureEvent {
+            structure: parsed.structure,
+            id: id.to_string(),
+            team: parsed.team,
+            lane: parsed.lane,
+            tier: parsed.tier,
+            killer: raw
+                .killer_name
+                .as_deref()
+                .and_then(|name| registry.player_ref(name)),
+        }),
+    ))
+}
+
+/// The multikill, first blood or shutdown a special kill reports, if its kill type is known.
+fn special_kill_event(raw: &RawEvent, registry: &PlayerRegistry, timestamp: u64) -> Option<Event> {
+    let streak = raw.kill_streak;
+    let kill_type = match raw
+        .kill_type
+        .as_deref()
+        .map(|name| name.trim_end_matches("Kill"))
+    {
+        Some("Double") => KillType::Double,
+        Some("Triple") => KillType::Triple,
+        Some("Quadra") => KillType::Quadra,
+        Some("Penta") => KillType::Penta,
+        Some("FirstBlood") => KillType::FirstBlood,
+        Some("Shutdown") => KillType::Shutdown,
+        // `Multikill` events only carry the streak.
+        Some("Multi") | None => match streak? {
+            2 => KillType::Double,
+            3 => KillType::Triple,
+            4 => KillType::Quadra,
+            5 => KillType::Penta,
+            _ => return None,
+        },
+        Some(_) => return None,
+    };
+    let (kind, streak) = match kill_type {
+        KillType::Double => (EventKind::Multikill, 2),
+        KillType::Triple => (EventKind::Multikill, 3),
+        KillType::Quadra => (EventKind::Multikill, 4),
+        KillType::Penta => (EventKind::Multikill, 5),
+        KillType::FirstBlood => (EventKind::FirstBlood, 1),
+        KillType::Shutdown => (EventKind::Shutdown, streak.unwrap_or(0)),
+    };
+    let killer = resolve_player(registry, raw.killer_name.as_deref()?);
+    let victim = raw
+        .victim_name
+        .as_deref()
+        .filter(|_| kind != EventKind::Multikill)
+        .map(|name| resolve_player(registry, name));
+    Some(Event::new(
+        kind,
+        timestamp,
+        EventPayload::SpecialKill(SpecialKillEvent {
+            killer,
+            victim,
+            kill_type,
+            streak,
+        }),
+    ))
+}
+
 fn deduplicate_events(events: &mut Vec<Event>) {
     let mut seen_respawns: HashSet<PlayerRef> = HashSet::new();
 
     events.retain(|event| match (&event.kind, &event.payload) {
-        (EventKind::Respawn, EventPayload::Player(PlayerEvent { player })) => {
+        (EventKind::Respawn, EventPayload::Player(PlayerEvent { player, .. })) => {
             seen_respawns.insert(player.clone())
         }
         _ => true,
     });
 }
 
-fn push_item_events(
+fn push_item_event(
     events: &mut Vec<Event>,
     ts_ms: u64,
     player: &PlayerRef,
     kind: EventKind,
-    item_id: u32,
-    item_name: Option<String>,
-    count: u32,
+    slot: u8,
+    entry: &ItemEntry,
+    previous_slot: Option<u8>,
 ) {
-    for _ in 0..count {
-        events.push(Event {
-            kind: kind.clone(),
-            ts: ts_ms,
-            payload: EventPayload::PlayerItem(ItemEvent {
-                player: player.clone(),
-                item_id,
-                item_name: item_name.clone(),
-            }),
-        });
+    events.push(Event::new(
+        kind,
+        ts_ms,
+        EventPayload::PlayerItem(ItemEvent {
+            player: player.clone(),
+            item_id: entry.item_id,
+            item_name: entry.name.clone(),
+            slot: Some(slot),
+            count: Some(entry.count),
+            previous_slot,
+            price: (entry.price > 0).then_some(entry.price),
+        }),
+    ));
+}
+
+/// `units` item events with a `count` of one each, for per-unit item events.
+fn push_item_units(
+    events: &mut Vec<Event>,
+    ts_ms: u64,
+    player: &PlayerRef,
+    kind: EventKind,
+    slot: u8,
+    entry: &ItemEntry,
+    units: u32,
+) {
+    let unit = ItemEntry {
+        count: 1,
+        ..entry.clone()
+    };
+    for _ in 0..units {
+        push_item_event(events, ts_ms, player, kind.clone(), slot, &unit, None);
     }
 }
 
@@ -747,11 +2360,11 @@ fn resolve_player(registry: &PlayerRegistry, name: &str) -> PlayerRef {
 }
 
 fn neutral_player(name: &str) -> PlayerRef {
-    PlayerRef {
-        summoner_name: name.to_string(),
-        team: Team::Neutral,
-        slot: 0,
-    }
+    PlayerRef::new(name, Team::Neutral, 0)
+}
+
+fn clamp_u32(value: i64) -> u32 {
+    value.clamp(0, i64::from(u32::MAX)) as u32
 }
 
 fn seconds_to_millis(value: f64) -> u64 {
@@ -775,13 +2388,8 @@ fn is_phase_change(name: &str) -> bool {
             | "MinionsSpawning"
             | "FirstBrick"
             | "FirstBlood"
-            | "TurretKilled"
-            | "InhibKilled"
             | "InhibRespawningSoon"
             | "InhibRespawned"
-            | "DragonKill"
-            | "HeraldKill"
-            | "BaronKill"
             | "GameEnd"
             | "Ace"
     )
@@ -790,6 +2398,7 @@ fn is_phase_change(name: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use levents_model::{Lane, StructureType, TurretTier};
 
     fn make_player_entry(
         name: &str,
@@ -801,11 +2410,23 @@ mod tests {
     ) -> PlayerListEntry {
         PlayerListEntry {
             summoner_name: name.to_string(),
+            riot_id: None,
+            riot_id_game_name: None,
+            riot_id_tag_line: None,
+            champion_name: None,
+            raw_champion_name: None,
+            skin_id: None,
             team: team.to_string(),
             level,
             current_gold: Some(gold),
             is_dead,
+            respawn_timer: 0.0,
+            scores: PlayerScores::default(),
             items,
+            position: None,
+            summoner_spells: None,
+            champion_stats: None,
+            unknown: HashMap::new(),
         }
     }
 
@@ -813,6 +2434,18 @@ mod tests {
         PlayerItemEntry {
             item_id: id,
             display_name: Some(name.to_string()),
+            slot: None,
+            count: 1,
+            price: 0,
+            unknown: HashMap::new(),
+        }
+    }
+
+    fn make_slotted_item(id: u32, name: &str, slot: u8, count: u32) -> PlayerItemEntry {
+        PlayerItemEntry {
+            slot: Some(slot),
+            count,
+            ..make_item(id, name)
         }
     }
 
@@ -851,6 +2484,501 @@ mod tests {
             .any(|event| matches!(event.kind, EventKind::ItemAdded)));
     }
 
+    #[test]
+    fn restored_registry_does_not_replay_the_game() {
+        let roster = vec![
+            make_player_entry(
+                "Alpha",
+                "ORDER",
+                6,
+                500.0,
+                false,
+                vec![make_item(1055, "Doran's Blade")],
+            ),
+            make_player_entry("Bravo", "CHAOS", 5, 300.0, false, vec![]),
+        ];
+        let mut registry = PlayerRegistry::default();
+        registry.apply(roster.clone(), 1_000);
+
+        let saved = serde_json::to_string(&registry.players.values().collect::<Vec<_>>()).unwrap();
+        let mut restored = PlayerRegistry::default();
+        restored.restore(serde_json::from_str(&saved).unwrap());
+
+        assert!(restored.apply(roster, 2_000).is_empty());
+        assert_eq!(restored.player_ref("Alpha"), registry.player_ref("Alpha"));
+    }
+
+    #[test]
+    fn high_frequency_events_report_health_from_champion_stats() {
+        let config = DaemonConfig {
+            high_frequency_events: true,
+            ..DaemonConfig::default()
+        };
+        let mut ctx = PollContext::new(
+            config,
+            Client::new(),
+            UnknownFieldStats::default(),
+            SeriesTracker::default(),
+            Vitals::default(),
+            RegistryMeter::default(),
+        );
+        ctx.players.apply(
+            vec![make_player_entry("Alpha", "ORDER", 1, 0.0, false, vec![])],
+            1_000,
+        );
+        let body = |health: f32| {
+            serde_json::json!({
+                "summonerName": "Alpha",
+                "abilities": { "Q": {}, "W": {}, "E": {}, "R": {} },
+                "championStats": {
+                    "currentHealth": health,
+                    "maxHealth": 600.0,
+                    "resourceValue": 280.0,
+                    "resourceMax": 280.0,
+                },
+            })
+            .to_string()
+        };
+        let changes = |events: Vec<Event>| {
+            events
+                .into_iter()
+                .filter_map(|event| match event.payload {
+                    EventPayload::ResourceChanged(inner) => Some(inner),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let first = changes(ctx.diff_active_player(body(600.0).as_bytes(), 2_000));
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].player.summoner_name, "Alpha");
+        assert_eq!(first[0].max_resource, 280.0);
+        assert!(changes(ctx.diff_active_player(body(590.0).as_bytes(), 3_000)).is_empty());
+        let low = changes(ctx.diff_active_player(body(120.0).as_bytes(), 4_000));
+        assert!(low[0].low_health);
+    }
+
+    #[test]
+    fn every_game_gets_its_own_id() {
+        let mut ctx = PollContext::new(
+            DaemonConfig::default(),
+            Client::new(),
+            UnknownFieldStats::default(),
+            SeriesTracker::default(),
+            Vitals::default(),
+            RegistryMeter::default(),
+        );
+        let game = GameHandle::default();
+        ctx.resume(SessionHandle::default(), game.clone());
+        let stamped = |ctx: &mut PollContext| {
+            let mut events = [Event::new(
+                EventKind::PhaseChange,
+                1_000,
+                EventPayload::Phase(PhaseEvent {
+                    phase: LOADING_PHASE.to_string(),
+                }),
+            )];
+            ctx.stamp_game(&mut events);
+            events[0].game_id.unwrap()
+        };
+
+        let first = stamped(&mut ctx);
+        assert_eq!(stamped(&mut ctx), first);
+        assert_eq!(*game.lock(), Some(first));
+
+        ctx.reset_game();
+        // The old game stays current until the new one produces events.
+        assert_eq!(*game.lock(), Some(first));
+        let second = stamped(&mut ctx);
+        assert_ne!(second, first);
+        assert_eq!(*game.lock(), Some(second));
+    }
+
+    #[test]
+    fn active_player_reports_runes_and_resource_once() {
+        let mut ctx = PollContext::new(
+            DaemonConfig::default(),
+            Client::new(),
+            UnknownFieldStats::default(),
+            SeriesTracker::default(),
+            Vitals::default(),
+            RegistryMeter::default(),
+        );
+        ctx.players.apply(
+            vec![
+                make_player_entry("Alpha", "ORDER", 1, 0.0, false, vec![]),
+                make_player_entry("Bravo", "CHAOS", 1, 0.0, false, vec![]),
+            ],
+            1_000,
+        );
+        let body = |q: u8| {
+            serde_json::json!({
+                "summonerName": "Alpha",
+                "abilities": { "Q": { "abilityLevel": q }, "W": {}, "E": {}, "R": {} },
+                "fullRunes": {
+                    "generalRunes": [{ "id": 8112 }, { "id": 8139 }],
+                    "primaryRuneTree": { "id": 8100 },
+                    "secondaryRuneTree": { "id": 8300 },
+                    "statRunes": [{ "id": 5008 }],
+                },
+                "championStats": { "resourceType": "ENERGY" },
+            })
+            .to_string()
+        };
+
+        let first = ctx.diff_active_player(body(1).as_bytes(), 2_000);
+        assert_eq!(
+            first
+                .iter()
+                .map(|event| event.kind.clone())
+                .collect::<Vec<_>>(),
+            [
+                EventKind::ActivePlayerRunes,
+                EventKind::ActivePlayerResource
+            ]
+        );
+        let EventPayload::ActiveRunes(runes) = &first[0].payload else {
+            panic!("unexpected payload {:?}", first[0].payload);
+        };
+        assert_eq!(runes.perk_ids, [8112, 8139, 5008]);
+        assert!(runes.player.active_player);
+        assert!(!ctx.players.player_ref("Bravo").unwrap().active_player);
+
+        let second = ctx.diff_active_player(body(2).as_bytes(), 3_000);
+        assert_eq!(
+            second
+                .iter()
+                .map(|event| event.kind.clone())
+                .collect::<Vec<_>>(),
+            [EventKind::SkillLevelUp]
+        );
+    }
+
+    #[test]
+    fn game_metadata_is_reported_when_the_mode_or_map_changes() {
+        let mut ctx = PollContext::new(
+            DaemonConfig::default(),
+            Client::new(),
+            UnknownFieldStats::default(),
+            SeriesTracker::default(),
+            Vitals::default(),
+            RegistryMeter::default(),
+        );
+        let stats = |game_time: f64, terrain: &str| {
+            serde_json::from_value::<GameStatsResponse>(serde_json::json!({
+                "gameMode": "CLASSIC",
+                "gameTime": game_time,
+                "mapName": "Map11",
+                "mapNumber": 11,
+                "mapTerrain": terrain,
+            }))
+            .unwrap()
+        };
+
+        let first = ctx
+            .diff_game_metadata(&stats(12.5, "Default"), 1_000)
+            .unwrap();
+        let EventPayload::GameMetadata(metadata) = &first.payload else {
+            panic!("unexpected payload {:?}", first.payload);
+        };
+        assert_eq!(
+            (metadata.game_mode.as_str(), metadata.map_number),
+            ("CLASSIC", 11)
+        );
+        assert_eq!(
+            (metadata.map_terrain.as_deref(), metadata.game_time_ms),
+            (None, 12_500)
+        );
+        assert!(stats(12.5, "Default").unknown.is_empty());
+
+        assert!(ctx
+            .diff_game_metadata(&stats(60.0, "Default"), 2_000)
+            .is_none());
+        let transformed = ctx.diff_game_metadata(&stats(900.0, "Infernal"), 3_000);
+        assert!(matches!(
+            transformed.map(|event| event.payload),
+            Some(EventPayload::GameMetadata(GameMetadataEvent { map_terrain: Some(terrain), .. }))
+                if terrain == "Infernal"
+        ));
+
+        ctx.reset_game();
+        assert!(ctx
+            .diff_game_metadata(&stats(1.0, "Default"), 4_000)
+            .is_some());
+    }
+
+    #[test]
+    fn score_deltas_wait_for_the_minimum_growth() {
+        let entry = |creep_score, ward_score| PlayerListEntry {
+            scores: PlayerScores {
+                creep_score,
+                ward_score,
+            },
+            ..make_player_entry("Alpha", "ORDER", 1, 0.0, false, vec![])
+        };
+        let mut registry = PlayerRegistry::default().with_score_deltas(ScoreDeltas {
+            creep_score: Some(10),
+            vision_score: Some(2.5),
+        });
+        assert!(registry.apply(vec![entry(20, 1.0)], 1_000).is_empty());
+        assert!(registry.apply(vec![entry(26, 3.0)], 2_000).is_empty());
+
+        let scores = |events: Vec<Event>| -> Vec<_> {
+            events
+                .into_iter()
+                .map(|event| match event.payload {
+                    EventPayload::PlayerScore(score) => (event.kind, score.delta, score.total),
+                    other => panic!("unexpected payload {other:?}"),
+                })
+                .collect()
+        };
+        assert_eq!(
+            scores(registry.apply(vec![entry(31, 4.0)], 3_000)),
+            [
+                (EventKind::CsDelta, 11.0, 31.0),
+                (EventKind::VisionScoreDelta, 3.0, 4.0)
+            ]
+        );
+        assert!(registry.apply(vec![entry(35, 5.0)], 4_000).is_empty());
+    }
+
+    #[test]
+    fn registry_evicts_players_and_items_beyond_the_budget() {
+        let budget = MemoryBudget {
+            max_players: 2,
+            max_items_per_player: 1,
+            ..MemoryBudget::default()
+        };
+        let meter = RegistryMeter::default();
+        let mut registry = PlayerRegistry::with_budget(budget, meter.clone());
+        let items = vec![
+            make_slotted_item(1055, "Doran's Blade", 0, 1),
+            make_slotted_item(2003, "Health Potion", 1, 2),
+        ];
+        registry.apply(
+            vec![
+                make_player_entry("Alpha", "ORDER", 1, 500.0, false, items),
+                make_player_entry("Bravo", "CHAOS", 1, 500.0, false, vec![]),
+                make_player_entry("Charlie", "CHAOS", 1, 500.0, false, vec![]),
+            ],
+            1_000,
+        );
+
+        assert_eq!(registry.players.len(), 2);
+        assert!(!registry.players.contains_key("Charlie"));
+        assert_eq!(
+            registry.players["Alpha"].items.keys().collect::<Vec<_>>(),
+            [&0]
+        );
+        assert_eq!(meter.read(), (2, 1, 2));
+    }
+
+    #[test]
+    fn registry_tracks_item_slots_and_stacks() {
+        let mut registry = PlayerRegistry::default();
+        let baseline = vec![make_player_entry(
+            "Alpha",
+            "ORDER",
+            3,
+            500.0,
+            false,
+            vec![
+                make_slotted_item(1055, "Doran's Blade", 0, 1),
+                PlayerItemEntry {
+                    price: 50,
+                    ..make_slotted_item(2003, "Health Potion", 1, 2)
+                },
+            ],
+        )];
+        assert!(registry.apply(baseline, 1_000).is_empty());
+
+        let updated = vec![make_player_entry(
+            "Alpha",
+            "ORDER",
+            3,
+            500.0,
+            false,
+            vec![
+                make_slotted_item(1055, "Doran's Blade", 3, 1),
+                PlayerItemEntry {
+                    price: 50,
+                    ..make_slotted_item(2003, "Health Potion", 1, 1)
+                },
+            ],
+        )];
+        let events = registry.apply(updated, 2_000);
+        assert_eq!(events.len(), 2);
+
+        let item = |kind: EventKind| {
+            events
+                .iter()
+                .find(|event| event.kind == kind)
+                .and_then(|event| match &event.payload {
+                    EventPayload::PlayerItem(item) => Some(item.clone()),
+                    _ => None,
+                })
+                .expect("item event")
+        };
+
+        let stack = item(EventKind::ItemStackChanged);
+        assert_eq!(
+            (stack.item_id, stack.slot, stack.count, stack.price),
+            (2003, Some(1), Some(1), Some(50))
+        );
+
+        let moved = item(EventKind::ItemMoved);
+        assert_eq!(moved.item_id, 1055);
+        assert_eq!((moved.previous_slot, moved.slot), (Some(0), Some(3)));
+        // The test client reports no price for it.
+        assert_eq!(moved.price, None);
+    }
+
+    #[test]
+    fn registry_is_keyed_on_riot_ids() {
+        let mut registry = PlayerRegistry::default();
+        registry.apply(
+            vec![PlayerListEntry {
+                riot_id_game_name: Some("Faker".to_string()),
+                riot_id_tag_line: Some("KR1".to_string()),
+                ..make_player_entry("Hide on bush", "ORDER", 1, 500.0, false, vec![])
+            }],
+            1_000,
+        );
+
+        assert!(registry.players.contains_key("Faker#KR1"));
+        for name in ["Faker#KR1", "Faker", "Hide on bush"] {
+            let reference = registry.player_ref(name).expect(name);
+            assert_eq!(reference.riot_id.as_deref(), Some("Faker#KR1"));
+            assert_eq!(reference.summoner_name, "Hide on bush");
+        }
+        assert!(registry.player_ref("KR1").is_none());
+    }
+
+    #[test]
+    fn player_references_name_the_champion_and_skin() {
+        let entries: Vec<PlayerListEntry> = serde_json::from_value(serde_json::json!([
+            {
+                "summonerName": "Alpha", "team": "ORDER", "level": 1, "isDead": false,
+                "championName": "Wukong", "rawChampionName": "game_character_displayname_MonkeyKing",
+                "skinID": 7,
+            },
+            {
+                "summonerName": "Bravo", "team": "CHAOS", "level": 1, "isDead": false,
+                "championName": "", "rawChampionName": "game_character_displayname_Ahri",
+            },
+        ]))
+        .unwrap();
+        let mut registry = PlayerRegistry::default();
+        registry.apply(entries, 1_000);
+
+        let alpha = registry.player_ref("Alpha").unwrap();
+        assert_eq!(
+            (alpha.champion_name.as_deref(), alpha.skin_id),
+            (Some("Wukong"), Some(7))
+        );
+        let bravo = registry.player_ref("Bravo").unwrap();
+        assert_eq!(
+            (bravo.champion_name.as_deref(), bravo.skin_id),
+            (Some("Ahri"), None)
+        );
+    }
+
+    #[test]
+    fn per_unit_items_send_one_event_per_unit() {
+        let mut registry = PlayerRegistry::default().with_per_unit_items(true);
+        let alpha = |items| make_player_entry("Alpha", "ORDER", 1, 500.0, false, items);
+        registry.apply(vec![alpha(vec![])], 1_000);
+
+        let bought = registry.apply(
+            vec![alpha(vec![make_slotted_item(2003, "Health Potion", 1, 5)])],
+            2_000,
+        );
+        let used = registry.apply(
+            vec![alpha(vec![make_slotted_item(2003, "Health Potion", 1, 3)])],
+            3_000,
+        );
+        let units = |events: &[Event], kind: EventKind| {
+            events
+                .iter()
+                .filter(|event| {
+                    event.kind == kind
+                        && matches!(&event.payload, EventPayload::PlayerItem(item) if item.count == Some(1))
+                })
+                .count()
+        };
+        assert_eq!(units(&bought, EventKind::ItemAdded), 5);
+        assert_eq!(units(&used, EventKind::ItemRemoved), 2);
+        assert!(!used
+            .iter()
+            .any(|event| event.kind == EventKind::ItemStackChanged));
+    }
+
+    #[test]
+    fn registry_accounts_gold_earned_and_spent() {
+        let mut registry = PlayerRegistry::default();
+        let blade = PlayerItemEntry {
+            price: 450,
+            ..make_slotted_item(1055, "Doran's Blade", 0, 1)
+        };
+
+        registry.apply(
+            vec![make_player_entry("Alpha", "ORDER", 1, 500.0, false, vec![])],
+            1_000,
+        );
+        registry.apply(
+            vec![make_player_entry(
+                "Alpha",
+                "ORDER",
+                1,
+                50.0,
+                false,
+                vec![blade],
+            )],
+            2_000,
+        );
+        registry.apply(
+            vec![make_player_entry("Alpha", "ORDER", 1, 200.0, false, vec![])],
+            3_000,
+        );
+
+        let events = registry.economy_events(4_000);
+        assert_eq!(events.len(), 1);
+        match &events[0].payload {
+            EventPayload::PlayerEconomy(economy) => {
+                assert_eq!(economy.spent, 450);
+                // Selling the blade (+150) counts as earned gold.
+                assert_eq!(economy.earned, 150);
+                assert_eq!(economy.current, 200);
+            }
+            other => panic!("unexpected payload {other:?}"),
+        }
+    }
+
+    #[test]
+    fn registry_assigns_lanes_and_keeps_them_on_references() {
+        let mut registry = PlayerRegistry::default();
+        let with_position = |name: &str, position: &str| PlayerListEntry {
+            position: Some(position.to_string()),
+            ..make_player_entry(name, "ORDER", 6, 0.0, false, vec![])
+        };
+        let roster = vec![
+            with_position("Top", "TOP"),
+            with_position("Jungle", "JUNGLE"),
+            with_position("Mid", "MIDDLE"),
+            with_position("Bot", "BOTTOM"),
+            with_position("Support", "UTILITY"),
+        ];
+        registry.apply(roster.clone(), 1_000);
+
+        let events = registry.assign_lanes(2_000);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, EventKind::LaneAssignment);
+
+        registry.apply(roster, 3_000);
+        let support = registry.player_ref("Support").expect("support");
+        assert_eq!(support.lane, Some(Lane::Support));
+    }
+
     #[test]
     fn normalize_events_emits_combat_events() {
         let mut registry = PlayerRegistry::default();
@@ -861,7 +2989,7 @@ mod tests {
         ];
         registry.apply(baseline, 1_000);
 
-        let raw = RawEvent {
+        let raw = [RawEvent {
             event_id: 1,
             event_name: "ChampionKill".to_string(),
             event_time: 12.5,
@@ -869,16 +2997,281 @@ mod tests {
             victim_name: Some("Bravo".to_string()),
             assisters: vec!["Charlie".to_string()],
             summoner_name: None,
-            level: None,
-            item_id: None,
-            item_name: None,
-        };
-
-        let events = normalize_events(&[raw], &registry);
-        assert_eq!(events.len(), 3);
+            ..Default::default()
+        }];
+
+        let events = normalize_events(&raw, &registry, &mut BountyTracker::default(), true);
+        assert_eq!(events.len(), 4);
+        assert!(events.iter().any(|event| matches!(
+            event.payload,
+            EventPayload::Player(PlayerEvent {
+                bounty: Some(300),
+                ..
+            })
+        )));
         assert!(events.iter().any(|event| event.kind == EventKind::Kill));
         assert!(events.iter().any(|event| event.kind == EventKind::Death));
         assert!(events.iter().any(|event| event.kind == EventKind::Assist));
+
+        let linked = normalize_events(&raw, &registry, &mut BountyTracker::default(), false);
+        assert_eq!(linked.len(), 1);
+        let EventPayload::KillDetail(kill) = &linked[0].payload else {
+            panic!("unexpected payload");
+        };
+        assert_eq!(kill.killer.as_ref().unwrap().summoner_name, "Alpha");
+        assert_eq!(kill.victim.summoner_name, "Bravo");
+        assert_eq!(kill.assisters[0].summoner_name, "Charlie");
+        assert_eq!(kill.bounty, Some(300));
+    }
+
+    #[test]
+    fn special_kills_get_their_own_kinds() {
+        let mut registry = PlayerRegistry::default();
+        registry.apply(
+            vec![
+                make_player_entry("Alpha", "ORDER", 1, 500.0, false, vec![]),
+                make_player_entry("Bravo", "CHAOS", 1, 300.0, false, vec![]),
+            ],
+            1_000,
+        );
+        let special =
+            |event_name: &str, kill_type: Option<&str>, kill_streak: Option<u32>| RawEvent {
+                event_name: event_name.to_string(),
+                event_time: 30.0,
+                killer_name: Some("Alpha".to_string()),
+                victim_name: Some("Bravo".to_string()),
+                kill_type: kill_type.map(str::to_string),
+                kill_streak,
+                ..Default::default()
+            };
+        let raw = [
+            special("ChampionSpecialKill", Some("Penta"), None),
+            special("ChampionSpecialKill", Some("FirstBlood"), None),
+            special("ChampionSpecialKill", Some("Shutdown"), Some(6)),
+            special("Multikill", None, Some(3)),
+            special("ChampionSpecialKill", Some("Ace"), None),
+        ];
+
+        let events = normalize_events(&raw, &registry, &mut BountyTracker::default(), true);
+        let specials: Vec<_> = events
+            .iter()
+            .map(|event| match &event.payload {
+                EventPayload::SpecialKill(kill) => (
+                    event.kind.clone(),
+                    kill.kill_type,
+                    kill.streak,
+                    kill.victim.is_some(),
+                ),
+                other => panic!("unexpected payload {other:?}"),
+            })
+            .collect();
+        assert_eq!(
+            specials,
+            [
+                (EventKind::Multikill, KillType::Penta, 5, false),
+                (EventKind::FirstBlood, KillType::FirstBlood, 1, true),
+                (EventKind::Shutdown, KillType::Shutdown, 6, true),
+                (EventKind::Multikill, KillType::Triple, 3, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn objective_kills_resolve_the_killing_team() {
+        let mut registry = PlayerRegistry::default();
+        registry.apply(
+            vec![make_player_entry("Bravo", "CHAOS", 9, 0.0, false, vec![])],
+            1_000,
+        );
+        let dragon = RawEvent {
+            event_name: "DragonKill".to_string(),
+            event_time: 600.0,
+            killer_name: Some("Bravo".to_string()),
+            dragon_type: Some("Chemtech".to_string()),
+            stolen: Some(Value::String("True".to_string())),
+            ..Default::default()
+        };
+        let grub = RawEvent {
+            event_name: "HordeKill".to_string(),
+            event_time: 360.0,
+            killer_name: Some("SRU_Horde".to_string()),
+            stolen: Some(Value::String("False".to_string())),
+            ..Default::default()
+        };
+
+        let events = normalize_events(
+            &[dragon, grub],
+            &registry,
+            &mut BountyTracker::default(),
+            true,
+        );
+        let objectives: Vec<_> = events
+            .iter()
+            .map(|event| match &event.payload {
+                EventPayload::ObjectiveKill(kill) => kill.clone(),
+                other => panic!("unexpected payload {other:?}"),
+            })
+            .collect();
+        assert_eq!(objectives.len(), 2);
+        assert_eq!(objectives[0].objective, Objective::Dragon);
+        assert_eq!(objectives[0].dragon_type.as_deref(), Some("Chemtech"));
+        assert!(objectives[0].stolen);
+        assert_eq!(objectives[0].team, Some(Team::Chaos));
+        assert_eq!(
+            objectives[0].killer.as_ref().unwrap().summoner_name,
+            "Bravo"
+        );
+        assert_eq!(objectives[1].objective, Objective::Grubs);
+        assert!(!objectives[1].stolen);
+        assert_eq!(
+            (objectives[1].team.clone(), objectives[1].killer.clone()),
+            (None, None)
+        );
+    }
+
+    #[test]
+    fn destroyed_structures_keep_their_identity() {
+        let mut registry = PlayerRegistry::default();
+        registry.apply(
+            vec![make_player_entry("Alpha", "ORDER", 1, 0.0, false, vec![])],
+            1_000,
+        );
+        let turret = RawEvent {
+            event_name: "TurretKilled".to_string(),
+            killer_name: Some("Alpha".to_string()),
+            turret_killed: Some("Turret_T2_L_03_A".to_string()),
+            ..Default::default()
+        };
+        let inhibitor = RawEvent {
+            event_name: "InhibKilled".to_string(),
+            killer_name: Some("Minion_T100L0S0N0001".to_string()),
+            inhib_killed: Some("Barracks_T2_L1".to_string()),
+            ..Default::default()
+        };
+
+        let events = normalize_events(
+            &[turret, inhibitor],
+            &registry,
+            &mut BountyTracker::default(),
+            true,
+        );
+        let structures: Vec<_> = events
+            .iter()
+            .map(|event| match &event.payload {
+                EventPayload::Structure(structure) => structure.clone(),
+                other => panic!("unexpected payload {other:?}"),
+            })
+            .collect();
+        assert_eq!(structures.len(), 2);
+        assert_eq!(structures[0].id, "Turret_T2_L_03_A");
+        assert_eq!(
+            (structures[0].team.clone(), structures[0].tier),
+            (Team::Chaos, Some(TurretTier::Outer))
+        );
+        assert_eq!(
+            structures[0].killer.as_ref().unwrap().summoner_name,
+            "Alpha"
+        );
+        assert_eq!(structures[1].structure, StructureType::Inhibitor);
+        assert_eq!(structures[1].lane, Some(Lane::Top));
+        assert_eq!(structures[1].killer, None);
+    }
+
+    #[test]
+    fn tally_credits_kills_and_objectives_to_teams() {
+        let mut registry = PlayerRegistry::default();
+        registry.apply(
+            vec![
+                make_player_entry("Alpha", "ORDER", 1, 500.0, false, vec![]),
+                make_player_entry("Bravo", "CHAOS", 1, 500.0, false, vec![]),
+            ],
+            1_000,
+        );
+
+        let raw = |name: &str, killer: &str| RawEvent {
+            event_name: name.to_string(),
+            killer_name: Some(killer.to_string()),
+            ..Default::default()
+        };
+        let turret = RawEvent {
+            turret_killed: Some("Turret_T2_L_03_A".to_string()),
+            ..raw("TurretKilled", "Minion_T100L0S0N0001")
+        };
+
+        let mut tally = TeamTally::default();
+        tally_objectives(
+            &mut tally,
+            &[
+                raw("ChampionKill", "Alpha"),
+                raw("DragonKill", "Bravo"),
+                raw("BaronKill", "Alpha"),
+                turret,
+            ],
+            &registry,
+        );
+
+        let order = *tally.stats_mut(&Team::Order).expect("order");
+        assert_eq!((order.kills, order.barons, order.towers), (1, 1, 1));
+        assert_eq!(tally.stats_mut(&Team::Chaos).expect("chaos").dragons, 1);
+    }
+
+    #[test]
+    fn ace_reports_wiped_team_until_first_respawn() {
+        let dead = |name: &str, respawn_timer: f64| PlayerListEntry {
+            respawn_timer,
+            ..make_player_entry(name, "CHAOS", 11, 0.0, true, vec![])
+        };
+        let mut registry = PlayerRegistry::default();
+        registry.apply(
+            vec![
+                make_player_entry("Alpha", "ORDER", 11, 0.0, false, vec![]),
+                dead("Bravo", 24.0),
+                dead("Charlie", 18.5),
+            ],
+            1_000,
+        );
+
+        let raw = RawEvent {
+            event_id: 40,
+            event_name: "Ace".to_string(),
+            event_time: 900.0,
+            acing_team: Some("ORDER".to_string()),
+            ..Default::default()
+        };
+        let events = detect_team_wipes(&[raw], &registry, 902.0);
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, EventKind::TeamWiped);
+        assert_eq!(
+            events[0].payload,
+            EventPayload::TeamWipe(TeamWipeEvent {
+                team: Team::Chaos,
+                duration_estimate_ms: 20_500,
+            })
+        );
+    }
+
+    #[test]
+    fn game_end_resolves_winner_from_local_result() {
+        let raw = RawEvent {
+            event_id: 90,
+            event_name: "GameEnd".to_string(),
+            event_time: 1_800.0,
+            result: Some("Lose".to_string()),
+            ..Default::default()
+        };
+        let series = SeriesTracker::default();
+
+        let event = detect_game_end(&[raw], Some(Team::Order), &series).expect("game end");
+
+        assert_eq!(event.kind, EventKind::GameEnded);
+        assert_eq!(
+            event.payload,
+            EventPayload::GameResult(GameResultEvent {
+                winner: Some(Team::Chaos),
+                series: None,
+            })
+        );
     }
 
     #[test]
@@ -900,12 +3293,11 @@ mod tests {
             victim_name: None,
             assisters: Vec::new(),
             summoner_name: Some("Alpha".to_string()),
-            level: None,
-            item_id: None,
-            item_name: None,
+            ..Default::default()
         };
 
-        let mut raw_events = normalize_events(&[raw], &registry);
+        let mut raw_events =
+            normalize_events(&[raw], &registry, &mut BountyTracker::default(), true);
         assert_eq!(raw_events.len(), 1);
         assert!(matches!(raw_events[0].kind, EventKind::Respawn));
 
@@ -919,6 +3311,69 @@ mod tests {
         assert_eq!(respawns, 1);
     }
 
+    #[test]
+    fn event_digest_resets_only_for_a_new_game() {
+        let raw = |event_id, event_time| RawEvent {
+            event_id,
+            event_name: "GameStart".to_string(),
+            event_time,
+            ..Default::default()
+        };
+        let digest = DigestState {
+            last_event_id: Some(3),
+            ..DigestState::default()
+        };
+
+        // An ongoing game still lists its GameStart.
+        assert!(!digest.should_reset(&[raw(0, 0.0), raw(3, 40.0), raw(4, 60.0)]));
+        assert!(digest.should_reset(&[raw(0, 0.0)]));
+        assert!(!DigestState::default().should_reset(&[raw(0, 0.0)]));
+    }
+
+    #[test]
+    fn event_digest_resets_when_a_new_game_reuses_the_last_id() {
+        let raw = |event_id, event_name: &str, event_time| RawEvent {
+            event_id,
+            event_name: event_name.to_string(),
+            event_time,
+            ..Default::default()
+        };
+        let last = raw(2, "ChampionKill", 95.0);
+        let digest = DigestState {
+            last_event_id: Some(2),
+            last_event: Some(EventFingerprint::of(&last)),
+            ..DigestState::default()
+        };
+        let ongoing = [raw(0, "GameStart", 0.0), raw(1, "FirstBlood", 95.0), last];
+        assert!(!digest.should_reset(&ongoing));
+
+        // Back-to-back customs: the next game already lists past the previous last id.
+        let next_game = [
+            raw(0, "GameStart", 0.0),
+            raw(1, "MinionsSpawning", 65.0),
+            raw(2, "TurretKilled", 70.0),
+            raw(3, "ChampionKill", 72.0),
+        ];
+        assert!(digest.should_reset(&next_game));
+    }
+
+    #[test]
+    fn loading_screen_is_not_an_outage() {
+        use Availability::{Down, Loading, Up};
+        let mut endpoints = EndpointHealth::default();
+
+        assert!(endpoints.observe(Loading, Up));
+        assert!(!endpoints.observe(Loading, Loading), "reported once");
+        assert!(endpoints.is_loading());
+        assert!(!endpoints.observe(Up, Up));
+        assert!(!endpoints.is_loading());
+
+        // No client at all is an error, whatever the other endpoint says.
+        assert!(!endpoints.observe(Down, Down));
+        assert!(!endpoints.observe(Loading, Down));
+        assert!(endpoints.observe(Loading, Up));
+    }
+
     #[test]
     fn activity_state_scales_intervals() {
         let config = DaemonConfig::default();
@@ -944,4 +3399,111 @@ mod tests {
         let backoff = state.on_error(&config);
         assert_eq!(backoff, config.error_backoff);
     }
+
+    #[test]
+    fn strict_mode_accepts_fields_the_poller_ignores() {
+        let config = DaemonConfig {
+            deserialize_mode: crate::DeserializeMode::Strict,
+            ..DaemonConfig::default()
+        };
+        let mut ctx = PollContext::new(
+            config,
+            Client::new(),
+            UnknownFieldStats::default(),
+            SeriesTracker::default(),
+            Vitals::default(),
+            RegistryMeter::default(),
+        );
+        let players = serde_json::json!([{
+            "summonerName": "Alpha",
+            "team": "ORDER",
+            "level": 1,
+            "isDead": false,
+            "isBot": false,
+            "skinName": "default",
+            "rawSkinName": "game_character_skin_displayname_Ahri_0",
+            "runes": { "keystone": { "id": 8112 } },
+            "screenPositionBottom": "9223372036854775807",
+            "screenPositionCenter": "9223372036854775807",
+            "items": [{
+                "itemID": 1056,
+                "displayName": "Doran's Ring",
+                "canUse": false,
+                "consumable": false,
+                "rawDescription": "GeneratedTip_Item_1056_Description",
+                "rawDisplayName": "Item_1056_Name",
+            }],
+        }]);
+        let list = parse_player_list(players.to_string().as_bytes()).unwrap();
+        ctx.audit_player_list(&list).expect("playerlist accepted");
+        ctx.players.apply(list, 1_000);
+
+        let active = serde_json::json!({
+            "summonerName": "Alpha",
+            "abilities": { "Q": { "abilityLevel": 1 }, "W": {}, "E": {}, "R": {} },
+            "currentGold": 500.0,
+            "level": 1,
+            "riotIdGameName": "Alpha",
+            "riotIdTagLine": "EUW",
+            "teamRelativeColors": true,
+        });
+        ctx.diff_active_player(active.to_string().as_bytes(), 2_000);
+        assert!(ctx.active_player.is_some());
+        assert!(ctx.unknown_fields.snapshot().is_empty());
+    }
+
+    #[test]
+    fn overrunning_polls_shed_optional_endpoints() {
+        let interval = Duration::from_millis(150);
+        let mut budget = PollBudget::default();
+
+        assert!(!budget.record(Duration::from_millis(120), interval));
+        assert!(budget.skipped().is_empty());
+
+        assert!(budget.record(Duration::from_millis(400), interval));
+        assert!(budget.record(Duration::from_millis(300), interval));
+        assert_eq!(budget.skipped(), SKIPPABLE_ENDPOINTS);
+        assert!(budget.skips(ACTIVE_PLAYER) && !budget.skips(GAME_STATS));
+
+        // Close to the interval keeps skipping; well within it brings endpoints back.
+        assert!(!budget.record(Duration::from_millis(100), interval));
+        assert_eq!(budget.skipped().len(), 1);
+        budget.record(Duration::from_millis(40), interval);
+        assert!(budget.skipped().is_empty());
+    }
+
+    #[test]
+    fn overrunning_polls_still_detect_a_new_game() {
+        let mut ctx = PollContext::new(
+            DaemonConfig::default(),
+            Client::new(),
+            UnknownFieldStats::default(),
+            SeriesTracker::default(),
+            Vitals::default(),
+            RegistryMeter::default(),
+        );
+        let interval = Duration::from_millis(150);
+        for _ in 0..5 {
+            ctx.budget.record(Duration::from_millis(400), interval);
+        }
+        assert!(!ctx.budget.skips(GAME_STATS));
+
+        let stats = |game_time| GameStatsResponse {
+            game_time,
+            game_mode: "CLASSIC".to_string(),
+            map_number: 11,
+            map_name: String::new(),
+            map_terrain: String::new(),
+            unknown: HashMap::new(),
+        };
+        ctx.apply_game_stats(&stats(1_200.0), 1_000_000);
+        ctx.digest.last_event_id = Some(42);
+        ctx.lanes_assigned = true;
+
+        // A remake or the next custom restarts the clock; the old game's state goes.
+        ctx.apply_game_stats(&stats(15.0), 1_010_000);
+        assert_eq!(ctx.digest.last_event_id, None);
+        assert!(!ctx.lanes_assigned);
+        assert_eq!(ctx.game_clock_anchor, Some(995_000));
+    }
 }
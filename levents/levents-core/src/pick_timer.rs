@@ -0,0 +1,156 @@
+//! Warnings for a champ select pick or ban about to time out.
+//!
+//! The session only reports the time left when something changes, so the deadline of the
+//! local player's turn is kept here and the websocket loop wakes up when it comes close.
+
+use levents_model::{Event, EventKind, EventPayload, PickTimerWarningEvent};
+use serde::Deserialize;
+use std::time::{Duration, Instant};
+
+use crate::skins::SESSION_URI;
+
+/// Time left on the local player's turn at which the warning is emitted.
+const WARNING_THRESHOLD: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct Session {
+    local_player_cell_id: i64,
+    actions: Vec<Vec<Action>>,
+    timer: Timer,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct Action {
+    id: i64,
+    actor_cell_id: i64,
+    #[serde(rename = "type")]
+    action_type: String,
+    champion_id: u32,
+    is_in_progress: bool,
+    completed: bool,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct Timer {
+    adjusted_time_left_in_phase: u64,
+}
+
+#[derive(Debug)]
+struct Turn {
+    action_id: i64,
+    action: String,
+    champion_id: u32,
+    deadline: Instant,
+}
+
+/// The local player's running pick or ban, and whether it was warned about.
+#[derive(Debug, Default)]
+pub(crate) struct PickTimer {
+    turn: Option<Turn>,
+    warned: Option<i64>,
+}
+
+impl PickTimer {
+    /// A new gameflow phase; a new champ select starts without warnings.
+    pub(crate) fn on_phase(&mut self, phase: &str) {
+        if phase != "ChampSelect" {
+            *self = Self::default();
+        }
+    }
+
+    /// Track the local player's turn from a champ select session update received at `now`.
+    pub(crate) fn on_update(&mut self, uri: &str, data: &serde_json::Value, now: Instant) {
+        if uri != SESSION_URI {
+            return;
+        }
+        let Ok(session) = Session::deserialize(data) else {
+            return;
+        };
+        let local = session.local_player_cell_id;
+        let time_left = Duration::from_millis(session.timer.adjusted_time_left_in_phase);
+        self.turn = session
+            .actions
+            .into_iter()
+            .flatten()
+            .find(|action| {
+                action.actor_cell_id == local && action.is_in_progress && !action.completed
+            })
+            .map(|action| Turn {
+                action_id: action.id,
+                action: action.action_type,
+                champion_id: action.champion_id,
+                deadline: now + time_left,
+            });
+    }
+
+    /// When the loop should next call [`PickTimer::poll`], if a warning is pending.
+    pub(crate) fn next_wakeup(&self) -> Option<Instant> {
+        let turn = self.turn.as_ref()?;
+        (self.warned != Some(turn.action_id)).then(|| {
+            turn.deadline
+                .checked_sub(WARNING_THRESHOLD)
+                .unwrap_or(turn.deadline)
+        })
+    }
+
+    /// A `PickTimerWarning` once the running turn has less than ten seconds left.
+    pub(crate) fn poll(&mut self, now: Instant, ts: u64) -> Option<Event> {
+        let turn = self.turn.as_ref()?;
+        let left = turn.deadline.saturating_duration_since(now);
+        if self.warned == Some(turn.action_id) || left > WARNING_THRESHOLD || left.is_zero() {
+            return None;
+        }
+        self.warned = Some(turn.action_id);
+        Some(Event::new(
+            EventKind::PickTimerWarning,
+            ts,
+            EventPayload::PickTimerWarning(PickTimerWarningEvent {
+                action: turn.action.clone(),
+                // Round up so the warning never claims less time than is left.
+                seconds_left: left.as_millis().div_ceil(1000) as u32,
+                champion_id: Some(turn.champion_id).filter(|&id| id != 0),
+            }),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn warns_once_when_the_local_turn_runs_low() {
+        let mut timer = PickTimer::default();
+        let start = Instant::now();
+        let session = json!({
+            "localPlayerCellId": 3,
+            "actions": [[
+                { "id": 7, "actorCellId": 1, "type": "pick", "isInProgress": true },
+                { "id": 8, "actorCellId": 3, "type": "pick", "championId": 103, "isInProgress": true },
+            ]],
+            "timer": { "adjustedTimeLeftInPhase": 27_500 },
+        });
+        timer.on_update(SESSION_URI, &session, start);
+
+        assert_eq!(
+            timer.next_wakeup(),
+            Some(start + Duration::from_millis(17_500))
+        );
+        assert!(timer.poll(start + Duration::from_secs(5), 1).is_none());
+        let warning = timer.poll(start + Duration::from_secs(18), 2).unwrap();
+        assert_eq!(
+            warning.payload,
+            EventPayload::PickTimerWarning(PickTimerWarningEvent {
+                action: "pick".to_string(),
+                seconds_left: 10,
+                champion_id: Some(103),
+            })
+        );
+        assert!(timer.poll(start + Duration::from_secs(20), 3).is_none());
+        assert_eq!(timer.next_wakeup(), None);
+    }
+}
@@ -0,0 +1,103 @@
+//! Checks run at startup, before the daemon reports ready, and on demand as a self-test.
+//!
+//! A bad interval or an unusable spill directory otherwise only shows once the first game is
+//! running, which on a broadcast is the worst moment to find out.
+
+use anyhow::{bail, ensure, Context, Result};
+use levents_model::Event;
+use std::future::Future;
+use std::time::{Duration, Instant};
+use tokio::time::timeout;
+
+use crate::{DaemonConfig, EventBus, EventFilter};
+
+/// How long a synthetic event may take to reach a subscriber.
+const ROUND_TRIP_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Outcome of one self-test step.
+#[derive(Debug, Clone)]
+pub struct SelfTestCheck {
+    /// Step name: `config`, `scratchBus` or `liveBus`.
+    pub name: &'static str,
+    /// Why the step failed; `None` when it passed.
+    pub error: Option<String>,
+    /// Time the step took.
+    pub elapsed: Duration,
+}
+
+/// Report of [`crate::LiveDaemon::self_test`].
+#[derive(Debug, Clone)]
+pub struct SelfTestReport {
+    /// Steps in the order they ran.
+    pub checks: Vec<SelfTestCheck>,
+    /// Subscribers on the live bus when the test started.
+    pub subscribers: usize,
+    /// Sources connected to the game client (`liveClient`, `lcu`).
+    pub sources_connected: Vec<String>,
+    /// Sources that are turned off in the configuration or not built in.
+    pub sources_disabled: Vec<String>,
+}
+
+impl SelfTestReport {
+    /// Whether every step passed.
+    pub fn passed(&self) -> bool {
+        self.checks.iter().all(|check| check.error.is_none())
+    }
+}
+
+/// Run one self-test step, timing it.
+pub(crate) async fn check(
+    name: &'static str,
+    step: impl Future<Output = Result<()>>,
+) -> SelfTestCheck {
+    let start = Instant::now();
+    let error = step.await.err().map(|error| format!("{error:#}"));
+    SelfTestCheck {
+        name,
+        error,
+        elapsed: start.elapsed(),
+    }
+}
+
+/// Reject settings the sources would only trip over once running.
+pub(crate) fn validate_config(config: &DaemonConfig) -> Result<()> {
+    reqwest::Url::parse(&config.live_base_url)
+        .with_context(|| format!("invalid Live Client URL `{}`", config.live_base_url))?;
+    let intervals = [
+        ("combat poll interval", Some(config.poll_interval_combat)),
+        ("normal poll interval", Some(config.poll_interval_normal)),
+        ("idle poll interval", Some(config.poll_interval_idle)),
+        ("error backoff", Some(config.error_backoff)),
+        (
+            "LCU discovery interval",
+            Some(config.lcu_discovery_interval),
+        ),
+        (
+            "economy snapshot interval",
+            config.economy_snapshot_interval,
+        ),
+        ("win probability interval", config.win_probability_interval),
+        ("memory check interval", config.memory_check_interval),
+        ("source stall timeout", config.source_stall_timeout),
+    ];
+    for (name, interval) in intervals {
+        ensure!(interval != Some(Duration::ZERO), "{name} must not be zero");
+    }
+    ensure!(
+        config.backfill_capacity > 0,
+        "backfill capacity must not be zero"
+    );
+    Ok(())
+}
+
+/// Publish `event` on `bus` and wait for a subscriber to receive it.
+pub(crate) async fn round_trip(bus: &EventBus, event: Event) -> Result<Duration> {
+    let mut subscription = bus.subscribe(EventFilter::kinds([event.kind.clone()]));
+    let start = Instant::now();
+    bus.publish(event);
+    match timeout(ROUND_TRIP_TIMEOUT, subscription.recv()).await {
+        Ok(Some(_)) => Ok(start.elapsed()),
+        Ok(None) => bail!("event bus closed before delivering the synthetic event"),
+        Err(_) => bail!("synthetic event not delivered within {ROUND_TRIP_TIMEOUT:?}"),
+    }
+}
@@ -0,0 +1,116 @@
+//! Friends' presence from the client's friend list.
+//!
+//! Presence says whether a friend is online and what they are playing, which duo
+//! coordination tools use to know when a partner is free. Status messages are free text the
+//! friend wrote, so they are never read; nothing is read at all unless
+//! [`crate::DaemonConfig::lcu_friend_presence`] is set.
+
+use levents_model::{Event, EventKind, EventPayload, FriendPresenceEvent};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+const FRIENDS_URI_PREFIX: &str = "/lol-chat/v1/friends/";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Friend {
+    #[serde(default)]
+    puuid: String,
+    #[serde(default)]
+    game_name: String,
+    #[serde(default)]
+    game_tag: String,
+    #[serde(default)]
+    availability: String,
+    /// Game details; the client reports most values as strings.
+    #[serde(default)]
+    lol: HashMap<String, serde_json::Value>,
+}
+
+/// Presence already reported, by friend.
+#[derive(Debug, Default)]
+pub(crate) struct PresenceTracker {
+    last: HashMap<String, FriendPresenceEvent>,
+}
+
+impl PresenceTracker {
+    /// A `FriendPresence` event when a friend's availability or game changed.
+    pub(crate) fn on_update(
+        &mut self,
+        uri: &str,
+        data: &serde_json::Value,
+        ts: u64,
+    ) -> Option<Event> {
+        let id = uri.strip_prefix(FRIENDS_URI_PREFIX)?;
+        if id.is_empty() || id.contains('/') {
+            return None;
+        }
+        let friend = Friend::deserialize(data).ok()?;
+        let lol = |key: &str| {
+            let value = match friend.lol.get(key)? {
+                serde_json::Value::Null => return None,
+                serde_json::Value::String(value) => value.clone(),
+                value => value.to_string(),
+            };
+            (!value.is_empty()).then_some(value)
+        };
+        let presence = FriendPresenceEvent {
+            riot_id: format!("{}#{}", friend.game_name, friend.game_tag),
+            puuid: friend.puuid.clone(),
+            availability: friend.availability.clone(),
+            game_status: lol("gameStatus"),
+            queue_type: lol("gameQueueType"),
+            champion_id: lol("championId")
+                .and_then(|id| id.parse().ok())
+                .filter(|&id| id != 0),
+        };
+        if self.last.get(id) == Some(&presence) {
+            return None;
+        }
+        self.last.insert(id.to_string(), presence.clone());
+        Some(Event::new(
+            EventKind::FriendPresence,
+            ts,
+            EventPayload::FriendPresence(presence),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn presence_changes_are_reported_without_status_messages() {
+        let mut tracker = PresenceTracker::default();
+        let uri = "/lol-chat/v1/friends/3f2a9c1d@eu1.pvp.net";
+        let friend = |game_status: &str, message: &str| {
+            json!({
+                "puuid": "3f2a9c1d",
+                "gameName": "Caps",
+                "gameTag": "EUW",
+                "availability": "dnd",
+                "statusMessage": message,
+                "lol": { "gameStatus": game_status, "gameQueueType": "RANKED_SOLO_5x5", "championId": "7" },
+            })
+        };
+
+        let event = tracker
+            .on_update(uri, &friend("inGame", "tilted"), 1)
+            .unwrap();
+        let EventPayload::FriendPresence(presence) = event.payload else {
+            panic!("unexpected payload");
+        };
+        assert_eq!(presence.riot_id, "Caps#EUW");
+        assert_eq!(presence.game_status.as_deref(), Some("inGame"));
+        assert_eq!(presence.champion_id, Some(7));
+        assert!(!serde_json::to_string(&presence).unwrap().contains("tilted"));
+
+        // A new status message alone is not a presence change.
+        assert!(tracker.on_update(uri, &friend("inGame", "gg"), 2).is_none());
+        assert!(tracker
+            .on_update(uri, &friend("outOfGame", "gg"), 3)
+            .is_some());
+    }
+}
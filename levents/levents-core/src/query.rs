@@ -0,0 +1,133 @@
+//! Lookups over the history an [`EventBus`] keeps for backfill.
+//!
+//! Embedded apps often only need something like "this player's kills so far"; querying the
+//! backlog saves them keeping their own copy of the event stream.
+
+use levents_model::{Event, EventKind, TimestampMs};
+
+use crate::EventBus;
+
+/// Query over the events an [`EventBus`] still retains, built with [`EventBus::events`].
+///
+/// Only the backfill history is searched: the last `backfill_capacity` events, spilled ones
+/// included. Heartbeats are never retained, so they never match.
+#[derive(Debug, Clone)]
+#[must_use = "a query does nothing until it is collected"]
+pub struct Query {
+    bus: EventBus,
+    kinds: Vec<EventKind>,
+    player: Option<String>,
+    since: Option<TimestampMs>,
+    until: Option<TimestampMs>,
+}
+
+impl Query {
+    pub(crate) fn new(bus: EventBus) -> Self {
+        Self {
+            bus,
+            kinds: Vec::new(),
+            player: None,
+            since: None,
+            until: None,
+        }
+    }
+
+    /// Only events of `kind`; call it again to accept several kinds.
+    pub fn kind(mut self, kind: EventKind) -> Self {
+        self.kinds.push(kind);
+        self
+    }
+
+    /// Only events referencing the player named `name`, by Riot ID, its game name or summoner
+    /// name.
+    pub fn player(mut self, name: impl Into<String>) -> Self {
+        self.player = Some(name.into());
+        self
+    }
+
+    /// Only events with a `ts` at or after `ts`.
+    ///
+    /// Live Client events carry game time and the others wall-clock time, so time bounds are
+    /// best combined with a kind.
+    pub fn since(mut self, ts: TimestampMs) -> Self {
+        self.since = Some(ts);
+        self
+    }
+
+    /// Only events with a `ts` at or before `ts`.
+    pub fn until(mut self, ts: TimestampMs) -> Self {
+        self.until = Some(ts);
+        self
+    }
+
+    /// Whether `event` passes every condition.
+    pub fn matches(&self, event: &Event) -> bool {
+        (self.kinds.is_empty() || self.kinds.contains(&event.kind))
+            && self.since.map_or(true, |since| event.ts >= since)
+            && self.until.map_or(true, |until| event.ts <= until)
+            && self.player.as_deref().map_or(true, |name| {
+                event
+                    .payload
+                    .players()
+                    .iter()
+                    .any(|player| player.answers_to(name))
+            })
+    }
+
+    /// Matching events, oldest first.
+    pub fn collect(self) -> Vec<Event> {
+        self.bus.retained(|event| self.matches(event))
+    }
+
+    /// The newest matching event.
+    pub fn last(self) -> Option<Event> {
+        self.collect().pop()
+    }
+
+    /// Number of matching events.
+    pub fn count(self) -> usize {
+        self.collect().len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use levents_model::{EventPayload, PlayerEvent, PlayerRef, Team};
+
+    fn player_event(kind: EventKind, ts: TimestampMs, name: &str) -> Event {
+        Event::new(
+            kind,
+            ts,
+            EventPayload::Player(PlayerEvent {
+                player: PlayerRef::new(name, Team::Order, 0),
+                bounty: None,
+            }),
+        )
+    }
+
+    #[test]
+    fn queries_filter_the_retained_history() {
+        let bus = EventBus::new();
+        bus.publish(player_event(EventKind::Kill, 1_000, "Alpha"));
+        bus.publish(player_event(EventKind::Death, 1_000, "Bravo"));
+        bus.publish(player_event(EventKind::Kill, 5_000, "Bravo"));
+        bus.publish(player_event(EventKind::Kill, 9_000, "Alpha"));
+
+        let kills = bus.events().kind(EventKind::Kill).player("Alpha").collect();
+        assert_eq!(
+            kills.iter().map(|event| event.ts).collect::<Vec<_>>(),
+            [1_000, 9_000]
+        );
+        assert_eq!(bus.events().kind(EventKind::Kill).since(2_000).count(), 2);
+        assert_eq!(bus.events().player("Bravo").until(1_000).count(), 1);
+        assert_eq!(
+            bus.events()
+                .kind(EventKind::Kill)
+                .last()
+                .map(|event| event.seq),
+            Some(4)
+        );
+        assert_eq!(bus.events().player("Charlie").count(), 0);
+    }
+}
@@ -0,0 +1,151 @@
+//! Matchmaking telemetry from the client's search state.
+//!
+//! `/lol-matchmaking/v1/search` is pushed about once a second while in queue; it becomes one
+//! `QueueEntered`, a `QueueTimeUpdate` per elapsed second and a `MatchFound` once a ready
+//! check starts.
+
+use levents_model::{Event, EventKind, EventPayload, QueueEvent};
+use serde::Deserialize;
+
+pub(crate) const SEARCH_URI: &str = "/lol-matchmaking/v1/search";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Search {
+    #[serde(default)]
+    search_state: String,
+    #[serde(default)]
+    time_in_queue: f64,
+    #[serde(default)]
+    estimated_queue_time: f64,
+    #[serde(default)]
+    ready_check: Option<ReadyCheck>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReadyCheck {
+    #[serde(default)]
+    state: String,
+}
+
+impl Search {
+    fn match_found(&self) -> bool {
+        self.search_state == "Found"
+            || self
+                .ready_check
+                .as_ref()
+                .is_some_and(|check| check.state == "InProgress")
+    }
+
+    fn payload(&self) -> QueueEvent {
+        QueueEvent {
+            time_in_queue_secs: self.time_in_queue.max(0.0) as u32,
+            estimated_queue_secs: self.estimated_queue_time.max(0.0) as u32,
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+enum QueueState {
+    #[default]
+    Idle,
+    /// Searching, with the last whole second reported.
+    Searching(u32),
+    Found,
+}
+
+/// Where the local player is in matchmaking.
+#[derive(Debug, Default)]
+pub(crate) struct QueueTracker {
+    state: QueueState,
+}
+
+impl QueueTracker {
+    /// Turn a search update into queue events.
+    pub(crate) fn on_update(
+        &mut self,
+        uri: &str,
+        event_type: &str,
+        data: &serde_json::Value,
+        ts: u64,
+    ) -> Vec<Event> {
+        if uri != SEARCH_URI {
+            return Vec::new();
+        }
+        let search = match Search::deserialize(data) {
+            Ok(search) if event_type != "Delete" => search,
+            // The search resource goes away when the queue is left.
+            _ => {
+                self.state = QueueState::Idle;
+                return Vec::new();
+            }
+        };
+
+        let payload = search.payload();
+        let kind = if search.match_found() {
+            if self.state == QueueState::Found {
+                return Vec::new();
+            }
+            self.state = QueueState::Found;
+            EventKind::MatchFound
+        } else if search.search_state == "Searching" {
+            match self.state {
+                QueueState::Searching(second) if second == payload.time_in_queue_secs => {
+                    return Vec::new()
+                }
+                QueueState::Searching(_) => EventKind::QueueTimeUpdate,
+                // A declined or failed ready check puts the player back in the same queue.
+                QueueState::Found => EventKind::QueueTimeUpdate,
+                QueueState::Idle => EventKind::QueueEntered,
+            }
+        } else {
+            self.state = QueueState::Idle;
+            return Vec::new();
+        };
+        if kind != EventKind::MatchFound {
+            self.state = QueueState::Searching(payload.time_in_queue_secs);
+        }
+        vec![Event::new(kind, ts, EventPayload::Queue(payload))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn search_updates_become_queue_events() {
+        let mut tracker = QueueTracker::default();
+        let mut update = |state: &str, time: f64, event_type: &str| {
+            let search = json!({
+                "searchState": state,
+                "timeInQueue": time,
+                "estimatedQueueTime": 95.2,
+                "readyCheck": { "state": if state == "Found" { "InProgress" } else { "Invalid" } },
+            });
+            tracker
+                .on_update(SEARCH_URI, event_type, &search, 1)
+                .into_iter()
+                .map(|event| event.kind)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            update("Searching", 0.0, "Create"),
+            [EventKind::QueueEntered]
+        );
+        assert_eq!(update("Searching", 0.6, "Update"), []);
+        assert_eq!(
+            update("Searching", 1.1, "Update"),
+            [EventKind::QueueTimeUpdate]
+        );
+        assert_eq!(update("Found", 42.0, "Update"), [EventKind::MatchFound]);
+        assert_eq!(update("Found", 43.0, "Update"), []);
+        assert_eq!(update("Searching", 0.0, "Delete"), []);
+        assert_eq!(
+            update("Searching", 0.0, "Create"),
+            [EventKind::QueueEntered]
+        );
+    }
+}
@@ -0,0 +1,161 @@
+//! Health and resource bar tracking for `ResourceChanged` events.
+//!
+//! Both bars move on nearly every poll during a fight, so a player is only reported again once
+//! a bar moved by the configured share of its maximum. Low health uses two thresholds: it is
+//! set when health drops under `low_health` and cleared only above `low_health_clear`, so a
+//! champion hovering around the threshold does not raise an alert per poll.
+
+#[cfg(feature = "live-client")]
+use std::collections::HashMap;
+
+#[cfg(feature = "live-client")]
+use levents_model::{Event, EventKind, EventPayload, PlayerRef, ResourceChangedEvent};
+
+/// When `ResourceChanged` events are sent; all values are shares of the bar's maximum.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResourceThresholds {
+    /// Change of either bar since the last report that triggers a new one.
+    pub min_change: f32,
+    /// Health share under which a player is reported as low.
+    pub low_health: f32,
+    /// Health share a low player has to recover past before the flag is cleared.
+    pub low_health_clear: f32,
+}
+
+impl Default for ResourceThresholds {
+    fn default() -> Self {
+        Self {
+            min_change: 0.1,
+            low_health: 0.25,
+            low_health_clear: 0.35,
+        }
+    }
+}
+
+/// Bars of one champion as read from `championStats`.
+#[cfg(feature = "live-client")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ResourceLevels {
+    pub health: f32,
+    pub max_health: f32,
+    pub resource: f32,
+    pub max_resource: f32,
+}
+
+#[cfg(feature = "live-client")]
+#[derive(Debug, Clone, Copy)]
+struct Reported {
+    levels: ResourceLevels,
+    low_health: bool,
+}
+
+/// Last reported bars per player, keyed like the player registry.
+#[cfg(feature = "live-client")]
+#[derive(Debug, Default)]
+pub(crate) struct ResourceTracker {
+    thresholds: ResourceThresholds,
+    reported: HashMap<String, Reported>,
+}
+
+#[cfg(feature = "live-client")]
+impl ResourceTracker {
+    pub(crate) fn new(thresholds: ResourceThresholds) -> Self {
+        Self {
+            thresholds,
+            reported: HashMap::new(),
+        }
+    }
+
+    /// A `ResourceChanged` event when `levels` moved past a threshold since the last one sent
+    /// for `key`, or when the player is seen for the first time.
+    pub(crate) fn observe(
+        &mut self,
+        key: &str,
+        player: &PlayerRef,
+        levels: ResourceLevels,
+        ts_ms: u64,
+    ) -> Option<Event> {
+        if levels.max_health <= 0.0 {
+            return None;
+        }
+        let health_share = levels.health / levels.max_health;
+        let previous = self.reported.get(key);
+        let low_health = match previous {
+            Some(previous) if previous.low_health => {
+                health_share < self.thresholds.low_health_clear
+            }
+            _ => health_share < self.thresholds.low_health,
+        };
+        if let Some(previous) = previous {
+            let moved = |now: f32, before: f32, max: f32| {
+                max > 0.0 && (now - before).abs() / max >= self.thresholds.min_change
+            };
+            let changed = low_health != previous.low_health
+                || moved(levels.health, previous.levels.health, levels.max_health)
+                || moved(
+                    levels.resource,
+                    previous.levels.resource,
+                    levels.max_resource,
+                );
+            if !changed {
+                return None;
+            }
+        }
+
+        self.reported
+            .insert(key.to_string(), Reported { levels, low_health });
+        Some(Event::new(
+            EventKind::ResourceChanged,
+            ts_ms,
+            EventPayload::ResourceChanged(ResourceChangedEvent {
+                player: player.clone(),
+                health: levels.health,
+                max_health: levels.max_health,
+                resource: levels.resource,
+                max_resource: levels.max_resource,
+                low_health,
+            }),
+        ))
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.reported.clear();
+    }
+}
+
+#[cfg(all(test, feature = "live-client"))]
+mod tests {
+    use super::*;
+    use levents_model::Team;
+
+    #[test]
+    fn low_health_is_reported_with_hysteresis() {
+        let player = PlayerRef::new("Alpha", Team::Order, 0);
+        let mut tracker = ResourceTracker::new(ResourceThresholds::default());
+        let mut observe = |health: f32, resource: f32| {
+            let levels = ResourceLevels {
+                health,
+                max_health: 1_000.0,
+                resource,
+                max_resource: 500.0,
+            };
+            tracker
+                .observe("Alpha", &player, levels, 1_000)
+                .map(|event| match event.payload {
+                    EventPayload::ResourceChanged(inner) => (inner.health, inner.low_health),
+                    other => panic!("unexpected payload {other:?}"),
+                })
+        };
+
+        assert_eq!(observe(1_000.0, 500.0), Some((1_000.0, false)));
+        // Under a tenth of either bar.
+        assert_eq!(observe(950.0, 460.0), None);
+        assert_eq!(observe(880.0, 460.0), Some((880.0, false)));
+        assert_eq!(observe(880.0, 380.0), Some((880.0, false)));
+        assert_eq!(observe(240.0, 380.0), Some((240.0, true)));
+        // Back over the low threshold, but not past the clear one.
+        assert_eq!(observe(300.0, 380.0), None);
+        assert_eq!(observe(330.0, 380.0), None);
+        assert_eq!(observe(360.0, 380.0), Some((360.0, false)));
+    }
+}
@@ -0,0 +1,216 @@
+//! Operator-supplied player identities and team branding merged into outgoing events.
+//!
+//! Broadcast overlays want branded names rather than raw summoner names, so the daemon can be
+//! given a roster file mapping summoner names to a [`PlayerIdentity`] and sides to
+//! [`TeamMetadata`].
+
+use anyhow::{Context, Result};
+use levents_model::{EventBatch, PlayerIdentity, PlayerRef, Team, TeamInfoEvent, TeamMetadata};
+use serde::Deserialize;
+use std::{collections::HashMap, path::Path, sync::Arc};
+
+/// Summoner name to identity mapping, plus per-side team metadata, loaded from a JSON file.
+///
+/// ```json
+/// {
+///   "players": { "Hide on bush": { "display_name": "Faker", "team_tag": "T1" } },
+///   "teams": { "order": { "name": "T1", "tag": "T1", "color": "#e2012d" } }
+/// }
+/// ```
+///
+/// A team whose `tag` matches most of a side's players' `team_tag` is placed on that side even
+/// if it is configured under the other one, so side swaps need no config change.
+#[derive(Debug, Clone, Default)]
+pub struct Roster {
+    players: Arc<HashMap<String, PlayerIdentity>>,
+    teams: Arc<HashMap<Team, TeamMetadata>>,
+}
+
+#[derive(Deserialize)]
+struct RosterFile {
+    #[serde(default)]
+    players: HashMap<String, PlayerIdentity>,
+    #[serde(default)]
+    teams: HashMap<Team, TeamMetadata>,
+}
+
+impl Roster {
+    /// Build a roster from `(summoner name, identity)` pairs.
+    pub fn new(players: impl IntoIterator<Item = (String, PlayerIdentity)>) -> Self {
+        Self {
+            players: Arc::new(players.into_iter().collect()),
+            teams: Arc::default(),
+        }
+    }
+
+    /// Attach team metadata keyed by the side it normally plays on.
+    pub fn with_teams(mut self, teams: impl IntoIterator<Item = (Team, TeamMetadata)>) -> Self {
+        self.teams = Arc::new(teams.into_iter().collect());
+        self
+    }
+
+    /// Parse a roster from its JSON representation.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let file: RosterFile = serde_json::from_str(json).context("invalid roster JSON")?;
+        Ok(Self::new(file.players).with_teams(file.teams))
+    }
+
+    /// Read and parse a roster file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read roster {}", path.display()))?;
+        Self::from_json(&json)
+    }
+
+    /// Identity configured for `summoner_name`, if any.
+    pub fn identity(&self, summoner_name: &str) -> Option<&PlayerIdentity> {
+        self.players.get(summoner_name)
+    }
+
+    /// Identity configured for `player`, listed under their Riot ID or summoner name.
+    fn identity_of(&self, player: &PlayerRef) -> Option<&PlayerIdentity> {
+        player
+            .riot_id
+            .as_deref()
+            .and_then(|riot_id| self.identity(riot_id))
+            .or_else(|| self.identity(&player.summoner_name))
+    }
+
+    /// Whether the roster lists no players.
+    pub fn is_empty(&self) -> bool {
+        self.players.is_empty()
+    }
+
+    /// Metadata for both sides of a game with the given players, if any team is configured.
+    #[cfg_attr(not(feature = "live-client"), allow(dead_code))]
+    pub(crate) fn team_info<'a>(
+        &self,
+        players: impl IntoIterator<Item = &'a PlayerRef>,
+    ) -> Option<TeamInfoEvent> {
+        if self.teams.is_empty() {
+            return None;
+        }
+
+        let mut tags: HashMap<(Team, &str), usize> = HashMap::new();
+        for player in players {
+            let tag = self
+                .identity_of(player)
+                .and_then(|identity| identity.team_tag.as_deref());
+            if let Some(tag) = tag {
+                *tags.entry((player.team.clone(), tag)).or_default() += 1;
+            }
+        }
+
+        let side = |team: Team| {
+            let tag = tags
+                .iter()
+                .filter(|((side, _), _)| *side == team)
+                .max_by_key(|(_, count)| **count)
+                .map(|((_, tag), _)| *tag);
+            tag.and_then(|tag| {
+                self.teams
+                    .values()
+                    .find(|metadata| metadata.tag.as_deref() == Some(tag))
+            })
+            .or_else(|| self.teams.get(&team))
+            .cloned()
+        };
+
+        Some(TeamInfoEvent {
+            order: side(Team::Order),
+            chaos: side(Team::Chaos),
+            series: None,
+        })
+    }
+
+    /// Attach identities to every listed player referenced by `batch`.
+    #[cfg_attr(not(feature = "live-client"), allow(dead_code))]
+    pub(crate) fn enrich(&self, batch: &mut EventBatch) {
+        if self.is_empty() {
+            return;
+        }
+        for event in &mut batch.events {
+            for player in event.payload.players_mut() {
+                if let Some(identity) = self.identity_of(player) {
+                    player.identity = Some(identity.clone());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use levents_model::{Event, EventKind, EventPayload, PlayerEvent};
+
+    fn kill(name: &str) -> Event {
+        Event::new(
+            EventKind::Kill,
+            0,
+            EventPayload::Player(PlayerEvent {
+                player: PlayerRef::new(name, Team::Order, 0),
+                bounty: None,
+            }),
+        )
+    }
+
+    fn identity_of(event: &Event) -> Option<&PlayerIdentity> {
+        match &event.payload {
+            EventPayload::Player(inner) => inner.player.identity.as_ref(),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn listed_players_get_their_identity() {
+        let roster = Roster::from_json(
+            r#"{"players": {"Hide on bush": {"display_name": "Faker", "team_tag": "T1",
+                "socials": {"twitter": "@faker"}}}}"#,
+        )
+        .expect("roster");
+        let mut batch = EventBatch::new(vec![kill("Hide on bush"), kill("Stranger")]);
+
+        roster.enrich(&mut batch);
+
+        let faker = identity_of(&batch.events[0]).expect("identity");
+        assert_eq!(faker.display_name, "Faker");
+        assert_eq!(faker.team_tag.as_deref(), Some("T1"));
+        assert_eq!(faker.socials["twitter"], "@faker");
+        assert_eq!(identity_of(&batch.events[1]), None);
+    }
+
+    #[test]
+    fn team_info_follows_player_tags_across_sides() {
+        let roster = Roster::from_json(
+            r##"{
+                "players": {
+                    "Hide on bush": {"display_name": "Faker", "team_tag": "T1"},
+                    "Chovy": {"display_name": "Chovy", "team_tag": "GEN"}
+                },
+                "teams": {
+                    "order": {"name": "T1", "tag": "T1", "color": "#e2012d"},
+                    "chaos": {"name": "Gen.G", "tag": "GEN"}
+                }
+            }"##,
+        )
+        .expect("roster");
+
+        let swapped = [
+            PlayerRef::new("Chovy", Team::Order, 0),
+            PlayerRef::new("Hide on bush", Team::Chaos, 0),
+        ];
+        let info = roster.team_info(&swapped).expect("team info");
+        assert_eq!(info.order.map(|team| team.name).as_deref(), Some("Gen.G"));
+        assert_eq!(info.chaos.map(|team| team.name).as_deref(), Some("T1"));
+
+        let unknown = [PlayerRef::new("Stranger", Team::Order, 0)];
+        let info = roster.team_info(&unknown).expect("team info");
+        assert_eq!(
+            info.order.and_then(|team| team.color).as_deref(),
+            Some("#e2012d")
+        );
+        assert!(Roster::default().team_info(&unknown).is_none());
+    }
+}
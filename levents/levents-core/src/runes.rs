@@ -0,0 +1,70 @@
+//! The local player's rune page, captured when champ select locks it in.
+//!
+//! Runes can still be edited after the champion is locked, up to the end of champ select, so
+//! the page is read once champ select hands over to the game rather than at the pick itself.
+
+use levents_model::{Event, EventKind, EventPayload, RunePageEvent};
+use serde::Deserialize;
+
+pub(crate) const CURRENT_PAGE_URI: &str = "/lol-perks/v1/currentpage";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RunePage {
+    #[serde(default)]
+    name: String,
+    primary_style_id: u32,
+    sub_style_id: u32,
+    #[serde(default)]
+    selected_perk_ids: Vec<u32>,
+}
+
+/// Whether moving from `previous` to `phase` means champ select finished and the game is
+/// starting; a dodge goes back to the lobby or queue instead.
+pub(crate) fn champ_select_ended(previous: Option<&str>, phase: &str) -> bool {
+    previous == Some("ChampSelect") && matches!(phase, "GameStart" | "InProgress")
+}
+
+/// A `RunePageLocked` event for a `/lol-perks/v1/currentpage` response.
+pub(crate) fn rune_page_event(data: &serde_json::Value, ts: u64) -> Option<Event> {
+    let page = RunePage::deserialize(data).ok()?;
+    Some(Event::new(
+        EventKind::RunePageLocked,
+        ts,
+        EventPayload::RunePage(RunePageEvent {
+            name: page.name,
+            primary_style_id: page.primary_style_id,
+            sub_style_id: page.sub_style_id,
+            perk_ids: page.selected_perk_ids,
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn locked_page_is_read_when_champ_select_hands_over() {
+        assert!(champ_select_ended(Some("ChampSelect"), "GameStart"));
+        assert!(!champ_select_ended(Some("ChampSelect"), "Lobby"));
+        assert!(!champ_select_ended(Some("Lobby"), "GameStart"));
+
+        let page = json!({
+            "id": 51,
+            "name": "Conqueror top",
+            "primaryStyleId": 8000,
+            "subStyleId": 8400,
+            "selectedPerkIds": [8010, 9111, 9104, 8299, 8444, 8242, 5005, 5008, 5001],
+            "isActive": true,
+        });
+        let event = rune_page_event(&page, 1).unwrap();
+        assert_eq!(event.kind, EventKind::RunePageLocked);
+        let EventPayload::RunePage(page) = event.payload else {
+            panic!("unexpected payload");
+        };
+        assert_eq!((page.primary_style_id, page.sub_style_id), (8000, 8400));
+        assert_eq!(page.perk_ids.len(), 9);
+    }
+}
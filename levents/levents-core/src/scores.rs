@@ -0,0 +1,33 @@
+//! Thresholds for the creep score and vision score events.
+//!
+//! Both scores tick up every few seconds for every player, so an event per change would drown
+//! the stream; a score is only reported again once it has grown by the configured minimum.
+
+/// Minimum growth before a player's score is reported again; `None` disables the events.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoreDeltas {
+    /// Creep score growth that triggers a `CsDelta` event.
+    pub creep_score: Option<u32>,
+    /// Vision score growth that triggers a `VisionScoreDelta` event.
+    pub vision_score: Option<f32>,
+}
+
+impl Default for ScoreDeltas {
+    fn default() -> Self {
+        Self {
+            // A wave is six or seven minions; ten avoids an event per last hit.
+            creep_score: Some(10),
+            vision_score: Some(5.0),
+        }
+    }
+}
+
+impl ScoreDeltas {
+    /// No score events at all.
+    pub fn none() -> Self {
+        Self {
+            creep_score: None,
+            vision_score: None,
+        }
+    }
+}
@@ -0,0 +1,210 @@
+//! Best-of series context carried across game sessions.
+//!
+//! Scores are kept per team rather than per side: teams are identified by their roster tag (or
+//! name), so a side swap between games moves each team's wins with it.
+
+use levents_model::{SeriesInfo, Team, TeamInfoEvent, TeamMetadata};
+use parking_lot::Mutex;
+use std::{collections::HashMap, sync::Arc};
+
+/// Operator-supplied series context, e.g. from a control command before game 2 of a Bo5.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SeriesSettings {
+    /// Series length; `0` clears the series.
+    pub best_of: u32,
+    /// 1-based number of the next (or current) game.
+    pub game_number: u32,
+    /// Wins of the team on the order side of the next (or current) game.
+    pub order_wins: u32,
+    /// Wins of the team on the chaos side of the next (or current) game.
+    pub chaos_wins: u32,
+}
+
+/// Shared series state; cloning yields a handle to the same series.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SeriesTracker {
+    state: Arc<Mutex<Option<SeriesState>>>,
+}
+
+#[derive(Debug)]
+struct SeriesState {
+    best_of: u32,
+    game_number: u32,
+    wins: HashMap<String, u32>,
+    /// Team keys on (order, chaos) for the current game, once it has started.
+    sides: Option<(String, String)>,
+    sides_swapped: bool,
+    /// Side-relative scores set before the teams of the next game were known.
+    pending: Option<(u32, u32)>,
+    /// Whether `game_number` already refers to a game that has not started yet.
+    awaiting_game: bool,
+    /// Team credited with the current game's win, if it has been decided.
+    winner: Option<String>,
+}
+
+impl SeriesTracker {
+    /// Replace the series context; `best_of == 0` clears it.
+    pub(crate) fn configure(&self, settings: SeriesSettings) {
+        let mut state = self.state.lock();
+        if settings.best_of == 0 {
+            *state = None;
+            return;
+        }
+
+        let sides = state.as_ref().and_then(|state| state.sides.clone());
+        let mut next = SeriesState {
+            best_of: settings.best_of,
+            game_number: settings.game_number.max(1),
+            wins: HashMap::new(),
+            sides: None,
+            sides_swapped: false,
+            pending: Some((settings.order_wins, settings.chaos_wins)),
+            awaiting_game: true,
+            winner: None,
+        };
+        if let Some(sides) = sides {
+            // Mid-game correction: the scores refer to the teams already on the map.
+            next.apply_pending(&sides);
+            next.sides = Some(sides);
+            next.awaiting_game = false;
+        }
+        *state = Some(next);
+    }
+
+    /// Current context, if a series is configured.
+    #[cfg_attr(not(feature = "live-client"), allow(dead_code))]
+    pub(crate) fn info(&self) -> Option<SeriesInfo> {
+        self.state.lock().as_ref().map(SeriesState::info)
+    }
+
+    /// A new game started with the teams described by `teams`.
+    #[cfg_attr(not(feature = "live-client"), allow(dead_code))]
+    pub(crate) fn begin_game(&self, teams: &TeamInfoEvent) -> Option<SeriesInfo> {
+        let mut guard = self.state.lock();
+        let state = guard.as_mut()?;
+        let sides = (
+            team_key(teams.order.as_ref(), Team::Order),
+            team_key(teams.chaos.as_ref(), Team::Chaos),
+        );
+
+        if !state.awaiting_game {
+            state.game_number += 1;
+        }
+        state.awaiting_game = false;
+        state.winner = None;
+        state.sides_swapped = state
+            .sides
+            .as_ref()
+            .is_some_and(|(order, chaos)| *order == sides.1 && *chaos == sides.0);
+        state.apply_pending(&sides);
+        state.sides = Some(sides);
+        Some(state.info())
+    }
+
+    /// Count a win for `winner` in the current game.
+    ///
+    /// Each game counts once: recording a different winner later (a manual override) moves the
+    /// win instead of adding another.
+    pub(crate) fn record_win(&self, winner: &Team) -> Option<SeriesInfo> {
+        let mut guard = self.state.lock();
+        let state = guard.as_mut()?;
+        if let Some((order, chaos)) = &state.sides {
+            let key = match winner {
+                Team::Order => order.clone(),
+                Team::Chaos => chaos.clone(),
+                Team::Neutral => return Some(state.info()),
+            };
+            if state.winner.as_ref() != Some(&key) {
+                if let Some(previous) = state.winner.take() {
+                    let wins = state.wins.entry(previous).or_default();
+                    *wins = wins.saturating_sub(1);
+                }
+                *state.wins.entry(key.clone()).or_default() += 1;
+                state.winner = Some(key);
+            }
+        }
+        Some(state.info())
+    }
+}
+
+impl SeriesState {
+    fn apply_pending(&mut self, (order, chaos): &(String, String)) {
+        if let Some((order_wins, chaos_wins)) = self.pending.take() {
+            self.wins.insert(order.clone(), order_wins);
+            self.wins.insert(chaos.clone(), chaos_wins);
+        }
+    }
+
+    fn info(&self) -> SeriesInfo {
+        let wins = |key: &String| self.wins.get(key).copied().unwrap_or_default();
+        let (order_wins, chaos_wins) = match (&self.sides, self.pending) {
+            (_, Some(pending)) => pending,
+            (Some((order, chaos)), None) => (wins(order), wins(chaos)),
+            (None, None) => (0, 0),
+        };
+        SeriesInfo {
+            best_of: self.best_of,
+            game_number: self.game_number,
+            order_wins,
+            chaos_wins,
+            sides_swapped: self.sides_swapped,
+        }
+    }
+}
+
+/// Stable identity for a team across games: its tag, its name, or else the side itself.
+fn team_key(metadata: Option<&TeamMetadata>, side: Team) -> String {
+    metadata
+        .map(|team| team.tag.clone().unwrap_or_else(|| team.name.clone()))
+        .unwrap_or_else(|| format!("{side:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn teams(order: &str, chaos: &str) -> TeamInfoEvent {
+        let team = |tag: &str| TeamMetadata {
+            name: tag.to_string(),
+            tag: Some(tag.to_string()),
+            ..TeamMetadata::default()
+        };
+        TeamInfoEvent {
+            order: Some(team(order)),
+            chaos: Some(team(chaos)),
+            series: None,
+        }
+    }
+
+    #[test]
+    fn wins_follow_teams_across_side_swaps() {
+        let series = SeriesTracker::default();
+        assert!(series.begin_game(&teams("T1", "GEN")).is_none());
+
+        series.configure(SeriesSettings {
+            best_of: 5,
+            game_number: 2,
+            order_wins: 1,
+            chaos_wins: 0,
+        });
+        let game_two = series.begin_game(&teams("T1", "GEN")).expect("series");
+        assert_eq!((game_two.game_number, game_two.order_wins), (2, 1));
+
+        let after = series.record_win(&Team::Chaos).expect("series");
+        assert_eq!((after.order_wins, after.chaos_wins), (1, 1));
+
+        let game_three = series.begin_game(&teams("GEN", "T1")).expect("series");
+        assert_eq!(game_three.game_number, 3);
+        assert!(game_three.sides_swapped);
+        series.record_win(&Team::Order);
+        let info = series.info().expect("series");
+        assert_eq!((info.order_wins, info.chaos_wins), (2, 1));
+
+        // An operator override moves the game's win rather than counting it twice.
+        let corrected = series.record_win(&Team::Chaos).expect("series");
+        assert_eq!((corrected.order_wins, corrected.chaos_wins), (1, 2));
+
+        series.configure(SeriesSettings::default());
+        assert!(series.info().is_none());
+    }
+}
@@ -0,0 +1,125 @@
+//! Skin and chroma picks from the champ select loadout.
+//!
+//! The champ select session lists the skin every teammate has selected; opponents' picks are
+//! not visible until the loading screen. Overlays use the events to pre-load splash art.
+
+use levents_model::{Event, EventKind, EventPayload, SkinSelectedEvent};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+pub(crate) const SESSION_URI: &str = "/lol-champ-select/v1/session";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Session {
+    #[serde(default)]
+    my_team: Vec<Member>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Member {
+    cell_id: u32,
+    #[serde(default)]
+    champion_id: u32,
+    #[serde(default)]
+    selected_skin_id: u32,
+    #[serde(default)]
+    game_name: String,
+    #[serde(default)]
+    tag_line: String,
+}
+
+impl Member {
+    fn riot_id(&self) -> Option<String> {
+        match (self.game_name.is_empty(), self.tag_line.is_empty()) {
+            (true, _) => None,
+            (false, true) => Some(self.game_name.clone()),
+            (false, false) => Some(format!("{}#{}", self.game_name, self.tag_line)),
+        }
+    }
+}
+
+/// Skins already reported in the current champ select, by cell.
+#[derive(Debug, Default)]
+pub(crate) struct SkinTracker {
+    selected: HashMap<u32, (u32, u32)>,
+}
+
+impl SkinTracker {
+    /// A new gameflow phase; a new champ select starts without picks.
+    pub(crate) fn on_phase(&mut self, phase: &str) {
+        if phase == "ChampSelect" {
+            self.selected.clear();
+        }
+    }
+
+    /// `SkinSelected` events for teammates whose skin changed in a session update.
+    pub(crate) fn on_update(&mut self, uri: &str, data: &serde_json::Value, ts: u64) -> Vec<Event> {
+        if uri != SESSION_URI {
+            return Vec::new();
+        }
+        let Ok(session) = Session::deserialize(data) else {
+            return Vec::new();
+        };
+        session
+            .my_team
+            .into_iter()
+            .filter(|member| member.champion_id != 0 && member.selected_skin_id != 0)
+            .filter(|member| {
+                let pick = (member.champion_id, member.selected_skin_id);
+                self.selected.insert(member.cell_id, pick) != Some(pick)
+            })
+            .map(|member| {
+                Event::new(
+                    EventKind::SkinSelected,
+                    ts,
+                    EventPayload::SkinSelected(SkinSelectedEvent {
+                        cell_id: member.cell_id,
+                        riot_id: member.riot_id(),
+                        champion_id: member.champion_id,
+                        skin_id: member.selected_skin_id,
+                    }),
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn only_changed_picks_are_reported() {
+        let mut tracker = SkinTracker::default();
+        let session = |skin: u32| {
+            json!({
+                "myTeam": [
+                    { "cellId": 0, "championId": 103, "selectedSkinId": skin, "gameName": "Faker", "tagLine": "KR1" },
+                    { "cellId": 1, "championId": 0, "selectedSkinId": 0 },
+                ],
+            })
+        };
+
+        let events = tracker.on_update(SESSION_URI, &session(103001), 1);
+        assert_eq!(
+            events[0].payload,
+            EventPayload::SkinSelected(SkinSelectedEvent {
+                cell_id: 0,
+                riot_id: Some("Faker#KR1".to_string()),
+                champion_id: 103,
+                skin_id: 103001,
+            })
+        );
+        assert_eq!(events.len(), 1);
+        assert!(tracker
+            .on_update(SESSION_URI, &session(103001), 2)
+            .is_empty());
+        assert_eq!(tracker.on_update(SESSION_URI, &session(103015), 3).len(), 1);
+
+        tracker.on_phase("ChampSelect");
+        assert_eq!(tracker.on_update(SESSION_URI, &session(103015), 4).len(), 1);
+    }
+}
@@ -0,0 +1,98 @@
+//! Session state persisted across daemon restarts.
+//!
+//! A restart mid-game would otherwise start from an empty player registry and event digest,
+//! and the first poll would re-emit every level, item and kill of the game so far. The
+//! daemon saves what it needs to carry on to a file on shutdown and picks it up on startup;
+//! the bus session and sequence number are kept too, so clients can resume their
+//! subscriptions with the tokens they already hold.
+
+use anyhow::{Context, Result};
+use levents_model::Uuid;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "live-client")]
+use crate::live_client::LiveSession;
+
+/// Older state most likely belongs to a game that is long over.
+const MAX_AGE: Duration = Duration::from_secs(60 * 60);
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SavedState {
+    pub(crate) saved_at_ms: u64,
+    /// Bus session the sequence numbers below belong to.
+    pub(crate) session: Uuid,
+    /// Sequence number of the last published event.
+    pub(crate) seq: u64,
+    #[cfg(feature = "live-client")]
+    #[serde(default)]
+    pub(crate) live: Option<LiveSession>,
+}
+
+impl SavedState {
+    /// Write the state to `path`, replacing any previous file.
+    pub(crate) fn save(&self, path: &Path) -> Result<()> {
+        // Write aside and rename so a crash mid-write never leaves a truncated file behind.
+        let partial = path.with_extension("partial");
+        fs::write(&partial, serde_json::to_vec(self)?)
+            .with_context(|| format!("failed to write {}", partial.display()))?;
+        fs::rename(&partial, path).with_context(|| format!("failed to replace {}", path.display()))
+    }
+
+    /// Read the state saved at `path`, or `None` if there is none or it is too old to trust.
+    ///
+    /// The file is removed once read, so a crash later in the run does not restore it again.
+    pub(crate) fn take(path: &Path) -> Result<Option<Self>> {
+        let bytes = match fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(error) => {
+                return Err(error).with_context(|| format!("failed to read {}", path.display()))
+            }
+        };
+        fs::remove_file(path).with_context(|| format!("failed to remove {}", path.display()))?;
+        let state: Self = serde_json::from_slice(&bytes)
+            .with_context(|| format!("failed to parse {}", path.display()))?;
+        let age = now_ms().saturating_sub(state.saved_at_ms);
+        Ok((age <= MAX_AGE.as_millis() as u64).then_some(state))
+    }
+}
+
+pub(crate) fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saved_state_is_taken_once_and_expires() {
+        let path = std::env::temp_dir().join(format!("levents-state-{}.json", std::process::id()));
+        let session = Uuid::new_v4();
+        let state = |saved_at_ms| SavedState {
+            saved_at_ms,
+            session,
+            seq: 42,
+            #[cfg(feature = "live-client")]
+            live: None,
+        };
+
+        state(now_ms()).save(&path).unwrap();
+        let restored = SavedState::take(&path).unwrap().expect("fresh state");
+        assert_eq!((restored.session, restored.seq), (session, 42));
+        assert!(SavedState::take(&path).unwrap().is_none());
+
+        state(now_ms() - 2 * MAX_AGE.as_millis() as u64)
+            .save(&path)
+            .unwrap();
+        assert!(SavedState::take(&path).unwrap().is_none());
+        assert!(!path.exists());
+    }
+}
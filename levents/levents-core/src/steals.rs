@@ -0,0 +1,159 @@
+//! Heuristic objective steal detection.
+//!
+//! Casters want to call steals the client's own `Stolen` flag does not report. Fights around
+//! an objective show up in the kill feed: when the team that took it had fewer players in the
+//! champion kills of the last seconds than the other team, it most likely snatched it from a
+//! team that was doing the work. Positions are not exposed, so a fight elsewhere on the map
+//! counts the same; the flag is a hint for casters, not a verdict.
+
+use std::collections::VecDeque;
+
+use levents_model::{Event, EventPayload, PlayerRef, Team};
+
+/// How far back champion kills count as a fight around the objective, in game time.
+const FIGHT_WINDOW_MS: u64 = 20_000;
+
+/// Players seen fighting in recent champion kills, for the current game.
+#[derive(Debug, Default)]
+pub(crate) struct StealTracker {
+    /// Game time, team and registry name of every participant, oldest first.
+    recent: VecDeque<(u64, Team, String)>,
+}
+
+impl StealTracker {
+    /// Record the champion kills in `events` and set `steal_suspected` on their objective
+    /// kills; `events` are in game time order.
+    pub(crate) fn annotate(&mut self, events: &mut [Event]) {
+        for event in events {
+            self.expire(event.ts);
+            match &mut event.payload {
+                EventPayload::KillDetail(kill) => {
+                    let fighters = kill
+                        .killer
+                        .iter()
+                        .chain(&kill.assisters)
+                        .chain([&kill.victim]);
+                    for player in fighters {
+                        self.record(event.ts, player);
+                    }
+                }
+                EventPayload::ObjectiveKill(objective) => {
+                    let Some(killer) = &objective.killer else {
+                        objective.steal_suspected = objective.stolen;
+                        continue;
+                    };
+                    let own = self.fighters(&killer.team, Some(&killer.summoner_name));
+                    let other = match killer.team {
+                        Team::Order => self.fighters(&Team::Chaos, None),
+                        Team::Chaos => self.fighters(&Team::Order, None),
+                        Team::Neutral => 0,
+                    };
+                    objective.steal_suspected = objective.stolen || other > own;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.recent.clear();
+    }
+
+    fn record(&mut self, ts: u64, player: &PlayerRef) {
+        self.recent
+            .push_back((ts, player.team.clone(), player.summoner_name.clone()));
+    }
+
+    fn expire(&mut self, now: u64) {
+        while self
+            .recent
+            .front()
+            .is_some_and(|(ts, _, _)| ts + FIGHT_WINDOW_MS < now)
+        {
+            self.recent.pop_front();
+        }
+    }
+
+    /// Distinct players of `team` in the recent kills, counting `also` even if absent.
+    fn fighters(&self, team: &Team, also: Option<&str>) -> usize {
+        let mut names: Vec<&str> = self
+            .recent
+            .iter()
+            .filter(|(_, fighter_team, _)| fighter_team == team)
+            .map(|(_, _, name)| name.as_str())
+            .chain(also)
+            .collect();
+        names.sort_unstable();
+        names.dedup();
+        names.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use levents_model::{EventKind, KillDetailEvent, Objective, ObjectiveKillEvent};
+
+    fn player(name: &str, team: Team) -> PlayerRef {
+        PlayerRef::new(name, team, 0)
+    }
+
+    fn kill(ts: u64, killer: PlayerRef, victim: PlayerRef, assisters: Vec<PlayerRef>) -> Event {
+        Event::new(
+            EventKind::ChampionKill,
+            ts,
+            EventPayload::KillDetail(KillDetailEvent {
+                killer: Some(killer),
+                victim,
+                assisters,
+                bounty: None,
+            }),
+        )
+    }
+
+    fn baron(ts: u64, killer: PlayerRef) -> Event {
+        Event::new(
+            EventKind::ObjectiveKill,
+            ts,
+            EventPayload::ObjectiveKill(ObjectiveKillEvent {
+                objective: Objective::Baron,
+                dragon_type: None,
+                stolen: false,
+                steal_suspected: false,
+                team: Some(killer.team.clone()),
+                killer: Some(killer),
+            }),
+        )
+    }
+
+    fn suspected(event: &Event) -> bool {
+        match &event.payload {
+            EventPayload::ObjectiveKill(objective) => objective.steal_suspected,
+            other => panic!("unexpected payload {other:?}"),
+        }
+    }
+
+    #[test]
+    fn objectives_taken_by_the_outnumbered_team_are_suspected_steals() {
+        let order = |name| player(name, Team::Order);
+        let chaos = |name| player(name, Team::Chaos);
+        let mut events = vec![
+            kill(
+                100_000,
+                order("Alpha"),
+                chaos("Kilo"),
+                vec![order("Bravo"), order("Charlie")],
+            ),
+            // Order fought three strong around the pit, but the chaos jungler took it.
+            baron(110_000, chaos("Lima")),
+            // Order took the next one long after the fight.
+            baron(200_000, order("Alpha")),
+        ];
+
+        let mut tracker = StealTracker::default();
+        tracker.annotate(&mut events);
+
+        assert!(suspected(&events[1]));
+        assert!(!suspected(&events[2]));
+    }
+}
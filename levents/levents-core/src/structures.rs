@@ -0,0 +1,124 @@
+//! Turret and inhibitor identity from the Live Client structure ids.
+//!
+//! Ids follow the Summoner's Rift layout: `Turret_T2_L_03_A` is the outer top turret of the
+//! chaos team and `Barracks_T1_R1` the bottom inhibitor of the order team. Turret numbers count
+//! from the nexus outwards, and mid lane shares its low numbers with the two nexus turrets.
+
+use levents_model::{Lane, StructureType, Team, TurretTier};
+
+/// A structure parsed from its id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Structure {
+    pub structure: StructureType,
+    /// Team the structure belongs to.
+    pub team: Team,
+    pub lane: Option<Lane>,
+    pub tier: Option<TurretTier>,
+}
+
+/// Parse a `TurretKilled` or `InhibKilled` structure id; `None` for ids outside the layout.
+pub(crate) fn parse(id: &str) -> Option<Structure> {
+    let mut parts = id.split('_');
+    let kind = parts.next()?;
+    let team = match parts.next()? {
+        "T1" => Team::Order,
+        "T2" => Team::Chaos,
+        _ => return None,
+    };
+    match kind {
+        "Turret" => {
+            let lane = parts.next()?;
+            let number: u32 = parts.next()?.parse().ok()?;
+            let (lane, tier) = match (lane, number) {
+                ("L" | "R", 3) | ("C", 5) => (lane, Some(TurretTier::Outer)),
+                ("L" | "R", 2) | ("C", 4) => (lane, Some(TurretTier::Inner)),
+                ("L" | "R", 1) | ("C", 3) => (lane, Some(TurretTier::Base)),
+                ("C", 1 | 2) => {
+                    return Some(Structure {
+                        structure: StructureType::Turret,
+                        team,
+                        lane: None,
+                        tier: Some(TurretTier::Nexus),
+                    })
+                }
+                _ => (lane, None),
+            };
+            Some(Structure {
+                structure: StructureType::Turret,
+                team,
+                lane: Some(lane_from_letter(lane)?),
+                tier,
+            })
+        }
+        "Barracks" => Some(Structure {
+            structure: StructureType::Inhibitor,
+            team,
+            lane: Some(lane_from_letter(parts.next()?.get(..1)?)?),
+            tier: None,
+        }),
+        _ => None,
+    }
+}
+
+fn lane_from_letter(letter: &str) -> Option<Lane> {
+    match letter {
+        "L" => Some(Lane::Top),
+        "C" => Some(Lane::Mid),
+        "R" => Some(Lane::Bottom),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn structure_ids_resolve_to_team_lane_and_tier() {
+        let turret = |team, lane, tier| Structure {
+            structure: StructureType::Turret,
+            team,
+            lane,
+            tier,
+        };
+        assert_eq!(
+            parse("Turret_T2_L_03_A"),
+            Some(turret(
+                Team::Chaos,
+                Some(Lane::Top),
+                Some(TurretTier::Outer)
+            ))
+        );
+        assert_eq!(
+            parse("Turret_T1_C_03_A"),
+            Some(turret(Team::Order, Some(Lane::Mid), Some(TurretTier::Base)))
+        );
+        assert_eq!(
+            parse("Turret_T1_R_02_A"),
+            Some(turret(
+                Team::Order,
+                Some(Lane::Bottom),
+                Some(TurretTier::Inner)
+            ))
+        );
+        assert_eq!(
+            parse("Turret_T2_C_01_A"),
+            Some(turret(Team::Chaos, None, Some(TurretTier::Nexus)))
+        );
+        assert_eq!(
+            parse("Turret_T1_C_07_A"),
+            Some(turret(Team::Order, Some(Lane::Mid), None))
+        );
+        assert_eq!(
+            parse("Barracks_T2_R1"),
+            Some(Structure {
+                structure: StructureType::Inhibitor,
+                team: Team::Chaos,
+                lane: Some(Lane::Bottom),
+                tier: None,
+            })
+        );
+        assert_eq!(parse("Turret_OrderTurretShrine_A"), None);
+        assert_eq!(parse("Minion_T100L0S0N0001"), None);
+    }
+}
@@ -0,0 +1,177 @@
+//! Surrender votes and remakes reported by the League Client.
+//!
+//! The Live Client feed has no trace of either, so a remade game only shows up there as a game
+//! that ended early. Vote updates arrive on the `/lol-surrender/` endpoints; the end-of-game
+//! stats block says whether the game ended in an early surrender.
+
+use levents_model::{Event, EventKind, EventPayload, SurrenderVoteEvent, Team};
+use serde::Deserialize;
+use tracing::trace;
+
+pub(crate) const SURRENDER_URI_PREFIX: &str = "/lol-surrender/";
+pub(crate) const EOG_STATS_URI: &str = "/lol-end-of-game/v1/eog-stats-block";
+
+/// One update of the vote in progress.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct VoteUpdate {
+    state: String,
+    #[serde(default)]
+    is_early_surrender: bool,
+    team_id: Option<u32>,
+    #[serde(default)]
+    votes_for: u32,
+    #[serde(default)]
+    votes_against: u32,
+}
+
+impl VoteUpdate {
+    fn payload(&self) -> SurrenderVoteEvent {
+        SurrenderVoteEvent {
+            team: match self.team_id {
+                Some(100) => Some(Team::Order),
+                Some(200) => Some(Team::Chaos),
+                _ => None,
+            },
+            remake: self.is_early_surrender,
+            votes_for: self.votes_for,
+            votes_against: self.votes_against,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EndOfGameStats {
+    #[serde(default)]
+    game_ended_in_early_surrender: bool,
+}
+
+/// Vote state for the current game.
+#[derive(Debug, Default)]
+pub(crate) struct SurrenderTracker {
+    /// A vote has started and not yet been decided.
+    voting: bool,
+    /// `Remake` was already emitted for this game.
+    remade: bool,
+}
+
+impl SurrenderTracker {
+    /// A new gameflow phase; a game starting forgets the previous one.
+    pub(crate) fn on_phase(&mut self, phase: &str) {
+        if phase == "InProgress" {
+            *self = Self::default();
+        }
+    }
+
+    /// Turn an LCU update on `uri` into vote and remake events.
+    pub(crate) fn on_update(&mut self, uri: &str, data: &serde_json::Value, ts: u64) -> Vec<Event> {
+        if uri.starts_with(SURRENDER_URI_PREFIX) {
+            match VoteUpdate::deserialize(data) {
+                Ok(update) => self.on_vote(update, ts),
+                Err(error) => {
+                    trace!(?error, uri, "unrecognised surrender update");
+                    Vec::new()
+                }
+            }
+        } else if uri == EOG_STATS_URI {
+            let remade = EndOfGameStats::deserialize(data)
+                .map(|stats| stats.game_ended_in_early_surrender)
+                .unwrap_or(false);
+            if remade && !self.remade {
+                self.remade = true;
+                let payload = SurrenderVoteEvent {
+                    team: None,
+                    remake: true,
+                    votes_for: 0,
+                    votes_against: 0,
+                };
+                vec![event(EventKind::Remake, ts, payload)]
+            } else {
+                Vec::new()
+            }
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn on_vote(&mut self, update: VoteUpdate, ts: u64) -> Vec<Event> {
+        let payload = update.payload();
+        let kind = match update.state.as_str() {
+            // Tallies tick while the vote runs; only its start is reported.
+            "InProgress" if !self.voting => EventKind::SurrenderVoteStarted,
+            "Passed" => EventKind::SurrenderVotePassed,
+            "Failed" => EventKind::SurrenderVoteFailed,
+            _ => return Vec::new(),
+        };
+        self.voting = kind == EventKind::SurrenderVoteStarted;
+
+        let mut events = Vec::new();
+        if kind == EventKind::SurrenderVotePassed && payload.remake && !self.remade {
+            self.remade = true;
+            events.push(event(kind, ts, payload.clone()));
+            events.push(event(EventKind::Remake, ts, payload));
+        } else {
+            events.push(event(kind, ts, payload));
+        }
+        events
+    }
+}
+
+fn event(kind: EventKind, ts: u64, payload: SurrenderVoteEvent) -> Event {
+    Event::new(kind, ts, EventPayload::SurrenderVote(payload))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn kinds(events: &[Event]) -> Vec<EventKind> {
+        events.iter().map(|event| event.kind.clone()).collect()
+    }
+
+    #[test]
+    fn votes_and_remakes_become_events() {
+        let mut tracker = SurrenderTracker::default();
+        let uri = "/lol-surrender/v1/vote";
+        let vote = |state: &str, votes_for| {
+            json!({
+                "state": state,
+                "isEarlySurrender": true,
+                "teamId": 200,
+                "votesFor": votes_for,
+                "votesAgainst": 0,
+            })
+        };
+
+        let started = tracker.on_update(uri, &vote("InProgress", 1), 1);
+        assert_eq!(kinds(&started), [EventKind::SurrenderVoteStarted]);
+        assert!(tracker.on_update(uri, &vote("InProgress", 2), 2).is_empty());
+
+        let passed = tracker.on_update(uri, &vote("Passed", 5), 3);
+        assert_eq!(
+            kinds(&passed),
+            [EventKind::SurrenderVotePassed, EventKind::Remake]
+        );
+        assert!(matches!(
+            &passed[1].payload,
+            EventPayload::SurrenderVote(SurrenderVoteEvent {
+                team: Some(Team::Chaos),
+                remake: true,
+                votes_for: 5,
+                ..
+            })
+        ));
+
+        // The end-of-game screen confirms the remake already reported.
+        let eog = json!({ "gameEndedInEarlySurrender": true });
+        assert!(tracker.on_update(EOG_STATS_URI, &eog, 4).is_empty());
+
+        tracker.on_phase("InProgress");
+        assert_eq!(
+            kinds(&tracker.on_update(EOG_STATS_URI, &eog, 5)),
+            [EventKind::Remake]
+        );
+    }
+}
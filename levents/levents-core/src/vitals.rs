@@ -0,0 +1,136 @@
+//! Daemon health reported in heartbeat events and watched by the source watchdog.
+
+use levents_model::HeartbeatEvent;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Marks a poll latency that has not been measured yet.
+const NO_LATENCY: u64 = u64::MAX;
+
+/// Event sources whose health is tracked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Source {
+    LiveClient,
+    Lcu,
+}
+
+impl Source {
+    /// Name reported in heartbeats and diagnostics.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Source::LiveClient => "liveClient",
+            Source::Lcu => "lcu",
+        }
+    }
+}
+
+/// Shared health counters, updated by the sources and read by the heartbeat task.
+#[derive(Debug, Clone)]
+pub(crate) struct Vitals {
+    inner: Arc<VitalsInner>,
+}
+
+#[derive(Debug)]
+struct VitalsInner {
+    started: Instant,
+    live_client: SourceState,
+    lcu: SourceState,
+    last_poll_latency_ms: AtomicU64,
+    over_budget_polls: AtomicU64,
+}
+
+#[derive(Debug, Default)]
+struct SourceState {
+    connected: AtomicBool,
+    /// Milliseconds after `started` at which the source last made progress.
+    last_beat_ms: AtomicU64,
+}
+
+impl Default for Vitals {
+    fn default() -> Self {
+        Self {
+            inner: Arc::new(VitalsInner {
+                started: Instant::now(),
+                live_client: SourceState::default(),
+                lcu: SourceState::default(),
+                last_poll_latency_ms: AtomicU64::new(NO_LATENCY),
+                over_budget_polls: AtomicU64::new(0),
+            }),
+        }
+    }
+}
+
+impl Vitals {
+    fn source(&self, source: Source) -> &SourceState {
+        match source {
+            Source::LiveClient => &self.inner.live_client,
+            Source::Lcu => &self.inner.lcu,
+        }
+    }
+
+    fn uptime_ms(&self) -> u64 {
+        self.inner.started.elapsed().as_millis() as u64
+    }
+
+    #[cfg_attr(not(any(feature = "live-client", feature = "lcu")), allow(dead_code))]
+    pub(crate) fn set_connected(&self, source: Source, connected: bool) {
+        self.source(source)
+            .connected
+            .store(connected, Ordering::Relaxed);
+    }
+
+    /// Note that `source` is still making progress, even if it had nothing to emit.
+    pub(crate) fn beat(&self, source: Source) {
+        self.source(source)
+            .last_beat_ms
+            .store(self.uptime_ms(), Ordering::Relaxed);
+    }
+
+    /// Time since `source` last called [`Vitals::beat`].
+    #[cfg_attr(not(any(feature = "live-client", feature = "lcu")), allow(dead_code))]
+    pub(crate) fn since_beat(&self, source: Source) -> Duration {
+        let last = self.source(source).last_beat_ms.load(Ordering::Relaxed);
+        Duration::from_millis(self.uptime_ms().saturating_sub(last))
+    }
+
+    #[cfg_attr(not(feature = "live-client"), allow(dead_code))]
+    pub(crate) fn record_poll(&self, latency: Duration) {
+        let ms = latency.as_millis().min(u128::from(NO_LATENCY - 1)) as u64;
+        self.inner.last_poll_latency_ms.store(ms, Ordering::Relaxed);
+        self.beat(Source::LiveClient);
+    }
+
+    /// Count a Live Client poll that took longer than its interval.
+    #[cfg_attr(not(feature = "live-client"), allow(dead_code))]
+    pub(crate) fn record_over_budget(&self) {
+        self.inner.over_budget_polls.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Names of the sources currently connected to the game client.
+    pub(crate) fn sources_connected(&self) -> Vec<String> {
+        [Source::LiveClient, Source::Lcu]
+            .into_iter()
+            .filter(|&source| self.source(source).connected.load(Ordering::Relaxed))
+            .map(|source| source.name().to_string())
+            .collect()
+    }
+
+    /// Heartbeat payload carrying the current vitals.
+    pub(crate) fn heartbeat(&self, dropped_events: Option<u64>) -> HeartbeatEvent {
+        let sources_connected = self.sources_connected();
+        let latency = self.inner.last_poll_latency_ms.load(Ordering::Relaxed);
+
+        HeartbeatEvent {
+            seq: 0,
+            uptime_ms: Some(self.uptime_ms()),
+            sources_connected,
+            sources_disabled: Vec::new(),
+            last_poll_latency_ms: (latency != NO_LATENCY).then_some(latency),
+            dropped_events,
+            over_budget_polls: Some(self.inner.over_budget_polls.load(Ordering::Relaxed)),
+            version: Some(crate::build_info::version()),
+        }
+    }
+}
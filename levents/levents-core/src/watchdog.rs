@@ -0,0 +1,101 @@
+//! Restarts sources that stop making progress.
+//!
+//! A source reports progress through [`Vitals::beat`] on every poll or socket wakeup, even when
+//! it has nothing to emit. A hung TLS handshake or HTTP request stops the beats, and the
+//! watchdog then drops the stuck stream, publishes a `Diagnostic` event and starts a fresh one.
+
+use crate::bus::EventBus;
+use crate::vitals::{Source, Vitals};
+use anyhow::Result;
+use futures_core::Stream;
+use levents_model::{DiagnosticEvent, Event, EventBatch, EventKind, EventPayload};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio::time::{interval, MissedTickBehavior};
+use tracing::warn;
+
+/// Forward the stream built by `start` into `bus`, rebuilding it whenever `source` has not
+/// reported progress for `stall_timeout`.
+pub(crate) fn supervise<F, S>(
+    bus: &EventBus,
+    vitals: Vitals,
+    source: Source,
+    stall_timeout: Duration,
+    mut start: F,
+) -> JoinHandle<()>
+where
+    F: FnMut() -> S + Send + 'static,
+    S: Stream<Item = Result<EventBatch>> + Send + 'static,
+{
+    let bus = bus.clone();
+    tokio::spawn(async move {
+        let mut check = interval(stall_timeout / 4);
+        check.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            vitals.beat(source);
+            let task = bus.spawn_source(start());
+            loop {
+                check.tick().await;
+                if vitals.since_beat(source) >= stall_timeout {
+                    break;
+                }
+            }
+            task.abort();
+            vitals.set_connected(source, false);
+
+            warn!(
+                source = source.name(),
+                ?stall_timeout,
+                "source stalled; restarting it"
+            );
+            bus.publish(Event::new(
+                EventKind::Diagnostic,
+                crate::timestamp_ms(),
+                EventPayload::Diagnostic(DiagnosticEvent {
+                    source: source.name().to_string(),
+                    message: format!(
+                        "no progress for {}s; source restarted",
+                        stall_timeout.as_secs_f32()
+                    ),
+                }),
+            ));
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bus::EventFilter;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn stalled_sources_are_restarted_with_a_diagnostic() {
+        let bus = EventBus::new();
+        let mut subscription = bus.subscribe(EventFilter::all());
+        let starts = Arc::new(AtomicUsize::new(0));
+        let counter = starts.clone();
+        // Never yields and never beats, like a hung handshake.
+        let watchdog = supervise(
+            &bus,
+            Vitals::default(),
+            Source::Lcu,
+            Duration::from_millis(40),
+            move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+                futures_util::stream::pending()
+            },
+        );
+
+        let event = subscription.recv().await.expect("diagnostic");
+        assert_eq!(event.kind, EventKind::Diagnostic);
+        assert!(matches!(
+            event.payload,
+            EventPayload::Diagnostic(ref diagnostic) if diagnostic.source == "lcu"
+        ));
+        tokio::task::yield_now().await;
+        assert_eq!(starts.load(Ordering::SeqCst), 2);
+        watchdog.abort();
+    }
+}
@@ -0,0 +1,99 @@
+//! Lightweight logistic win-probability estimate shared by broadcast overlays.
+
+use levents_model::{Event, EventKind, EventPayload, Team, WinProbabilityEvent};
+
+/// Hand-tuned logistic weights: per 1000 gold, per kill and per objective advantage.
+const GOLD_WEIGHT: f64 = 0.35;
+const KILL_WEIGHT: f64 = 0.05;
+const DRAGON_WEIGHT: f64 = 0.2;
+const BARON_WEIGHT: f64 = 0.6;
+const TOWER_WEIGHT: f64 = 0.25;
+
+/// Aggregated state of one team fed into the estimator.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct TeamStats {
+    pub gold: i64,
+    pub kills: u32,
+    pub dragons: u32,
+    pub barons: u32,
+    pub towers: u32,
+}
+
+/// Running per-team tallies for the current game.
+#[derive(Debug, Default)]
+pub(crate) struct TeamTally {
+    order: TeamStats,
+    chaos: TeamStats,
+}
+
+impl TeamTally {
+    pub(crate) fn stats_mut(&mut self, team: &Team) -> Option<&mut TeamStats> {
+        match team {
+            Team::Order => Some(&mut self.order),
+            Team::Chaos => Some(&mut self.chaos),
+            Team::Neutral => None,
+        }
+    }
+
+    pub(crate) fn event(&self, ts_ms: u64) -> Event {
+        Event::new(
+            EventKind::WinProbability,
+            ts_ms,
+            EventPayload::WinProbability(estimate(&self.order, &self.chaos)),
+        )
+    }
+}
+
+/// Estimate each team's chance to win from the current advantages.
+pub(crate) fn estimate(order: &TeamStats, chaos: &TeamStats) -> WinProbabilityEvent {
+    let diff = |a: i64, b: i64| (a - b) as f64;
+    let z = GOLD_WEIGHT * diff(order.gold, chaos.gold) / 1000.0
+        + KILL_WEIGHT * diff(order.kills.into(), chaos.kills.into())
+        + DRAGON_WEIGHT * diff(order.dragons.into(), chaos.dragons.into())
+        + BARON_WEIGHT * diff(order.barons.into(), chaos.barons.into())
+        + TOWER_WEIGHT * diff(order.towers.into(), chaos.towers.into());
+
+    let order_probability = 1.0 / (1.0 + (-z).exp());
+    WinProbabilityEvent {
+        order: order_probability as f32,
+        chaos: (1.0 - order_probability) as f32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn even_game_is_a_coin_flip() {
+        let stats = TeamStats {
+            gold: 10_000,
+            kills: 3,
+            ..TeamStats::default()
+        };
+        let estimate = estimate(&stats, &stats);
+        assert!((estimate.order - 0.5).abs() < f32::EPSILON);
+        assert!((estimate.chaos - 0.5).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn advantages_favour_the_leading_team() {
+        let order = TeamStats {
+            gold: 15_000,
+            kills: 8,
+            barons: 1,
+            towers: 4,
+            ..TeamStats::default()
+        };
+        let chaos = TeamStats {
+            gold: 11_000,
+            kills: 3,
+            dragons: 2,
+            ..TeamStats::default()
+        };
+
+        let estimate = estimate(&order, &chaos);
+        assert!(estimate.order > 0.8);
+        assert!((estimate.order + estimate.chaos - 1.0).abs() < 1e-6);
+    }
+}
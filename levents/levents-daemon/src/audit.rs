@@ -0,0 +1,97 @@
+//! Audit trail of control commands.
+//!
+//! Every Control invocation is logged under the `levents::audit` tracing target and, when a
+//! file is configured, appended to it as a JSON line, so a tournament can tell afterwards who
+//! froze the overlay or overrode a result.
+
+use std::{
+    fs::{File, OpenOptions},
+    io::Write,
+    path::Path,
+    sync::{Arc, Mutex},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use tracing::{info, warn};
+
+/// Destination for control audit records; cloning yields a handle to the same log.
+#[derive(Debug, Clone, Default)]
+pub struct AuditLog {
+    file: Option<Arc<Mutex<File>>>,
+}
+
+#[derive(Serialize)]
+struct AuditRecord<'a> {
+    ts_ms: u64,
+    consumer: &'a str,
+    command: &'a str,
+    detail: &'a str,
+    outcome: &'a str,
+}
+
+impl AuditLog {
+    /// Append records to `path` in addition to the tracing output.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("failed to open audit log {}", path.display()))?;
+        Ok(Self {
+            file: Some(Arc::new(Mutex::new(file))),
+        })
+    }
+
+    /// Record that `consumer` issued `command` (with `detail`) and how it ended.
+    pub fn record(&self, consumer: &str, command: &str, detail: &str, outcome: &str) {
+        info!(target: "levents::audit", consumer, command, detail, outcome, "control command");
+
+        let Some(file) = &self.file else {
+            return;
+        };
+        let ts_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_millis() as u64)
+            .unwrap_or_default();
+        let record = AuditRecord {
+            ts_ms,
+            consumer,
+            command,
+            detail,
+            outcome,
+        };
+        let mut file = file.lock().unwrap_or_else(|poison| poison.into_inner());
+        let result = serde_json::to_writer(&mut *file, &record)
+            .map_err(anyhow::Error::from)
+            .and_then(|()| Ok(file.write_all(b"\n")?));
+        if let Err(error) = result {
+            warn!(?error, "failed to write audit record");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_are_appended_as_json_lines() {
+        let path = std::env::temp_dir().join(format!("levents-audit-{}.jsonl", std::process::id()));
+        let log = AuditLog::open(&path).expect("audit log");
+        log.record("referee desk", "OverrideResult", "winner: 2", "accepted");
+        log.record("stats vendor", "EmitSyntheticKill", "", "permission denied");
+
+        let contents = std::fs::read_to_string(&path).expect("read");
+        let _ = std::fs::remove_file(&path);
+        let records: Vec<serde_json::Value> = contents
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("json"))
+            .collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0]["consumer"], "referee desk");
+        assert_eq!(records[1]["outcome"], "permission denied");
+    }
+}
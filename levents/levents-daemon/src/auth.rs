@@ -0,0 +1,261 @@
+//! Per-consumer API keys for the gRPC frontend.
+//!
+//! Production setups share one daemon between the overlay, the stats vendor and the observer
+//! tools, so each consumer gets its own key with the event kinds it may see, a request rate
+//! limit, and the roles that decide which control commands it may issue. Without a key file
+//! the daemon stays open.
+
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+    sync::{Arc, Mutex},
+    time::Instant,
+};
+
+use anyhow::{Context, Result};
+use levents_model::EventKind;
+use serde::Deserialize;
+use tonic::{service::Interceptor, Request, Status};
+
+/// Metadata keys checked for an API key, in order.
+const KEY_HEADERS: [&str; 2] = ["x-api-key", "authorization"];
+
+/// A consumer authenticated by its API key, attached to every request it makes.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Consumer {
+    /// Name used in logs.
+    pub name: String,
+    /// Event kinds the consumer receives; empty allows every kind.
+    #[serde(default)]
+    pub kinds: Vec<EventKind>,
+    /// Shorthand for every control permission.
+    #[serde(default)]
+    pub control: bool,
+    /// Roles granting control permissions, defined in the key file.
+    #[serde(default)]
+    pub roles: Vec<String>,
+    /// Permissions resolved from `control` and `roles` when the key file is loaded.
+    #[serde(skip)]
+    pub permissions: HashSet<Permission>,
+    /// Sustained requests per second; `None` is unlimited.
+    #[serde(default)]
+    pub requests_per_second: Option<f64>,
+    /// Requests allowed in a burst above the sustained rate.
+    #[serde(default = "default_burst")]
+    pub burst: u32,
+}
+
+fn default_burst() -> u32 {
+    10
+}
+
+/// Groups of control commands a role can be granted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Permission {
+    /// Inject synthetic events (`EmitSyntheticKill`).
+    SyntheticEvents,
+    /// Set the best-of series context (`SetSeries`).
+    Series,
+    /// Correct scores and results (`AdjustScore`, `OverrideResult`).
+    Corrections,
+    /// Control playback of delayed streams (`SeekToLive`).
+    Playback,
+    /// Start spectating on the daemon's League Client (`SpectatePlayer`).
+    Spectate,
+    /// Toggle automatic acceptance of ready checks (`SetAutoAccept`).
+    Matchmaking,
+    /// Follow raw League Client frames (`SubscribeLcuRaw`).
+    Debug,
+}
+
+impl Permission {
+    const ALL: [Permission; 7] = [
+        Permission::SyntheticEvents,
+        Permission::Series,
+        Permission::Corrections,
+        Permission::Playback,
+        Permission::Spectate,
+        Permission::Matchmaking,
+        Permission::Debug,
+    ];
+}
+
+impl Consumer {
+    /// Whether the consumer may issue commands guarded by `permission`.
+    pub fn may(&self, permission: Permission) -> bool {
+        self.permissions.contains(&permission)
+    }
+
+    /// Kinds this consumer may receive, or `None` for every kind.
+    pub fn allowed_kinds(&self) -> Option<HashSet<EventKind>> {
+        (!self.kinds.is_empty()).then(|| self.kinds.iter().cloned().collect())
+    }
+}
+
+#[derive(Deserialize)]
+struct KeyFile {
+    #[serde(default)]
+    roles: HashMap<String, Vec<Permission>>,
+    keys: HashMap<String, Consumer>,
+}
+
+/// API keys loaded from a JSON file:
+///
+/// ```json
+/// {
+///   "roles": { "referee": ["corrections", "series"], "producer": ["playback"] },
+///   "keys": {
+///     "admin-secret": { "name": "tech lead", "control": true },
+///     "referee-secret": { "name": "referee desk", "roles": ["referee"] },
+///     "vendor-secret": { "name": "stats vendor", "kinds": ["kill", "gameEnded"], "requests_per_second": 1 }
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ApiKeys {
+    keys: Arc<HashMap<String, Consumer>>,
+    buckets: Arc<Mutex<HashMap<String, Bucket>>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    refilled: Instant,
+}
+
+impl ApiKeys {
+    /// Parse keys from their JSON representation.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let mut file: KeyFile = serde_json::from_str(json).context("invalid API key JSON")?;
+        for consumer in file.keys.values_mut() {
+            if consumer.control {
+                consumer.permissions.extend(Permission::ALL);
+            }
+            for role in &consumer.roles {
+                let permissions = file.roles.get(role).with_context(|| {
+                    format!("API key `{}` uses undefined role `{role}`", consumer.name)
+                })?;
+                consumer.permissions.extend(permissions.iter().copied());
+            }
+        }
+        Ok(Self {
+            keys: Arc::new(file.keys),
+            buckets: Arc::default(),
+        })
+    }
+
+    /// Read and parse a key file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read API keys {}", path.display()))?;
+        Self::from_json(&json)
+    }
+}
+
+impl Interceptor for ApiKeys {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        if self.keys.is_empty() {
+            return Ok(request);
+        }
+
+        let key = KEY_HEADERS
+            .iter()
+            .find_map(|header| request.metadata().get(*header))
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.trim_start_matches("Bearer ").trim())
+            .ok_or_else(|| Status::unauthenticated("missing API key"))?;
+        let consumer = self
+            .keys
+            .get(key)
+            .ok_or_else(|| Status::unauthenticated("unknown API key"))?;
+
+        if let Some(rate) = consumer.requests_per_second {
+            let burst = f64::from(consumer.burst.max(1));
+            let now = Instant::now();
+            let mut buckets = self
+                .buckets
+                .lock()
+                .unwrap_or_else(|poison| poison.into_inner());
+            let bucket = buckets.entry(key.to_string()).or_insert(Bucket {
+                tokens: burst,
+                refilled: now,
+            });
+            let elapsed = now.duration_since(bucket.refilled).as_secs_f64();
+            bucket.tokens = (bucket.tokens + elapsed * rate).min(burst);
+            bucket.refilled = now;
+            if bucket.tokens < 1.0 {
+                return Err(Status::resource_exhausted(format!(
+                    "rate limit exceeded for {}",
+                    consumer.name
+                )));
+            }
+            bucket.tokens -= 1.0;
+        }
+
+        request.extensions_mut().insert(consumer.clone());
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(key: &str) -> Request<()> {
+        let mut request = Request::new(());
+        request
+            .metadata_mut()
+            .insert("authorization", format!("Bearer {key}").parse().unwrap());
+        request
+    }
+
+    #[test]
+    fn keys_authenticate_and_rate_limit_consumers() {
+        let mut keys = ApiKeys::from_json(
+            r#"{"keys": {
+                "vendor": {"name": "stats vendor", "kinds": ["kill"], "requests_per_second": 0.001, "burst": 1},
+                "overlay": {"name": "overlay", "control": true},
+                "referee": {"name": "referee desk", "roles": ["referee"]}
+            },
+            "roles": {"referee": ["corrections", "series"]}}"#,
+        )
+        .expect("keys");
+
+        let vendor = keys.call(request("vendor")).expect("authenticated");
+        let consumer = vendor.extensions().get::<Consumer>().expect("consumer");
+        assert!(!consumer.may(Permission::SyntheticEvents));
+        assert_eq!(
+            consumer.allowed_kinds(),
+            Some(HashSet::from([EventKind::Kill]))
+        );
+        assert_eq!(
+            keys.call(request("vendor")).unwrap_err().code(),
+            tonic::Code::ResourceExhausted
+        );
+
+        let overlay = keys.call(request("overlay")).expect("authenticated");
+        let overlay = overlay.extensions().get::<Consumer>().expect("consumer");
+        assert!(Permission::ALL
+            .iter()
+            .all(|permission| overlay.may(*permission)));
+        let referee = keys.call(request("referee")).expect("authenticated");
+        let referee = referee.extensions().get::<Consumer>().expect("consumer");
+        assert!(referee.may(Permission::Corrections));
+        assert!(!referee.may(Permission::Playback));
+        assert!(
+            ApiKeys::from_json(r#"{"keys": {"k": {"name": "k", "roles": ["ghost"]}}}"#).is_err()
+        );
+
+        assert_eq!(
+            keys.call(request("stranger")).unwrap_err().code(),
+            tonic::Code::Unauthenticated
+        );
+        assert_eq!(
+            keys.call(Request::new(())).unwrap_err().code(),
+            tonic::Code::Unauthenticated
+        );
+        assert!(ApiKeys::default().call(Request::new(())).is_ok());
+    }
+}
@@ -0,0 +1,129 @@
+//! Opt-in crash reports.
+//!
+//! "The daemon died mid-game" is hard to act on without the panic message, the backtrace and
+//! what the game was doing at the time. With a crash directory configured, every panic writes
+//! one JSON report there with all three. Reports stay on the machine; nothing is uploaded.
+
+use std::{
+    any::Any,
+    backtrace::Backtrace,
+    fs, panic,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result};
+use levents_core::{build_info, EventBus};
+use levents_model::Event;
+use serde::Serialize;
+
+/// Events from the backfill included in a report.
+const RECENT_EVENTS: usize = 50;
+
+#[derive(Serialize)]
+struct CrashReport<'a> {
+    ts_ms: u64,
+    version: &'a str,
+    git_hash: &'a str,
+    build_date: &'a str,
+    thread: Option<&'a str>,
+    message: String,
+    location: Option<String>,
+    backtrace: String,
+    /// Latest published events, oldest first; `None` when the backlog was locked.
+    recent_events: Option<Vec<Event>>,
+}
+
+/// Write a report into `dir` for every panic, then run the previously installed hook.
+pub fn install(dir: impl Into<PathBuf>, bus: EventBus) -> Result<()> {
+    let dir = dir.into();
+    fs::create_dir_all(&dir)
+        .with_context(|| format!("failed to create crash directory {}", dir.display()))?;
+
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let location = info.location().map(ToString::to_string);
+        match write_report(&dir, info.payload(), location, &bus) {
+            Ok(path) => eprintln!("levents: crash report written to {}", path.display()),
+            Err(error) => eprintln!("levents: failed to write crash report: {error:#}"),
+        }
+        previous(info);
+    }));
+    Ok(())
+}
+
+fn write_report(
+    dir: &Path,
+    payload: &(dyn Any + Send),
+    location: Option<String>,
+    bus: &EventBus,
+) -> Result<PathBuf> {
+    let ts_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or_default();
+    let message = if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "non-string panic payload".to_string()
+    };
+    let thread = std::thread::current();
+    let report = CrashReport {
+        ts_ms,
+        version: build_info::VERSION,
+        git_hash: build_info::GIT_HASH,
+        build_date: build_info::BUILD_DATE,
+        thread: thread.name(),
+        message,
+        location,
+        backtrace: Backtrace::force_capture().to_string(),
+        recent_events: bus.try_recent(RECENT_EVENTS),
+    };
+
+    let path = dir.join(format!("levents-crash-{ts_ms}.json"));
+    fs::write(&path, serde_json::to_vec_pretty(&report)?)
+        .with_context(|| format!("failed to write {}", path.display()))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use levents_model::{EventKind, EventPayload, PhaseEvent};
+
+    #[test]
+    fn reports_carry_the_panic_and_recent_events() {
+        let dir = std::env::temp_dir().join(format!("levents-crash-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let bus = EventBus::new();
+        bus.publish(Event::new(
+            EventKind::PhaseChange,
+            1,
+            EventPayload::Phase(PhaseEvent {
+                phase: "InProgress".to_string(),
+            }),
+        ));
+
+        let path = std::thread::Builder::new()
+            .name("poller".to_string())
+            .spawn({
+                let dir = dir.clone();
+                move || {
+                    let payload: Box<dyn Any + Send> = Box::new("unexpected playerlist shape");
+                    write_report(&dir, &*payload, Some("src/poller.rs:7:9".into()), &bus)
+                }
+            })
+            .unwrap()
+            .join()
+            .unwrap()
+            .unwrap();
+        let report: serde_json::Value = serde_json::from_slice(&fs::read(path).unwrap()).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(report["message"], "unexpected playerlist shape");
+        assert_eq!(report["thread"], "poller");
+        assert_eq!(report["location"], "src/poller.rs:7:9");
+        assert_eq!(report["recent_events"][0]["data"]["phase"], "InProgress");
+    }
+}
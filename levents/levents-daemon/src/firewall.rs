@@ -0,0 +1,187 @@
+//! Accept-time connection filtering for the network frontends.
+//!
+//! Binding `0.0.0.0` for a second-PC setup exposes the daemon to the whole LAN, so the
+//! firewall restricts which client addresses may connect and how many connections may be open
+//! at once. Rejected connections are closed before any request is read. Loopback clients are
+//! always allowed so the local overlay keeps working whatever the allowlist says.
+
+use std::{
+    io,
+    net::{IpAddr, SocketAddr},
+    pin::Pin,
+    sync::Arc,
+    task::{Context as TaskContext, Poll},
+};
+
+use anyhow::{Context, Result};
+use ipnet::IpNet;
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tracing::warn;
+
+/// Which clients may connect, and how many at once.
+#[derive(Debug, Clone, Default)]
+pub struct Firewall {
+    /// Networks allowed to connect; empty allows every address.
+    allowed: Vec<IpNet>,
+    /// Open connection slots; `None` is unlimited.
+    slots: Option<Arc<Semaphore>>,
+}
+
+impl Firewall {
+    /// Allow clients in `allowed` (every client when empty), at most `max_connections` at once.
+    pub fn new(allowed: Vec<IpNet>, max_connections: Option<usize>) -> Self {
+        Self {
+            allowed,
+            slots: max_connections.map(|max| Arc::new(Semaphore::new(max))),
+        }
+    }
+
+    /// Parse a comma-separated allowlist of addresses and CIDR networks, e.g.
+    /// `192.168.1.20, 10.0.0.0/24, fd00::/8`.
+    pub fn parse_allowlist(value: &str) -> Result<Vec<IpNet>> {
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                entry
+                    .parse::<IpNet>()
+                    .or_else(|_| entry.parse::<IpAddr>().map(IpNet::from))
+                    .with_context(|| format!("invalid address or network {entry:?}"))
+            })
+            .collect()
+    }
+
+    /// Whether every client address is allowed.
+    pub fn is_open(&self) -> bool {
+        self.allowed.is_empty()
+    }
+
+    /// Whether `peer` may connect at all.
+    pub fn allows(&self, peer: IpAddr) -> bool {
+        let peer = match peer {
+            IpAddr::V6(v6) => v6.to_ipv4_mapped().map_or(peer, IpAddr::V4),
+            IpAddr::V4(_) => peer,
+        };
+        self.is_open()
+            || peer.is_loopback()
+            || self.allowed.iter().any(|network| network.contains(&peer))
+    }
+
+    /// Admit a connection from `peer`, or `None` (after logging why) to close it.
+    pub fn admit(&self, peer: SocketAddr) -> Option<Admission> {
+        if !self.allows(peer.ip()) {
+            warn!(%peer, "rejected connection from a client outside the allowlist");
+            return None;
+        }
+        let permit = match &self.slots {
+            Some(slots) => match slots.clone().try_acquire_owned() {
+                Ok(permit) => Some(permit),
+                Err(_) => {
+                    warn!(%peer, "rejected connection; connection limit reached");
+                    return None;
+                }
+            },
+            None => None,
+        };
+        Some(Admission { _permit: permit })
+    }
+}
+
+/// Holds a connection slot until dropped.
+#[derive(Debug)]
+pub struct Admission {
+    _permit: Option<OwnedSemaphorePermit>,
+}
+
+/// A connection that releases its [`Admission`] when closed.
+#[derive(Debug)]
+pub struct Guarded<S> {
+    inner: S,
+    _admission: Admission,
+}
+
+impl<S> Guarded<S> {
+    /// Tie `admission` to the lifetime of `inner`.
+    pub fn new(inner: S, admission: Admission) -> Self {
+        Self {
+            inner,
+            _admission: admission,
+        }
+    }
+
+    /// The wrapped connection.
+    pub fn get_ref(&self) -> &S {
+        &self.inner
+    }
+}
+
+impl<S: AsyncRead + Unpin> AsyncRead for Guarded<S> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut TaskContext<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_read(cx, buf)
+    }
+}
+
+impl<S: AsyncWrite + Unpin> AsyncWrite for Guarded<S> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut TaskContext<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+
+    fn poll_write_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut TaskContext<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write_vectored(cx, bufs)
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.inner.is_write_vectored()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn peer(addr: &str) -> SocketAddr {
+        SocketAddr::new(addr.parse().unwrap(), 40000)
+    }
+
+    #[test]
+    fn allowlist_and_connection_limit_apply_at_accept_time() {
+        let allowed = Firewall::parse_allowlist("192.168.1.20, 10.0.0.0/24").unwrap();
+        let firewall = Firewall::new(allowed, Some(1));
+
+        assert!(firewall.allows("10.0.0.7".parse().unwrap()));
+        assert!(firewall.allows("::ffff:192.168.1.20".parse().unwrap()));
+        assert!(firewall.allows("::1".parse().unwrap()));
+        assert!(firewall.admit(peer("192.168.1.21")).is_none());
+
+        let first = firewall
+            .admit(peer("192.168.1.20"))
+            .expect("first connection");
+        assert!(firewall.admit(peer("127.0.0.1")).is_none());
+        drop(first);
+        assert!(firewall.admit(peer("127.0.0.1")).is_some());
+
+        assert!(Firewall::parse_allowlist("10.0.0.0/33").is_err());
+    }
+}
@@ -0,0 +1,23 @@
+//! Frontends for the levents daemon: the gRPC server, the JSON Lines pipe and, on Windows, a
+//! named-pipe publisher, plus the firewall that guards the network ones.
+//!
+//! The binary wires these pieces up from environment variables; they are exposed as a library
+//! so integration tests (and embedders) can run the same server in-process.
+
+pub mod audit;
+#[cfg(feature = "grpc")]
+pub mod auth;
+pub mod crash;
+pub mod firewall;
+#[cfg(feature = "grpc")]
+pub mod grpc;
+#[cfg(feature = "grpc")]
+pub mod mdns;
+#[cfg(windows)]
+pub mod named_pipe;
+pub mod pipe;
+#[cfg(feature = "grpc")]
+pub mod signing;
+#[cfg(feature = "grpc")]
+pub mod subscribers;
+pub mod update;
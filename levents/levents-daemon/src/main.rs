@@ -1,29 +1,292 @@
-use std::net::SocketAddr;
+use std::{net::SocketAddr, str::FromStr, time::Duration};
 
-use anyhow::{Context, Result};
-use levents_core::{DaemonConfig, LiveDaemon};
-
-mod grpc;
+use anyhow::{ensure, Context, Result};
+use levents_core::{build_info, DaemonConfig, Enrichments, LiveDaemon, Roster};
+use levents_daemon::{audit, auth, crash, firewall::Firewall, grpc, mdns, pipe, signing, update};
+use levents_model::SCHEMA_VERSION;
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    init_tracing();
+    // `levents-daemon pipe` streams JSON Lines over stdin/stdout instead of serving gRPC.
+    let pipe_mode = std::env::args().nth(1).as_deref() == Some("pipe");
+    init_tracing(pipe_mode);
+    tracing::info!(
+        version = build_info::VERSION,
+        git_hash = build_info::GIT_HASH,
+        build_date = build_info::BUILD_DATE,
+        "levents-daemon starting"
+    );
+
+    let mut config = DaemonConfig::default();
+    if let Ok(value) = std::env::var("LEVENTS_ENRICHMENTS") {
+        config.enrichments =
+            Enrichments::parse(&value).context("failed to parse LEVENTS_ENRICHMENTS")?;
+    }
+    if let Ok(path) = std::env::var("LEVENTS_ROSTER") {
+        config.roster = Roster::load(&path).context("failed to load LEVENTS_ROSTER")?;
+    }
+    if let Ok(dir) = std::env::var("LEVENTS_SPILL_DIR") {
+        config.backfill_spill_dir = Some(dir.into());
+    }
+    if let Some(capacity) = env_var("LEVENTS_BACKFILL_CAPACITY")? {
+        config.backfill_capacity = capacity;
+    }
+    if let Some(budget) = env_var("LEVENTS_BACKFILL_MEMORY")? {
+        config.backfill_memory_budget = budget;
+    }
+    if let Some(ms) = env_var::<u64>("LEVENTS_STALE_AFTER_MS")? {
+        config.stale_after = (ms > 0).then(|| Duration::from_millis(ms));
+    }
+    if let Some(max) = env_var("LEVENTS_MAX_PLAYERS")? {
+        config.memory_budget.max_players = max;
+    }
+    if let Some(max) = env_var("LEVENTS_MAX_UNKNOWN_FIELDS")? {
+        config.memory_budget.max_unknown_fields = max;
+    }
+    if let Some(secs) = env_var::<u64>("LEVENTS_MEMORY_CHECK_SECS")? {
+        config.memory_check_interval = (secs > 0).then(|| Duration::from_secs(secs));
+    }
+    if std::env::var("LEVENTS_LIVE_CLIENT").is_ok_and(|value| value == "0") {
+        config.live_client_enabled = false;
+    }
+    if std::env::var("LEVENTS_LCU").is_ok_and(|value| value == "0") {
+        config.lcu_enabled = false;
+    }
+    if std::env::var("LEVENTS_LOBBY_CHAT").is_ok_and(|value| value == "1") {
+        config.lcu_lobby_chat = true;
+    }
+    if std::env::var("LEVENTS_FRIEND_PRESENCE").is_ok_and(|value| value == "1") {
+        config.lcu_friend_presence = true;
+    }
+    if std::env::var("LEVENTS_AUTO_ACCEPT").is_ok_and(|value| value == "1") {
+        config.lcu_auto_accept = true;
+    }
+    if let Some(min) = env_var::<u32>("LEVENTS_CS_DELTA_MIN")? {
+        config.score_deltas.creep_score = (min > 0).then_some(min);
+    }
+    if let Some(min) = env_var::<f32>("LEVENTS_VISION_DELTA_MIN")? {
+        config.score_deltas.vision_score = (min > 0.0).then_some(min);
+    }
+    if std::env::var("LEVENTS_FLATTENED_KILLS").is_ok_and(|value| value == "0") {
+        config.flattened_kills = false;
+    }
+    if std::env::var("LEVENTS_PER_UNIT_ITEMS").is_ok_and(|value| value == "1") {
+        config.per_unit_items = true;
+    }
+    if std::env::var("LEVENTS_HIGH_FREQUENCY_EVENTS").is_ok_and(|value| value == "1") {
+        config.high_frequency_events = true;
+    }
+    if std::env::var("LEVENTS_LCU_RAW").is_ok_and(|value| value == "1") {
+        config.lcu_raw_frames = true;
+    }
+    if let Ok(path) = std::env::var("LEVENTS_STATE_FILE") {
+        config.state_file = Some(path.into());
+    }
+    if let Some(ms) = env_var::<u64>("LEVENTS_GRPC_KEEPALIVE_MS")? {
+        config.http2_keepalive_interval = (ms > 0).then(|| Duration::from_millis(ms));
+    }
+    if let Some(streams) = env_var("LEVENTS_GRPC_MAX_STREAMS")? {
+        config.max_concurrent_streams = Some(streams);
+    }
+    let daemon = LiveDaemon::new(config);
+    daemon.preflight().await.context("preflight failed")?;
+
+    if let Ok(dir) = std::env::var("LEVENTS_CRASH_DIR") {
+        crash::install(dir, daemon.events_hub()).context("failed to set up LEVENTS_CRASH_DIR")?;
+    }
+
+    let audit = match std::env::var("LEVENTS_AUDIT_LOG") {
+        Ok(path) => audit::AuditLog::open(&path).context("failed to open LEVENTS_AUDIT_LOG")?,
+        Err(_) => audit::AuditLog::default(),
+    };
+
+    if std::env::var("LEVENTS_UPDATE_CHECK").is_ok_and(|value| value == "1") {
+        tokio::spawn(update::run(daemon.events_hub()));
+    }
+
+    let json_schema = env_var("LEVENTS_JSON_SCHEMA")?.unwrap_or(SCHEMA_VERSION);
+    ensure!(
+        (1..=SCHEMA_VERSION).contains(&json_schema),
+        "LEVENTS_JSON_SCHEMA must be between 1 and {SCHEMA_VERSION}"
+    );
+
+    let json_format = |timestamps_var: &str| -> Result<pipe::JsonFormat> {
+        let timestamps = match std::env::var(timestamps_var) {
+            Ok(value) => value
+                .parse()
+                .with_context(|| format!("failed to parse {timestamps_var}"))?,
+            Err(_) => pipe::Timestamps::default(),
+        };
+        Ok(pipe::JsonFormat {
+            schema: json_schema,
+            timestamps,
+        })
+    };
+
+    if let Ok(name) = std::env::var("LEVENTS_NAMED_PIPE") {
+        spawn_named_pipe(&daemon, name, json_format("LEVENTS_NAMED_PIPE_TIMESTAMPS")?);
+    }
+
+    if pipe_mode {
+        let format = json_format("LEVENTS_PIPE_TIMESTAMPS")?;
+        return until_shutdown(&daemon, pipe::run(daemon.clone(), audit, format)).await;
+    }
+
+    let addr = match std::env::var("LEVENTS_GRPC_ADDR") {
+        Ok(value) => {
+            grpc::parse_listen_addr(&value).context("failed to parse LEVENTS_GRPC_ADDR")?
+        }
+        Err(_) => SocketAddr::from(([127, 0, 0, 1], grpc::DEFAULT_PORT)),
+    };
+    // Bound before anything is advertised, so a taken port fails the start.
+    let listener = grpc::bind(addr).with_context(|| format!("failed to bind {addr}"))?;
+    if let Ok(instance) = std::env::var("LEVENTS_MDNS") {
+        spawn_mdns(&instance, addr);
+    }
+
+    let stream_delay =
+        Duration::from_millis(env_var("LEVENTS_STREAM_DELAY_MS")?.unwrap_or_default());
+    ensure!(
+        stream_delay <= grpc::MAX_STREAM_DELAY,
+        "LEVENTS_STREAM_DELAY_MS must be at most {}",
+        grpc::MAX_STREAM_DELAY.as_millis()
+    );
+
+    let compression = match std::env::var("LEVENTS_GRPC_COMPRESSION") {
+        Ok(value) => {
+            grpc::parse_compression(&value).context("failed to parse LEVENTS_GRPC_COMPRESSION")?
+        }
+        Err(_) => None,
+    };
+
+    let api_keys = match std::env::var("LEVENTS_API_KEYS") {
+        Ok(path) => auth::ApiKeys::load(&path).context("failed to load LEVENTS_API_KEYS")?,
+        Err(_) => auth::ApiKeys::default(),
+    };
 
-    let daemon = LiveDaemon::new(DaemonConfig::default());
+    let signer = match std::env::var("LEVENTS_SIGNING_KEY") {
+        Ok(path) => {
+            Some(signing::EventSigner::load(&path).context("failed to load LEVENTS_SIGNING_KEY")?)
+        }
+        Err(_) => None,
+    };
 
-    let addr: SocketAddr = std::env::var("LEVENTS_GRPC_ADDR")
-        .unwrap_or_else(|_| "127.0.0.1:50051".to_string())
-        .parse()
-        .context("failed to parse LEVENTS_GRPC_ADDR")?;
+    let allowed = match std::env::var("LEVENTS_ALLOWED_CLIENTS") {
+        Ok(value) => {
+            Firewall::parse_allowlist(&value).context("failed to parse LEVENTS_ALLOWED_CLIENTS")?
+        }
+        Err(_) => Vec::new(),
+    };
+    let firewall = Firewall::new(allowed, env_var("LEVENTS_MAX_CONNECTIONS")?);
+
+    let options = grpc::ServeOptions {
+        stream_delay,
+        compression,
+        api_keys,
+        audit,
+        signer,
+        firewall,
+    };
+    until_shutdown(
+        &daemon,
+        grpc::serve_with_listener(daemon.clone(), listener, options),
+    )
+    .await
+}
+
+/// Run `frontend` until it ends or the process is asked to stop, then save the daemon state.
+async fn until_shutdown(
+    daemon: &LiveDaemon,
+    frontend: impl std::future::Future<Output = Result<()>>,
+) -> Result<()> {
+    let result = tokio::select! {
+        result = frontend => result,
+        () = shutdown_signal() => {
+            tracing::info!("shutting down");
+            Ok(())
+        }
+    };
+    if let Err(error) = daemon.save_state() {
+        tracing::error!(?error, "failed to save daemon state");
+    }
+    result
+}
+
+/// Resolves on Ctrl-C, or on SIGTERM from a service manager.
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+        match signal(SignalKind::terminate()) {
+            Ok(mut terminate) => {
+                tokio::select! {
+                    _ = tokio::signal::ctrl_c() => {}
+                    _ = terminate.recv() => {}
+                }
+                return;
+            }
+            Err(error) => tracing::warn!(?error, "cannot listen for SIGTERM"),
+        }
+    }
+    if let Err(error) = tokio::signal::ctrl_c().await {
+        tracing::warn!(?error, "cannot listen for Ctrl-C");
+        std::future::pending::<()>().await;
+    }
+}
+
+#[cfg(windows)]
+fn spawn_named_pipe(daemon: &LiveDaemon, name: String, format: pipe::JsonFormat) {
+    let daemon = daemon.clone();
+    tokio::spawn(async move {
+        if let Err(error) = levents_daemon::named_pipe::publish(daemon, name, format).await {
+            tracing::error!(?error, "named pipe publisher stopped");
+        }
+    });
+}
+
+#[cfg(not(windows))]
+fn spawn_named_pipe(_daemon: &LiveDaemon, _name: String, _format: pipe::JsonFormat) {
+    tracing::warn!("LEVENTS_NAMED_PIPE is only supported on Windows; ignoring it");
+}
+
+fn spawn_mdns(instance: &str, addr: SocketAddr) {
+    let Some(announcement) = mdns::Announcement::for_grpc(instance, addr) else {
+        tracing::warn!(%addr, "ignoring LEVENTS_MDNS; the gRPC server only listens on loopback");
+        return;
+    };
+    tokio::spawn(async move {
+        if let Err(error) = mdns::announce(announcement).await {
+            tracing::error!(?error, "mDNS announcer stopped");
+        }
+    });
+}
 
-    grpc::serve(daemon, addr).await
+/// Parse an optional environment variable.
+fn env_var<T>(name: &str) -> Result<Option<T>>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    match std::env::var(name) {
+        Ok(value) => value
+            .parse()
+            .map(Some)
+            .with_context(|| format!("failed to parse {name}")),
+        Err(_) => Ok(None),
+    }
 }
 
-fn init_tracing() {
+/// Log to stderr in pipe mode, where stdout carries the events.
+fn init_tracing(pipe_mode: bool) {
     use tracing_subscriber::prelude::*;
     use tracing_subscriber::{fmt, EnvFilter};
 
     let fmt_layer = fmt::layer().with_target(false);
+    let fmt_layer = if pipe_mode {
+        fmt_layer.with_writer(std::io::stderr).boxed()
+    } else {
+        fmt_layer.boxed()
+    };
     let filter_layer = EnvFilter::try_from_default_env()
         .or_else(|_| EnvFilter::try_new("info"))
         .expect("env filter");
@@ -0,0 +1,366 @@
+//! mDNS (DNS-SD) announcement of the daemon as `_levents._tcp.local`.
+//!
+//! Overlay apps on another device browse for the service instead of asking the operator for
+//! an IP. The responder answers PTR/SRV/TXT/ANY queries for the service and announces itself
+//! on startup; TXT records carry the daemon version, the event schema and the transports.
+//! Queries from a port other than 5353 (one-shot resolvers such as the TypeScript SDK's
+//! `discoverDaemons`) are answered by unicast, as RFC 6762 §6.7 asks.
+
+use std::{
+    io,
+    net::{IpAddr, Ipv4Addr, SocketAddr, SocketAddrV4},
+    time::Duration,
+};
+
+use anyhow::{Context, Result};
+use levents_model::SCHEMA_VERSION;
+use socket2::{Domain, Protocol, Socket, Type};
+use tokio::net::UdpSocket;
+use tracing::{debug, info, warn};
+
+/// DNS-SD service type the daemon registers under.
+pub const SERVICE: &str = "_levents._tcp.local";
+
+const MDNS_GROUP: Ipv4Addr = Ipv4Addr::new(224, 0, 0, 251);
+const MDNS_PORT: u16 = 5353;
+
+const TYPE_A: u16 = 1;
+const TYPE_PTR: u16 = 12;
+const TYPE_TXT: u16 = 16;
+const TYPE_AAAA: u16 = 28;
+const TYPE_SRV: u16 = 33;
+const TYPE_ANY: u16 = 255;
+const CLASS_IN: u16 = 1;
+/// Set on records this host is authoritative for, so caches replace older copies.
+const CACHE_FLUSH: u16 = 0x8000;
+
+/// What the daemon advertises about itself.
+#[derive(Debug, Clone)]
+pub struct Announcement {
+    /// Instance name shown to users, e.g. `stage-left`.
+    pub instance: String,
+    /// Port of the gRPC server.
+    pub port: u16,
+    /// Addresses other devices can reach the daemon at.
+    pub addresses: Vec<IpAddr>,
+    /// Transports served on `port`, e.g. `grpc`.
+    pub transports: Vec<String>,
+}
+
+impl Announcement {
+    /// Announce the gRPC server bound to `bind` as `instance`.
+    ///
+    /// A wildcard bind advertises the address of the interface that routes multicast; a
+    /// loopback bind is unreachable from other devices, so it yields `None`.
+    pub fn for_grpc(instance: &str, bind: SocketAddr) -> Option<Self> {
+        let ip = bind.ip();
+        if ip.is_loopback() {
+            return None;
+        }
+        let addresses = if ip.is_unspecified() {
+            multicast_interface_address().into_iter().collect()
+        } else {
+            vec![ip]
+        };
+        Some(Self {
+            instance: instance.to_string(),
+            port: bind.port(),
+            addresses,
+            transports: vec!["grpc".to_string()],
+        })
+    }
+
+    fn instance_name(&self) -> String {
+        format!("{}.{SERVICE}", self.instance)
+    }
+
+    fn host_name(&self) -> String {
+        let label: String = self
+            .instance
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_lowercase()
+                } else {
+                    '-'
+                }
+            })
+            .collect();
+        format!("levents-{label}.local")
+    }
+
+    /// TXT record entries, in order.
+    fn txt(&self) -> Vec<String> {
+        vec![
+            format!("version={}", env!("CARGO_PKG_VERSION")),
+            format!("schema=v{SCHEMA_VERSION}"),
+            format!("transports={}", self.transports.join(",")),
+        ]
+    }
+
+    /// Whether `query` asks for this service or instance.
+    fn answers(&self, query: &[u8]) -> bool {
+        let instance = self.instance_name();
+        questions(query).into_iter().any(|(name, qtype)| {
+            (name.eq_ignore_ascii_case(SERVICE) && matches!(qtype, TYPE_PTR | TYPE_ANY))
+                || (name.eq_ignore_ascii_case(&instance)
+                    && matches!(qtype, TYPE_SRV | TYPE_TXT | TYPE_ANY))
+        })
+    }
+
+    /// A response packet carrying the PTR answer and the SRV, TXT and address records.
+    ///
+    /// `id` and `question` are echoed for unicast replies to one-shot queries.
+    fn response(&self, id: u16, question: Option<&[u8]>) -> Vec<u8> {
+        let instance = self.instance_name();
+        let host = self.host_name();
+        let additional = 2 + self.addresses.len();
+        let mut packet = Vec::with_capacity(512);
+        packet.extend_from_slice(&id.to_be_bytes());
+        packet.extend_from_slice(&0x8400u16.to_be_bytes());
+        packet.extend_from_slice(&u16::from(question.is_some()).to_be_bytes());
+        packet.extend_from_slice(&1u16.to_be_bytes());
+        packet.extend_from_slice(&0u16.to_be_bytes());
+        // Filled in below, once the records are written.
+        let additional_at = packet.len();
+        packet.extend_from_slice(&0u16.to_be_bytes());
+        if let Some(question) = question {
+            packet.extend_from_slice(question);
+        }
+
+        let mut rdata = Vec::new();
+        write_name(&mut rdata, &instance);
+        write_record(&mut packet, SERVICE, TYPE_PTR, CLASS_IN, 4500, &rdata);
+
+        rdata.clear();
+        rdata.extend_from_slice(&0u16.to_be_bytes());
+        rdata.extend_from_slice(&0u16.to_be_bytes());
+        rdata.extend_from_slice(&self.port.to_be_bytes());
+        write_name(&mut rdata, &host);
+        write_record(
+            &mut packet,
+            &instance,
+            TYPE_SRV,
+            CLASS_IN | CACHE_FLUSH,
+            120,
+            &rdata,
+        );
+
+        rdata.clear();
+        for entry in self.txt() {
+            rdata.push(entry.len() as u8);
+            rdata.extend_from_slice(entry.as_bytes());
+        }
+        write_record(
+            &mut packet,
+            &instance,
+            TYPE_TXT,
+            CLASS_IN | CACHE_FLUSH,
+            4500,
+            &rdata,
+        );
+
+        for address in &self.addresses {
+            let (rtype, rdata) = match address {
+                IpAddr::V4(v4) => (TYPE_A, v4.octets().to_vec()),
+                IpAddr::V6(v6) => (TYPE_AAAA, v6.octets().to_vec()),
+            };
+            write_record(
+                &mut packet,
+                &host,
+                rtype,
+                CLASS_IN | CACHE_FLUSH,
+                120,
+                &rdata,
+            );
+        }
+        packet[additional_at..additional_at + 2]
+            .copy_from_slice(&(additional as u16).to_be_bytes());
+        packet
+    }
+}
+
+/// Answer queries for `announcement` on the mDNS group until the socket fails.
+pub async fn announce(announcement: Announcement) -> Result<()> {
+    let socket = bind_multicast().context("failed to join the mDNS group")?;
+    let group = SocketAddr::from((MDNS_GROUP, MDNS_PORT));
+    info!(
+        instance = %announcement.instance,
+        port = announcement.port,
+        addresses = ?announcement.addresses,
+        "announcing the daemon over mDNS"
+    );
+
+    // RFC 6762 §8.3: announce twice, a second apart.
+    for _ in 0..2 {
+        socket
+            .send_to(&announcement.response(0, None), group)
+            .await?;
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+
+    let mut buf = [0u8; 1500];
+    loop {
+        let (len, peer) = socket.recv_from(&mut buf).await?;
+        let query = &buf[..len];
+        if !is_query(query) || !announcement.answers(query) {
+            continue;
+        }
+        debug!(%peer, "answering mDNS query");
+        let sent = if peer.port() == MDNS_PORT {
+            socket.send_to(&announcement.response(0, None), group).await
+        } else {
+            let id = u16::from_be_bytes([query[0], query[1]]);
+            let response = announcement.response(id, question_section(query));
+            socket.send_to(&response, peer).await
+        };
+        if let Err(error) = sent {
+            warn!(?error, %peer, "failed to answer mDNS query");
+        }
+    }
+}
+
+fn bind_multicast() -> io::Result<UdpSocket> {
+    let socket = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?;
+    // Share the port with the OS responder (Bonjour, Avahi) and other daemons.
+    socket.set_reuse_address(true)?;
+    #[cfg(unix)]
+    socket.set_reuse_port(true)?;
+    socket.set_nonblocking(true)?;
+    socket.bind(&SocketAddr::from(SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, MDNS_PORT)).into())?;
+    socket.join_multicast_v4(&MDNS_GROUP, &Ipv4Addr::UNSPECIFIED)?;
+    socket.set_multicast_ttl_v4(255)?;
+    UdpSocket::from_std(socket.into())
+}
+
+/// Address of the interface the OS routes multicast through; no packet is sent.
+fn multicast_interface_address() -> Option<IpAddr> {
+    let socket = std::net::UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0)).ok()?;
+    socket.connect((MDNS_GROUP, MDNS_PORT)).ok()?;
+    let ip = socket.local_addr().ok()?.ip();
+    (!ip.is_unspecified()).then_some(ip)
+}
+
+fn write_name(out: &mut Vec<u8>, name: &str) {
+    for label in name.split('.').filter(|label| !label.is_empty()) {
+        let label = &label.as_bytes()[..label.len().min(63)];
+        out.push(label.len() as u8);
+        out.extend_from_slice(label);
+    }
+    out.push(0);
+}
+
+fn write_record(out: &mut Vec<u8>, name: &str, rtype: u16, class: u16, ttl: u32, rdata: &[u8]) {
+    write_name(out, name);
+    out.extend_from_slice(&rtype.to_be_bytes());
+    out.extend_from_slice(&class.to_be_bytes());
+    out.extend_from_slice(&ttl.to_be_bytes());
+    out.extend_from_slice(&(rdata.len() as u16).to_be_bytes());
+    out.extend_from_slice(rdata);
+}
+
+fn is_query(packet: &[u8]) -> bool {
+    // QR clear (a query) and opcode 0 (standard query).
+    packet.len() >= 12 && packet[2] & 0xF8 == 0
+}
+
+/// Name and type of every question in `packet`.
+fn questions(packet: &[u8]) -> Vec<(String, u16)> {
+    let count = match packet.get(4..6) {
+        Some(bytes) => u16::from_be_bytes([bytes[0], bytes[1]]),
+        None => return Vec::new(),
+    };
+    let mut offset = 12;
+    let mut questions = Vec::new();
+    for _ in 0..count {
+        let Some((name, next)) = read_name(packet, offset) else {
+            break;
+        };
+        let Some(qtype) = packet.get(next..next + 2) else {
+            break;
+        };
+        questions.push((name, u16::from_be_bytes([qtype[0], qtype[1]])));
+        offset = next + 4;
+    }
+    questions
+}
+
+/// The raw question section of `packet`, echoed in unicast replies.
+fn question_section(packet: &[u8]) -> Option<&[u8]> {
+    let count = u16::from_be_bytes([packet[4], packet[5]]);
+    if count != 1 {
+        return None;
+    }
+    let (_, next) = read_name(packet, 12)?;
+    packet.get(12..next + 4)
+}
+
+/// Decode the possibly compressed name at `offset`; returns it and the offset past it.
+fn read_name(packet: &[u8], mut offset: usize) -> Option<(String, usize)> {
+    let mut labels = Vec::new();
+    let mut end = None;
+    // Bounds pointer chains so a malicious packet cannot loop forever.
+    for _ in 0..32 {
+        let len = *packet.get(offset)? as usize;
+        match len {
+            0 => {
+                let name = labels.join(".");
+                return Some((name, end.unwrap_or(offset + 1)));
+            }
+            len if len & 0xC0 == 0xC0 => {
+                let low = *packet.get(offset + 1)? as usize;
+                end.get_or_insert(offset + 2);
+                offset = ((len & 0x3F) << 8) | low;
+            }
+            len => {
+                let label = packet.get(offset + 1..offset + 1 + len)?;
+                labels.push(String::from_utf8_lossy(label).into_owned());
+                offset += 1 + len;
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(name: &str, qtype: u16) -> Vec<u8> {
+        let mut packet = vec![0x12, 0x34, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0];
+        write_name(&mut packet, name);
+        packet.extend_from_slice(&qtype.to_be_bytes());
+        packet.extend_from_slice(&CLASS_IN.to_be_bytes());
+        packet
+    }
+
+    #[test]
+    fn service_queries_are_answered_with_srv_txt_and_address() {
+        let announcement = Announcement {
+            instance: "Stage Left".to_string(),
+            port: 50051,
+            addresses: vec!["192.168.1.20".parse().unwrap()],
+            transports: vec!["grpc".to_string()],
+        };
+        let ptr = query(SERVICE, TYPE_PTR);
+        assert!(is_query(&ptr));
+        assert!(announcement.answers(&ptr));
+        assert!(announcement.answers(&query("Stage Left._levents._tcp.local", TYPE_SRV)));
+        assert!(!announcement.answers(&query("_http._tcp.local", TYPE_PTR)));
+
+        let response = announcement.response(0x1234, question_section(&ptr));
+        assert!(!is_query(&response));
+        assert_eq!(&response[..2], &[0x12, 0x34]);
+        // One echoed question, the PTR answer, then SRV, TXT and A as additional records.
+        assert_eq!(&response[4..12], &[0, 1, 0, 1, 0, 0, 0, 3]);
+        assert_eq!(questions(&response), [(SERVICE.to_string(), TYPE_PTR)]);
+
+        let host = b"\x12levents-stage-left\x05local\x00";
+        assert!(response.windows(host.len()).any(|window| window == host));
+        let srv_port = [0, 0, 0, 0, 0xC3, 0x83];
+        assert!(response.windows(6).any(|window| window == srv_port));
+        let txt = b"\x09schema=v1\x0ftransports=grpc";
+        assert!(response.windows(txt.len()).any(|window| window == txt));
+        assert!(response.ends_with(&[192, 168, 1, 20]));
+    }
+}
@@ -0,0 +1,61 @@
+//! JSON Lines publisher on a Windows named pipe.
+//!
+//! C# overlay apps often prefer named pipes over sockets: they need no port, and access is
+//! limited to the local machine. Every client that connects to the pipe receives the events
+//! published from then on, one JSON object per line, in the same format as `pipe` mode.
+
+use std::io::ErrorKind;
+
+use anyhow::{Context, Result};
+use levents_core::{EventFilter, LiveDaemon};
+use tokio::net::windows::named_pipe::{NamedPipeServer, ServerOptions};
+use tracing::{debug, info, warn};
+
+use crate::pipe::{write_event, JsonFormat};
+
+/// Accept clients on `name` (e.g. `\\.\pipe\levents`) until creating a pipe instance fails,
+/// writing events in `format`.
+pub async fn publish(daemon: LiveDaemon, name: String, format: JsonFormat) -> Result<()> {
+    let mut server = ServerOptions::new()
+        .first_pipe_instance(true)
+        .reject_remote_clients(true)
+        .create(&name)
+        .with_context(|| format!("failed to create named pipe {name}"))?;
+    info!(%name, "publishing events on named pipe");
+
+    loop {
+        let connected = server.connect().await;
+
+        // Create the next instance before handing this one off so clients never find the
+        // pipe missing.
+        let client = server;
+        server = ServerOptions::new()
+            .reject_remote_clients(true)
+            .create(&name)
+            .with_context(|| format!("failed to create named pipe {name}"))?;
+
+        match connected {
+            Ok(()) => {
+                tokio::spawn(serve_client(daemon.clone(), client, format));
+            }
+            Err(error) => warn!(?error, %name, "named pipe client failed to connect"),
+        }
+    }
+}
+
+async fn serve_client(daemon: LiveDaemon, mut client: NamedPipeServer, format: JsonFormat) {
+    debug!("named pipe client connected");
+    let mut subscription = daemon.events_hub().subscribe(EventFilter::all());
+    while let Some(event) = subscription.recv().await {
+        let anchor = daemon.game_clock_anchor();
+        match write_event(&mut client, &event, format, anchor).await {
+            Ok(()) => {}
+            Err(error) if error.kind() == ErrorKind::BrokenPipe => break,
+            Err(error) => {
+                warn!(?error, "failed to write to named pipe client");
+                break;
+            }
+        }
+    }
+    debug!("named pipe client disconnected");
+}
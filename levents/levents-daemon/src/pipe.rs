@@ -0,0 +1,426 @@
+//! JSON Lines over stdin/stdout, for `levents-daemon pipe`.
+//!
+//! Every published event is written to stdout as one JSON object per line, and every line read
+//! from stdin is parsed as a control command, so the daemon composes with `jq` and friends and
+//! integrates on machines where no port can be opened. Logs go to stderr to keep stdout clean.
+//!
+//! ```text
+//! {"command": "emitSyntheticKill", "summonerName": "Faker"}
+//! {"command": "setSeries", "bestOf": 5, "gameNumber": 3, "orderWins": 1, "chaosWins": 1}
+//! {"command": "adjustScore", "team": "order", "kills": 1}
+//! {"command": "overrideResult", "winner": "chaos"}
+//! {"command": "seekToLive"}
+//! {"command": "setAutoAccept", "enabled": true}
+//! ```
+//!
+//! Consumers written against an older event schema can ask for it with `LEVENTS_JSON_SCHEMA`;
+//! kinds renamed since then are written under their old names. With `rfc3339` timestamps every
+//! line also carries its wall-clock `time`, which spreadsheets and log pipelines read without
+//! guessing what the raw `ts` counts from.
+
+use std::io::ErrorKind;
+use std::str::FromStr;
+
+use anyhow::{bail, ensure, Context, Result};
+use levents_core::{EventBus, EventFilter, LiveDaemon, SeriesSettings, Subscription};
+use levents_model::{Event, Team, TimestampMs, SCHEMA_VERSION};
+use serde::Deserialize;
+use serde_json::Value;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+use tracing::{info, warn};
+
+use crate::audit::AuditLog;
+
+/// Caller recorded in the audit log for commands read from stdin.
+const CALLER: &str = "stdin";
+
+/// Timestamps below a day are game-clock time; wall-clock ones are decades past the epoch.
+const GAME_CLOCK_LIMIT_MS: TimestampMs = 86_400_000;
+
+/// How events are written as JSON lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JsonFormat {
+    /// Event schema version to name kinds by; see [`levents_model::KIND_ALIASES`].
+    pub schema: u32,
+    pub timestamps: Timestamps,
+}
+
+impl Default for JsonFormat {
+    fn default() -> Self {
+        Self {
+            schema: SCHEMA_VERSION,
+            timestamps: Timestamps::default(),
+        }
+    }
+}
+
+/// How event times are written.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Timestamps {
+    /// `ts` alone: Unix milliseconds, or game-clock milliseconds for Live Client events.
+    #[default]
+    Millis,
+    /// `ts` plus `time`, the wall-clock time as an RFC 3339 string in UTC. Game-clock events
+    /// also carry `gameStart`, the wall-clock anchor `time` was computed from, and have no
+    /// `time` until the game clock has been read once.
+    Rfc3339,
+}
+
+impl FromStr for Timestamps {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "millis" => Ok(Self::Millis),
+            "rfc3339" => Ok(Self::Rfc3339),
+            other => bail!("unknown timestamp format `{other}` (expected millis or rfc3339)"),
+        }
+    }
+}
+
+/// A control command read from stdin.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "command", rename_all = "camelCase")]
+enum Command {
+    #[serde(rename_all = "camelCase")]
+    EmitSyntheticKill {
+        summoner_name: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    SetSeries {
+        best_of: u32,
+        #[serde(default)]
+        game_number: u32,
+        #[serde(default)]
+        order_wins: u32,
+        #[serde(default)]
+        chaos_wins: u32,
+    },
+    AdjustScore {
+        team: Team,
+        kills: i32,
+    },
+    OverrideResult {
+        winner: Team,
+    },
+    SeekToLive,
+    SetAutoAccept {
+        enabled: bool,
+    },
+}
+
+impl Command {
+    fn name(&self) -> &'static str {
+        match self {
+            Command::EmitSyntheticKill { .. } => "EmitSyntheticKill",
+            Command::SetSeries { .. } => "SetSeries",
+            Command::AdjustScore { .. } => "AdjustScore",
+            Command::OverrideResult { .. } => "OverrideResult",
+            Command::SeekToLive => "SeekToLive",
+            Command::SetAutoAccept { .. } => "SetAutoAccept",
+        }
+    }
+}
+
+/// Stream events to stdout in `format` and apply commands from stdin until stdout is closed.
+pub async fn run(daemon: LiveDaemon, audit: AuditLog, format: JsonFormat) -> Result<()> {
+    let bus = daemon.events_hub();
+    // Subscribe before publishing the bootstrap so the snapshot is the first thing written.
+    let subscription = bus.subscribe(EventFilter::all());
+    let bootstrap = daemon.bootstrap().await?;
+    info!(events = bootstrap.events.len(), "daemon bootstrap complete");
+    bus.publish_batch(bootstrap);
+
+    relay(
+        daemon,
+        bus,
+        subscription,
+        audit,
+        BufReader::new(tokio::io::stdin()),
+        tokio::io::stdout(),
+        format,
+    )
+    .await
+}
+
+async fn relay<R, W>(
+    daemon: LiveDaemon,
+    bus: EventBus,
+    mut subscription: Subscription,
+    audit: AuditLog,
+    input: R,
+    mut output: W,
+    format: JsonFormat,
+) -> Result<()>
+where
+    R: AsyncBufRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    let mut lines = Some(input.lines());
+    loop {
+        tokio::select! {
+            event = subscription.recv() => {
+                let Some(event) = event else {
+                    return Ok(());
+                };
+                let anchor = daemon.game_clock_anchor();
+                match write_event(&mut output, &event, format, anchor).await {
+                    Ok(()) => {}
+                    // The reader went away, e.g. `head` has seen enough.
+                    Err(error) if error.kind() == ErrorKind::BrokenPipe => return Ok(()),
+                    Err(error) => return Err(error).context("failed to write event"),
+                }
+            }
+            line = async { lines.as_mut()?.next_line().await.transpose() }, if lines.is_some() => {
+                match line {
+                    Some(Ok(line)) if line.trim().is_empty() => {}
+                    Some(Ok(line)) => apply_line(&daemon, &bus, &audit, &line),
+                    Some(Err(error)) => {
+                        warn!(?error, "failed to read stdin; no longer accepting commands");
+                        lines = None;
+                    }
+                    // Keep streaming after stdin closes, e.g. `levents-daemon pipe < /dev/null`.
+                    None => lines = None,
+                }
+            }
+        }
+    }
+}
+
+/// Write `event` as one JSON line in `format` and flush it.
+///
+/// `game_clock_anchor` is the wall-clock time the game clock started at, see
+/// [`LiveDaemon::game_clock_anchor`].
+pub(crate) async fn write_event<W: AsyncWrite + Unpin>(
+    output: &mut W,
+    event: &Event,
+    format: JsonFormat,
+    game_clock_anchor: Option<TimestampMs>,
+) -> std::io::Result<()> {
+    let legacy_name = event.kind.legacy_name(format.schema);
+    let older_schema = format.schema < event.schema_version;
+    let as_published =
+        legacy_name.is_none() && !older_schema && format.timestamps == Timestamps::Millis;
+    let mut line = if as_published {
+        serde_json::to_vec(event)?
+    } else {
+        let mut value = serde_json::to_value(event)?;
+        if older_schema {
+            value["schemaVersion"] = format.schema.into();
+        }
+        if let Some(name) = legacy_name {
+            value["kind"] = name.into();
+        }
+        if format.timestamps == Timestamps::Rfc3339 {
+            add_wall_clock_time(&mut value, event.ts, game_clock_anchor);
+        }
+        serde_json::to_vec(&value)?
+    };
+    line.push(b'\n');
+    output.write_all(&line).await?;
+    output.flush().await
+}
+
+fn add_wall_clock_time(value: &mut Value, ts: TimestampMs, anchor: Option<TimestampMs>) {
+    if ts >= GAME_CLOCK_LIMIT_MS {
+        value["time"] = rfc3339(ts).into();
+    } else if let Some(anchor) = anchor {
+        value["time"] = rfc3339(anchor + ts).into();
+        value["gameStart"] = rfc3339(anchor).into();
+    }
+}
+
+/// `YYYY-MM-DDTHH:MM:SS.mmmZ` for Unix milliseconds, using Howard Hinnant's days-to-civil
+/// algorithm.
+fn rfc3339(ms: TimestampMs) -> String {
+    let secs = ms / 1_000;
+    let days = (secs / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    let time = secs % 86_400;
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+        time / 3_600,
+        time % 3_600 / 60,
+        time % 60,
+        ms % 1_000
+    )
+}
+
+fn apply_line(daemon: &LiveDaemon, bus: &EventBus, audit: &AuditLog, line: &str) {
+    let command = match serde_json::from_str::<Command>(line) {
+        Ok(command) => command,
+        Err(error) => {
+            warn!(%error, line, "ignoring invalid control command");
+            return;
+        }
+    };
+
+    let name = command.name();
+    let detail = format!("{command:?}");
+    let outcome = match execute(daemon, bus, command) {
+        Ok(message) => {
+            info!(command = name, message, "control command applied");
+            "accepted".to_string()
+        }
+        Err(error) => {
+            warn!(command = name, %error, "control command rejected");
+            error.to_string()
+        }
+    };
+    audit.record(CALLER, name, &detail, &outcome);
+}
+
+/// Apply `command`, mirroring the validation of the gRPC `Control` call.
+fn execute(daemon: &LiveDaemon, bus: &EventBus, command: Command) -> Result<String> {
+    match command {
+        Command::EmitSyntheticKill { summoner_name } => {
+            ensure!(!summoner_name.trim().is_empty(), "summonerName is required");
+            bus.publish(daemon.synthetic_kill(&summoner_name));
+            Ok(format!("synthetic kill issued for {summoner_name}"))
+        }
+        Command::SetSeries {
+            best_of,
+            game_number,
+            order_wins,
+            chaos_wins,
+        } => {
+            ensure!(
+                best_of == 0 || game_number <= best_of,
+                "gameNumber must not exceed bestOf"
+            );
+            daemon.set_series(SeriesSettings {
+                best_of,
+                game_number,
+                order_wins,
+                chaos_wins,
+            });
+            Ok(if best_of == 0 {
+                "series cleared".to_string()
+            } else {
+                format!("game {game_number} of a best-of-{best_of} ({order_wins}-{chaos_wins})")
+            })
+        }
+        Command::AdjustScore { team, kills } => {
+            ensure!(team != Team::Neutral, "team must be order or chaos");
+            ensure!(kills != 0, "kills must be non-zero");
+            bus.publish(daemon.adjust_score(team.clone(), kills));
+            Ok(format!("adjusted {team:?} score by {kills:+}"))
+        }
+        Command::OverrideResult { winner } => {
+            ensure!(winner != Team::Neutral, "winner must be order or chaos");
+            bus.publish(daemon.override_result(winner.clone()));
+            Ok(format!("result overridden: {winner:?} wins"))
+        }
+        Command::SeekToLive => {
+            bus.seek_to_live();
+            Ok("delayed subscribers released to live".to_string())
+        }
+        Command::SetAutoAccept { enabled } => {
+            daemon.set_auto_accept(enabled);
+            Ok(format!(
+                "ready check auto-accept {}",
+                if enabled { "on" } else { "off" }
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use levents_core::DaemonConfig;
+    use levents_model::{EventKind, EventPayload};
+    use std::time::Duration;
+    use tokio::io::Lines;
+
+    async fn next_event<R: AsyncBufRead + Unpin>(lines: &mut Lines<R>) -> Event {
+        let line = tokio::time::timeout(Duration::from_secs(5), lines.next_line())
+            .await
+            .expect("timed out waiting for stdout")
+            .expect("read stdout")
+            .expect("stdout closed");
+        serde_json::from_str(&line).expect("event JSON")
+    }
+
+    #[tokio::test]
+    async fn commands_from_stdin_show_up_on_stdout() {
+        let daemon = LiveDaemon::new(DaemonConfig::default());
+        let bus = EventBus::new();
+        let subscription = bus.subscribe(EventFilter::all());
+        let input: &[u8] = b"{\"command\": \"emitSyntheticKill\", \"summonerName\": \"Alpha\"}\n\
+            not json\n\
+            {\"command\": \"adjustScore\", \"team\": \"neutral\", \"kills\": 1}\n\
+            {\"command\": \"setAutoAccept\", \"enabled\": true}\n\
+            {\"command\": \"overrideResult\", \"winner\": \"chaos\"}\n";
+        let (output, stdout) = tokio::io::duplex(4096);
+        let relay = tokio::spawn(relay(
+            daemon.clone(),
+            bus,
+            subscription,
+            AuditLog::default(),
+            input,
+            output,
+            JsonFormat::default(),
+        ));
+
+        let mut lines = BufReader::new(stdout).lines();
+        let kill = next_event(&mut lines).await;
+        assert_eq!(kill.kind, EventKind::Kill);
+        let ended = next_event(&mut lines).await;
+        assert_eq!(ended.kind, EventKind::GameEnded);
+        assert!(ended.manual);
+        assert!(matches!(
+            ended.payload,
+            EventPayload::GameResult(ref result) if result.winner == Some(Team::Chaos)
+        ));
+        assert!(daemon.auto_accept());
+        relay.abort();
+    }
+
+    #[tokio::test]
+    async fn rfc3339_timestamps_anchor_game_clock_events() {
+        let format = JsonFormat {
+            timestamps: Timestamps::Rfc3339,
+            ..JsonFormat::default()
+        };
+        let line = |event: Event, anchor| async move {
+            let mut output = Vec::new();
+            write_event(&mut output, &event, format, anchor)
+                .await
+                .unwrap();
+            serde_json::from_slice::<Value>(&output).unwrap()
+        };
+        let daemon = LiveDaemon::new(DaemonConfig::default());
+
+        let manual = line(daemon.adjust_score(Team::Order, 1), None).await;
+        assert!(manual["time"].as_str().unwrap().ends_with('Z'));
+        assert!(manual.get("gameStart").is_none());
+
+        let kill = line(daemon.synthetic_kill("Alpha"), None).await;
+        let mut in_game = kill.clone();
+        in_game["ts"] = 95_250.into();
+        let in_game = line(serde_json::from_value(in_game).unwrap(), None).await;
+        assert!(in_game.get("time").is_none());
+        let mut anchored = kill;
+        anchored["ts"] = 95_250.into();
+        let anchored = line(
+            serde_json::from_value(anchored).unwrap(),
+            Some(1_792_171_205_123),
+        )
+        .await;
+        assert_eq!(anchored["gameStart"], "2026-10-16T17:20:05.123Z");
+        assert_eq!(anchored["time"], "2026-10-16T17:21:40.373Z");
+        assert_eq!(anchored["ts"], 95_250);
+
+        assert_eq!(rfc3339(0), "1970-01-01T00:00:00.000Z");
+        assert_eq!(rfc3339(951_782_400_000), "2000-02-29T00:00:00.000Z");
+    }
+}
@@ -0,0 +1,218 @@
+//! Lifecycle of gRPC subscribers: a `Diagnostic` event when one connects, falls behind or
+//! disconnects, and counters reported by `GetServerInfo`.
+//!
+//! Overlay boxes rarely log anything an operator can reach during a broadcast; these events
+//! show which one dropped off mid-game from any other subscriber, such as the operator's own
+//! dashboard.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use levents_core::EventBus;
+use levents_model::{DiagnosticEvent, Event, EventKind, EventPayload};
+use tracing::{info, warn};
+
+use crate::grpc::pb::SubscriberStats;
+
+/// `source` of the diagnostics.
+const SOURCE: &str = "subscribers";
+/// Lag is reported at most this often per subscriber, so a slow consumer is not sent a
+/// diagnostic for every burst it misses.
+const LAG_REPORT_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Subscriber counters since the server started; clones share them.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SubscriberMetrics {
+    inner: Arc<MetricsInner>,
+}
+
+#[derive(Debug, Default)]
+struct MetricsInner {
+    connected: AtomicU64,
+    disconnected: AtomicU64,
+    lagged_events: AtomicU64,
+}
+
+impl SubscriberMetrics {
+    pub(crate) fn stats(&self) -> SubscriberStats {
+        let connected = self.inner.connected.load(Ordering::Relaxed);
+        let disconnected = self.inner.disconnected.load(Ordering::Relaxed);
+        SubscriberStats {
+            active: connected.saturating_sub(disconnected),
+            connected,
+            disconnected,
+            lagged_events: self.inner.lagged_events.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// One connected subscriber; reports the disconnect when the stream holding it is dropped.
+pub(crate) struct SubscriberSession {
+    bus: EventBus,
+    metrics: SubscriberMetrics,
+    /// API key name and peer address, e.g. `overlay-1@10.0.0.5:51234`.
+    label: String,
+    connected_at: Instant,
+    delivered: u64,
+    /// Events skipped as of the last call to [`SubscriberSession::observe_lag`].
+    lagged: u64,
+    /// Skipped events not reported yet.
+    unreported: u64,
+    last_lag_report: Option<Instant>,
+}
+
+impl SubscriberSession {
+    /// Count the subscriber and announce it with a summary of its filter.
+    pub(crate) fn connect(
+        bus: EventBus,
+        metrics: SubscriberMetrics,
+        label: String,
+        filter: &str,
+    ) -> Self {
+        metrics.inner.connected.fetch_add(1, Ordering::Relaxed);
+        info!(subscriber = %label, filter, "subscriber connected");
+        publish(&bus, format!("{label} connected ({filter})"));
+        Self {
+            bus,
+            metrics,
+            label,
+            connected_at: Instant::now(),
+            delivered: 0,
+            lagged: 0,
+            unreported: 0,
+            last_lag_report: None,
+        }
+    }
+
+    pub(crate) fn delivered(&mut self, count: usize) {
+        self.delivered += count as u64;
+    }
+
+    /// Take the subscription's running count of skipped events; new skips are reported at
+    /// most once per [`LAG_REPORT_INTERVAL`].
+    pub(crate) fn observe_lag(&mut self, lagged: u64) {
+        let skipped = lagged.saturating_sub(self.lagged);
+        if skipped == 0 {
+            return;
+        }
+        self.lagged = lagged;
+        self.unreported += skipped;
+        self.metrics
+            .inner
+            .lagged_events
+            .fetch_add(skipped, Ordering::Relaxed);
+        if self
+            .last_lag_report
+            .is_some_and(|at| at.elapsed() < LAG_REPORT_INTERVAL)
+        {
+            return;
+        }
+        warn!(subscriber = %self.label, skipped = self.unreported, "subscriber lagging");
+        publish(
+            &self.bus,
+            format!(
+                "{} is lagging; {} events skipped",
+                self.label, self.unreported
+            ),
+        );
+        self.unreported = 0;
+        self.last_lag_report = Some(Instant::now());
+    }
+}
+
+impl Drop for SubscriberSession {
+    fn drop(&mut self) {
+        self.metrics
+            .inner
+            .disconnected
+            .fetch_add(1, Ordering::Relaxed);
+        let connected_secs = self.connected_at.elapsed().as_secs();
+        info!(
+            subscriber = %self.label,
+            connected_secs,
+            delivered = self.delivered,
+            lagged = self.lagged,
+            "subscriber disconnected"
+        );
+        publish(
+            &self.bus,
+            format!(
+                "{} disconnected after {connected_secs}s; {} events delivered, {} skipped",
+                self.label, self.delivered, self.lagged
+            ),
+        );
+    }
+}
+
+fn publish(bus: &EventBus, message: String) {
+    bus.publish(Event::new(
+        EventKind::Diagnostic,
+        timestamp_ms(),
+        EventPayload::Diagnostic(DiagnosticEvent {
+            source: SOURCE.to_string(),
+            message,
+        }),
+    ));
+}
+
+fn timestamp_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use levents_core::EventFilter;
+
+    fn message(event: Option<Event>) -> String {
+        match event.map(|event| event.payload) {
+            Some(EventPayload::Diagnostic(diagnostic)) => diagnostic.message,
+            other => panic!("unexpected payload {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn lifecycle_is_announced_and_counted() {
+        let bus = EventBus::new();
+        let mut diagnostics = bus.subscribe(EventFilter::kinds([EventKind::Diagnostic]));
+        let metrics = SubscriberMetrics::default();
+
+        let mut session = SubscriberSession::connect(
+            bus.clone(),
+            metrics.clone(),
+            "overlay@10.0.0.5:51234".to_string(),
+            "all kinds",
+        );
+        assert_eq!(
+            message(diagnostics.recv().await),
+            "overlay@10.0.0.5:51234 connected (all kinds)"
+        );
+        assert_eq!(metrics.stats().active, 1);
+
+        session.delivered(3);
+        session.observe_lag(5);
+        // Within the report interval: counted but not announced again.
+        session.observe_lag(7);
+        assert_eq!(
+            message(diagnostics.recv().await),
+            "overlay@10.0.0.5:51234 is lagging; 5 events skipped"
+        );
+        drop(session);
+        assert!(message(diagnostics.recv().await)
+            .ends_with("disconnected after 0s; 3 events delivered, 7 skipped"));
+
+        assert_eq!(
+            metrics.stats(),
+            SubscriberStats {
+                active: 0,
+                connected: 1,
+                disconnected: 1,
+                lagged_events: 7,
+            }
+        );
+    }
+}
@@ -0,0 +1,115 @@
+//! Opt-in check for newer daemon releases on GitHub.
+//!
+//! Nothing is sent besides the request itself. When the latest release is newer than the running
+//! build, a `Diagnostic` event tells the operator, who decides when to update.
+
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use levents_core::{build_info, EventBus};
+use levents_model::{DiagnosticEvent, Event, EventKind, EventPayload};
+use serde::Deserialize;
+use tracing::{debug, info, warn};
+
+const RELEASES_URL: &str = "https://api.github.com/repos/mrdeadlift/hex-event/releases/latest";
+/// Daemons run for days at venues, so the check repeats.
+const CHECK_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+#[derive(Debug, Deserialize)]
+struct Release {
+    tag_name: String,
+    html_url: String,
+}
+
+/// Check for a newer release now and then once a day, publishing a diagnostic into `bus`
+/// for each newer release found.
+pub async fn run(bus: EventBus) {
+    let http = reqwest::Client::new();
+    let mut announced = None;
+    loop {
+        match latest_release(&http).await {
+            Ok(release) => {
+                let latest = release.tag_name.trim_start_matches('v');
+                if is_newer(latest, build_info::VERSION) && announced.as_deref() != Some(latest) {
+                    info!(latest, url = %release.html_url, "a newer levents-daemon is available");
+                    bus.publish(Event::new(
+                        EventKind::Diagnostic,
+                        timestamp_ms(),
+                        EventPayload::Diagnostic(DiagnosticEvent {
+                            source: "update".to_string(),
+                            message: format!(
+                                "levents-daemon {latest} is available (running {}): {}",
+                                build_info::VERSION,
+                                release.html_url
+                            ),
+                        }),
+                    ));
+                    announced = Some(latest.to_string());
+                } else {
+                    debug!(latest, "levents-daemon is up to date");
+                }
+            }
+            Err(error) => warn!(?error, "update check failed"),
+        }
+        tokio::time::sleep(CHECK_INTERVAL).await;
+    }
+}
+
+async fn latest_release(http: &reqwest::Client) -> Result<Release> {
+    http.get(RELEASES_URL)
+        // GitHub rejects API requests without a user agent.
+        .header(
+            reqwest::header::USER_AGENT,
+            format!("levents-daemon/{}", build_info::VERSION),
+        )
+        .send()
+        .await
+        .context("request latest release")?
+        .error_for_status()?
+        .json()
+        .await
+        .context("decode latest release")
+}
+
+/// Whether dotted version `candidate` is newer than `current`; pre-release and build suffixes
+/// are ignored.
+fn is_newer(candidate: &str, current: &str) -> bool {
+    fn parts(version: &str) -> Vec<u64> {
+        version
+            .split(['-', '+'])
+            .next()
+            .unwrap_or_default()
+            .split('.')
+            .map(|part| part.parse().unwrap_or(0))
+            .collect()
+    }
+    let (candidate, current) = (parts(candidate), parts(current));
+    let len = candidate.len().max(current.len());
+    let at = |parts: &[u64], index: usize| parts.get(index).copied().unwrap_or(0);
+    (0..len)
+        .map(|index| at(&candidate, index).cmp(&at(&current, index)))
+        .find(|ordering| ordering.is_ne())
+        .is_some_and(|ordering| ordering.is_gt())
+}
+
+fn timestamp_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn versions_compare_numerically() {
+        assert!(is_newer("0.10.0", "0.9.3"));
+        assert!(is_newer("1.0", "0.99.99"));
+        assert!(is_newer("0.1.1-rc.1", "0.1.0"));
+        assert!(!is_newer("0.1.0", "0.1.0"));
+        assert!(!is_newer("0.1", "0.1.0"));
+        assert!(!is_newer("0.0.9", "0.1.0"));
+    }
+}
@@ -0,0 +1,231 @@
+//! Runs the real gRPC frontend against a scripted Live Client server and checks the exact
+//! event sequence a subscriber receives.
+
+use std::{sync::Arc, time::Duration};
+
+use levents_core::{DaemonConfig, LiveDaemon};
+use levents_daemon::grpc::{
+    self,
+    pb::{
+        event::Payload, event_service_client::EventServiceClient, Event, EventKind,
+        GameSessionRequest, SubscribeRequest,
+    },
+    ServeOptions,
+};
+use serde_json::{json, Value};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+    sync::Mutex,
+    time::timeout,
+};
+use tonic::Streaming;
+
+/// What the mock Live Client API currently reports.
+#[derive(Default)]
+struct Game {
+    players: Vec<Value>,
+    events: Vec<Value>,
+    game_time: f64,
+}
+
+/// Serve `/liveclientdata/*` from `game` over plain HTTP, one request per connection.
+async fn spawn_live_client(game: Arc<Mutex<Game>>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        loop {
+            let Ok((mut socket, _)) = listener.accept().await else {
+                return;
+            };
+            let game = game.clone();
+            tokio::spawn(async move {
+                let mut request = Vec::new();
+                let mut buf = [0; 1024];
+                while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+                    match socket.read(&mut buf).await {
+                        Ok(0) | Err(_) => return,
+                        Ok(read) => request.extend_from_slice(&buf[..read]),
+                    }
+                }
+                let request = String::from_utf8_lossy(&request);
+                let path = request.split_whitespace().nth(1).unwrap_or_default();
+
+                let game = game.lock().await;
+                let body = match path {
+                    "/liveclientdata/playerlist" => json!(game.players),
+                    "/liveclientdata/eventdata" => json!({ "Events": game.events }),
+                    "/liveclientdata/gamestats" => json!({ "gameTime": game.game_time }),
+                    "/liveclientdata/activeplayer" if !game.players.is_empty() => json!({
+                        "summonerName": "Alpha",
+                        "abilities": {
+                            "Q": {"abilityLevel": 1},
+                            "W": {"abilityLevel": 0},
+                            "E": {"abilityLevel": 0},
+                            "R": {"abilityLevel": 0}
+                        }
+                    }),
+                    _ => Value::Null,
+                }
+                .to_string();
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            });
+        }
+    });
+    format!("http://{addr}")
+}
+
+fn player(name: &str, team: &str) -> Value {
+    json!({
+        "summonerName": name,
+        "team": team,
+        "level": 1,
+        "currentGold": 500.0,
+        "isDead": false,
+        "scores": {"creepScore": 0},
+        "items": []
+    })
+}
+
+fn raw_event(id: u64, name: &str, time: f64, extra: Value) -> Value {
+    let mut event = json!({ "EventID": id, "EventName": name, "EventTime": time });
+    event
+        .as_object_mut()
+        .unwrap()
+        .extend(extra.as_object().unwrap().clone());
+    event
+}
+
+async fn next_event(stream: &mut Streaming<Event>) -> Event {
+    timeout(Duration::from_secs(5), stream.message())
+        .await
+        .expect("timed out waiting for an event")
+        .expect("stream error")
+        .expect("stream ended")
+}
+
+/// Receive events until one of `kind` arrives, returning the kinds seen on the way.
+async fn kinds_until(stream: &mut Streaming<Event>, kind: EventKind) -> Vec<EventKind> {
+    let mut kinds = Vec::new();
+    loop {
+        let event = next_event(stream).await;
+        let received = event.kind();
+        kinds.push(received);
+        if received == kind {
+            return kinds;
+        }
+    }
+}
+
+#[tokio::test]
+async fn scripted_game_reaches_grpc_subscribers_in_order() {
+    let game = Arc::new(Mutex::new(Game::default()));
+    let live_base_url = spawn_live_client(game.clone()).await;
+
+    let mut config = DaemonConfig::default();
+    config.live_base_url = live_base_url;
+    config.lcu_lockfile = Some(std::env::temp_dir().join("levents-e2e-missing-lockfile"));
+    config.economy_snapshot_interval = None;
+    config.poll_interval_combat = Duration::from_millis(20);
+    config.poll_interval_normal = Duration::from_millis(20);
+    config.poll_interval_idle = Duration::from_millis(20);
+    config.error_backoff = Duration::from_millis(20);
+    let daemon = LiveDaemon::new(config);
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(grpc::serve_with_listener(
+        daemon,
+        listener,
+        ServeOptions::default(),
+    ));
+
+    let mut client = timeout(Duration::from_secs(5), async {
+        loop {
+            match EventServiceClient::connect(format!("http://{addr}")).await {
+                Ok(client) => return client,
+                Err(_) => tokio::time::sleep(Duration::from_millis(20)).await,
+            }
+        }
+    })
+    .await
+    .expect("gRPC server did not come up");
+
+    let response = client
+        .subscribe(SubscribeRequest {
+            kinds: vec![
+                EventKind::PhaseChange as i32,
+                EventKind::Kill as i32,
+                EventKind::Death as i32,
+                EventKind::Assist as i32,
+                EventKind::GameEnded as i32,
+            ],
+            ..SubscribeRequest::default()
+        })
+        .await
+        .expect("subscribe");
+    assert!(response.metadata().contains_key("levents-session"));
+    let mut stream = response.into_inner();
+
+    {
+        let mut game = game.lock().await;
+        game.players = vec![
+            player("Alpha", "ORDER"),
+            player("Bravo", "CHAOS"),
+            player("Charlie", "ORDER"),
+        ];
+        game.events = vec![raw_event(0, "GameStart", 0.0, json!({}))];
+        game.game_time = 1.0;
+    }
+    assert_eq!(
+        kinds_until(&mut stream, EventKind::PhaseChange).await,
+        [EventKind::PhaseChange]
+    );
+
+    {
+        let mut game = game.lock().await;
+        game.events.push(raw_event(
+            1,
+            "ChampionKill",
+            95.0,
+            json!({"KillerName": "Alpha", "VictimName": "Bravo", "Assisters": ["Charlie"]}),
+        ));
+        game.game_time = 96.0;
+    }
+    let first = next_event(&mut stream).await;
+    match first.payload {
+        Some(Payload::Player(ref kill)) => {
+            assert_eq!(first.kind(), EventKind::Kill);
+            assert_eq!(kill.player.as_ref().unwrap().summoner_name, "Alpha");
+        }
+        ref other => panic!("expected a kill, got {other:?}"),
+    }
+    let session = client
+        .get_game_session(GameSessionRequest {})
+        .await
+        .expect("game session")
+        .into_inner();
+    assert!(!first.game_id.is_empty());
+    assert_eq!(session.game_id, first.game_id);
+    assert_eq!(
+        kinds_until(&mut stream, EventKind::Assist).await,
+        [EventKind::Death, EventKind::Assist]
+    );
+
+    {
+        let mut game = game.lock().await;
+        game.events
+            .push(raw_event(2, "GameEnd", 1500.0, json!({"Result": "Win"})));
+    }
+    assert_eq!(next_event(&mut stream).await.kind(), EventKind::PhaseChange);
+    let ended = next_event(&mut stream).await;
+    assert_eq!(ended.kind(), EventKind::GameEnded);
+    match ended.payload {
+        Some(Payload::GameResult(result)) => assert_eq!(result.winner(), grpc::pb::Team::Order),
+        other => panic!("expected a game result, got {other:?}"),
+    }
+}
@@ -1,128 +1,22 @@
 //! Shared data structures used across the levents workspace.
-
+//!
+//! The event types themselves live in the `no_std`-friendly `levents-types` crate and are
+//! re-exported here unchanged; this crate adds the JSON schema tooling on top.
+//!
+//! Deserialization is lenient by default so newer producers can add fields without breaking
+//! older consumers. Enabling the `strict` feature rejects unknown fields on every struct that
+//! does not flatten another type, which is how the test suite catches schema drift.
+//!
+//! The `schema` feature (on by default) derives `JsonSchema` for every type and enables the
+//! [`schema`] module; disable default features to drop the `schemars` dependency.
+//!
+//! Recorded events of older schema versions are read back with [`migrate`].
+
+pub mod migrate;
+#[cfg(feature = "schema")]
 pub mod schema;
 
-use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-
-/// Millisecond timestamp sourced from the game client.
-pub type TimestampMs = u64;
-
-/// Identifies a specific player within the current game session.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
-pub struct PlayerRef {
-    pub summoner_name: String,
-    pub team: Team,
-    /// Slot index [0, 4] for teammates, [5, 9] for opponents.
-    pub slot: u8,
-}
-
-/// Teams recognised by the League of Legends client.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
-#[serde(rename_all = "lowercase")]
-pub enum Team {
-    Order,
-    Chaos,
-    Neutral,
-}
-
-/// Top-level event emitted by the daemon.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
-pub struct Event {
-    pub kind: EventKind,
-    pub ts: TimestampMs,
-    #[serde(flatten)]
-    pub payload: EventPayload,
-}
-
-/// Accepted event kinds.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
-#[serde(rename_all = "camelCase")]
-pub enum EventKind {
-    Kill,
-    Death,
-    Assist,
-    LevelUp,
-    SkillLevelUp,
-    ItemAdded,
-    ItemRemoved,
-    GoldDelta,
-    Respawn,
-    PhaseChange,
-    Heartbeat,
-}
-
-/// Event payload variants.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
-#[serde(tag = "payloadKind", content = "data", rename_all = "camelCase")]
-pub enum EventPayload {
-    Player(PlayerEvent),
-    PlayerItem(ItemEvent),
-    PlayerLevel(LevelEvent),
-    PlayerSkillLevel(SkillLevelEvent),
-    PlayerGold(GoldEvent),
-    Phase(PhaseEvent),
-    Heartbeat(HeartbeatEvent),
-    Custom(HashMap<String, serde_json::Value>),
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
-pub struct PlayerEvent {
-    pub player: PlayerRef,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
-pub struct ItemEvent {
-    pub player: PlayerRef,
-    pub item_id: u32,
-    pub item_name: Option<String>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
-pub struct LevelEvent {
-    pub player: PlayerRef,
-    pub level: u8,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
-#[serde(rename_all = "lowercase")]
-pub enum AbilitySlot {
-    Q,
-    W,
-    E,
-    R,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
-pub struct SkillLevelEvent {
-    pub player: PlayerRef,
-    pub ability: AbilitySlot,
-    pub level: u8,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
-pub struct GoldEvent {
-    pub player: PlayerRef,
-    pub delta: i32,
-    pub total: i32,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
-pub struct PhaseEvent {
-    pub phase: String,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
-pub struct HeartbeatEvent {
-    pub seq: u64,
-}
-
-/// Batch of events emitted in a single poll cycle.
-#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
-pub struct EventBatch {
-    pub events: Vec<Event>,
-}
+pub use levents_types::*;
 
 #[cfg(test)]
 mod tests {
@@ -130,24 +24,25 @@ mod tests {
 
     #[test]
     fn serializes_event() {
-        let event = Event {
-            kind: EventKind::Kill,
-            ts: 1234,
-            payload: EventPayload::Player(PlayerEvent {
-                player: PlayerRef {
-                    summoner_name: "Example".into(),
-                    team: Team::Order,
-                    slot: 0,
-                },
+        let mut event = Event::new(
+            EventKind::Kill,
+            1234,
+            EventPayload::Player(PlayerEvent {
+                player: PlayerRef::new("Example", Team::Order, 0),
+                bounty: None,
             }),
-        };
+        );
+        event.game_id = Some(Uuid::nil());
 
         let json = serde_json::to_string(&event).expect("serialize");
         assert!(json.contains("\"kind\":\"kill\""));
+        assert!(json.contains("\"gameId\":"));
         let back: Event = serde_json::from_str(&json).expect("deserialize");
         assert_eq!(back.kind, EventKind::Kill);
+        assert_eq!(back.game_id, Some(Uuid::nil()));
     }
 
+    #[cfg(feature = "schema")]
     #[test]
     fn schema_includes_event_kind_enum() {
         let schema = crate::schema::event_schema();
@@ -157,4 +52,11 @@ mod tests {
             .expect("enum array");
         assert!(enums.iter().any(|value| value == "kill"));
     }
+
+    #[test]
+    fn unknown_player_fields_follow_feature_mode() {
+        let json = r#"{"summoner_name":"Example","team":"order","slot":0,"puuid":"x"}"#;
+        let parsed = serde_json::from_str::<PlayerRef>(json);
+        assert_eq!(parsed.is_err(), cfg!(feature = "strict"));
+    }
 }
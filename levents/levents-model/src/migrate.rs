@@ -0,0 +1,167 @@
+//! Upgrading events recorded with an older schema.
+//!
+//! JSONL archives outlive the daemon that wrote them. Kind renames are covered by
+//! [`crate::KIND_ALIASES`]; every other change to the shape of an event adds a step to
+//! [`MIGRATIONS`] that rewrites the JSON of the previous version. Reading recorded events
+//! through [`event_from_str`] or [`batch_from_str`] applies the steps they are missing before
+//! deserializing them.
+
+use serde_json::{Map, Value};
+
+use crate::{Event, EventBatch, SCHEMA_VERSION};
+
+/// Rewrites the JSON of an event from schema version `from` to `from + 1`.
+#[derive(Debug, Clone, Copy)]
+pub struct Migration {
+    /// Version the step upgrades from.
+    pub from: u32,
+    /// Applied in place to the event object; payload fields are under `data`.
+    pub apply: fn(&mut Map<String, Value>),
+}
+
+/// Every migration step, oldest first. Empty until the first payload change.
+pub const MIGRATIONS: &[Migration] = &[];
+
+/// Upgrade the JSON of one event to [`SCHEMA_VERSION`] in place.
+///
+/// Events without a `schemaVersion` are version 1. Events from a newer schema are left as
+/// they are; deserializing them ignores the fields this build does not know.
+pub fn upgrade_event(value: &mut Value) {
+    upgrade(MIGRATIONS, SCHEMA_VERSION, value);
+}
+
+/// Upgrade the JSON of a batch and every event in it; events without their own
+/// `schemaVersion` take the batch's.
+pub fn upgrade_batch(value: &mut Value) {
+    upgrade_batch_with(MIGRATIONS, SCHEMA_VERSION, value);
+}
+
+/// Deserialize an event of any earlier schema version.
+pub fn event_from_value(mut value: Value) -> serde_json::Result<Event> {
+    upgrade_event(&mut value);
+    serde_json::from_value(value)
+}
+
+/// Deserialize an event of any earlier schema version, e.g. a line of a JSONL archive.
+pub fn event_from_str(json: &str) -> serde_json::Result<Event> {
+    event_from_value(serde_json::from_str(json)?)
+}
+
+/// Deserialize a batch of any earlier schema version.
+pub fn batch_from_value(mut value: Value) -> serde_json::Result<EventBatch> {
+    upgrade_batch(&mut value);
+    serde_json::from_value(value)
+}
+
+/// Deserialize a batch of any earlier schema version.
+pub fn batch_from_str(json: &str) -> serde_json::Result<EventBatch> {
+    batch_from_value(serde_json::from_str(json)?)
+}
+
+fn schema_version(object: &Map<String, Value>) -> u32 {
+    match object.get("schemaVersion").and_then(Value::as_u64) {
+        Some(version) => u32::try_from(version).unwrap_or(u32::MAX),
+        None => 1,
+    }
+}
+
+fn upgrade(migrations: &[Migration], target: u32, value: &mut Value) {
+    let Some(object) = value.as_object_mut() else {
+        return;
+    };
+    let version = schema_version(object);
+    if version >= target {
+        return;
+    }
+    for step in migrations
+        .iter()
+        .filter(|step| (version..target).contains(&step.from))
+    {
+        (step.apply)(object);
+    }
+    object.insert("schemaVersion".to_string(), target.into());
+}
+
+fn upgrade_batch_with(migrations: &[Migration], target: u32, value: &mut Value) {
+    let Some(object) = value.as_object_mut() else {
+        return;
+    };
+    let version = schema_version(object);
+    if let Some(Value::Array(events)) = object.get_mut("events") {
+        for event in events {
+            if let Some(event) = event.as_object_mut() {
+                event
+                    .entry("schemaVersion")
+                    .or_insert_with(|| version.into());
+            }
+            upgrade(migrations, target, event);
+        }
+    }
+    if version < target {
+        object.insert("schemaVersion".to_string(), target.into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EventKind, EventPayload};
+    use serde_json::json;
+
+    /// Version 2 renamed `gold` to `goldEarned`; version 3 added `currency`.
+    const STEPS: &[Migration] = &[
+        Migration {
+            from: 1,
+            apply: |event| {
+                if let Some(gold) = event.remove("gold") {
+                    event.insert("goldEarned".to_string(), gold);
+                }
+            },
+        },
+        Migration {
+            from: 2,
+            apply: |event| {
+                event.insert("currency".to_string(), "gold".into());
+            },
+        },
+    ];
+
+    #[test]
+    fn old_events_get_every_missing_step() {
+        let mut legacy = json!({"kind": "goldDelta", "gold": 300});
+        upgrade(STEPS, 3, &mut legacy);
+        assert_eq!(
+            legacy,
+            json!({"kind": "goldDelta", "goldEarned": 300, "currency": "gold", "schemaVersion": 3})
+        );
+
+        let mut batch = json!({
+            "schemaVersion": 2,
+            "events": [{"goldEarned": 50}, {"schemaVersion": 3, "currency": "gold"}],
+        });
+        upgrade_batch_with(STEPS, 3, &mut batch);
+        assert_eq!(batch["schemaVersion"], 3);
+        assert_eq!(batch["events"][0]["currency"], "gold");
+        assert_eq!(
+            batch["events"][1],
+            json!({"schemaVersion": 3, "currency": "gold"})
+        );
+
+        let mut newer = json!({"schemaVersion": 9, "gold": 1});
+        upgrade(STEPS, 3, &mut newer);
+        assert_eq!(newer, json!({"schemaVersion": 9, "gold": 1}));
+    }
+
+    #[test]
+    fn archived_events_without_a_version_decode() {
+        let line = r#"{"kind":"phaseChange","ts":1000,"payloadKind":"phase","data":{"phase":"InProgress"}}"#;
+        let event = event_from_str(line).expect("archived event");
+        assert_eq!(event.schema_version, SCHEMA_VERSION);
+        assert_eq!(event.kind, EventKind::PhaseChange);
+        assert!(matches!(event.payload, EventPayload::Phase(_)));
+
+        let batch = batch_from_str(&format!(r#"{{"events":[{line}]}}"#)).expect("archived batch");
+        assert_eq!(batch.schema_version, SCHEMA_VERSION);
+        assert_eq!(batch.events[0].schema_version, SCHEMA_VERSION);
+    }
+}
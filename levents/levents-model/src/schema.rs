@@ -1,4 +1,4 @@
-use crate::{Event, EventBatch};
+use crate::{Event, EventBatch, TAXONOMY};
 use schemars::{schema::RootSchema, schema_for};
 use std::fs::File;
 use std::io;
@@ -14,6 +14,14 @@ pub fn event_batch_schema() -> RootSchema {
     schema_for!(EventBatch)
 }
 
+/// Return the event kind taxonomy (see [`TAXONOMY`]) as JSON.
+pub fn taxonomy() -> serde_json::Value {
+    serde_json::json!({
+        "schemaVersion": crate::SCHEMA_VERSION,
+        "kinds": TAXONOMY,
+    })
+}
+
 /// Write the [`EventBatch`] schema to the provided filesystem path.
 ///
 /// The parent directories are created automatically if missing.
@@ -24,6 +32,53 @@ pub fn write_event_batch_schema(path: impl AsRef<Path>) -> io::Result<()> {
     }
 
     let file = File::create(path)?;
-    serde_json::to_writer_pretty(file, &event_batch_schema())
-        .map_err(|error| io::Error::new(io::ErrorKind::Other, error))
+    serde_json::to_writer_pretty(file, &event_batch_schema()).map_err(io::Error::other)
+}
+
+/// Write the event kind taxonomy to the provided filesystem path, creating parent directories.
+pub fn write_taxonomy(path: impl AsRef<Path>) -> io::Result<()> {
+    let path = path.as_ref();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let file = File::create(path)?;
+    serde_json::to_writer_pretty(file, &taxonomy()).map_err(io::Error::other)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EventKind;
+
+    #[test]
+    fn taxonomy_lists_every_event_kind() {
+        let schema = serde_json::to_value(schema_for!(EventKind)).unwrap();
+        let kinds: Vec<&str> = schema["enum"]
+            .as_array()
+            .expect("EventKind is a plain string enum")
+            .iter()
+            .filter_map(|kind| kind.as_str())
+            .collect();
+        let names: Vec<&str> = TAXONOMY.iter().map(|info| info.name).collect();
+        assert_eq!(names, kinds);
+
+        for info in TAXONOMY {
+            assert_eq!(serde_json::to_value(&info.kind).unwrap(), info.name);
+            assert_eq!(info.kind.info(), info);
+        }
+    }
+
+    #[test]
+    fn committed_taxonomy_is_current() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/../../bindings/taxonomy.json");
+        let committed: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(path).expect("bindings/taxonomy.json"))
+                .expect("taxonomy JSON");
+        assert_eq!(
+            committed,
+            taxonomy(),
+            "regenerate with `cargo run -p levents-model --bin event-taxonomy -- ../bindings/taxonomy.json`"
+        );
+    }
 }
@@ -1,11 +1,12 @@
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let protoc = protoc_bin_vendored::protoc_bin_path()
-        .expect("failed to locate vendored protoc binary");
+    let protoc =
+        protoc_bin_vendored::protoc_bin_path().expect("failed to locate vendored protoc binary");
     std::env::set_var("PROTOC", protoc);
 
     tonic_build::configure()
         .build_server(true)
-        .build_client(false)
+        .build_client(true)
+        .bytes([".levents.v1.CustomEvent.json"])
         .compile(&["proto/events.proto"], &["proto"])?;
 
     println!("cargo:rerun-if-changed=proto/events.proto");
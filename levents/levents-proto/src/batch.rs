@@ -0,0 +1,94 @@
+//! Conversion of event batches for the per-subscriber streams.
+//!
+//! Converting one event at a time allocates a fresh `String` for every custom payload. A burst
+//! of events (a teamfight, a reconnect backfill) is instead converted in one go, with custom
+//! payloads written into a shared buffer and handed out as [`bytes::Bytes`] slices of it.
+
+use bytes::BytesMut;
+use levents_model::Event;
+
+use crate::convert::encode_event;
+use crate::pb;
+
+/// Initial size of the custom payload buffer; grown as needed.
+const SCRATCH_CAPACITY: usize = 4 * 1024;
+
+/// Converts events to protobuf, reusing its buffers from one batch to the next.
+#[derive(Debug)]
+pub struct BatchConverter {
+    scratch: BytesMut,
+    converted: Vec<pb::Event>,
+}
+
+impl Default for BatchConverter {
+    fn default() -> Self {
+        Self {
+            scratch: BytesMut::with_capacity(SCRATCH_CAPACITY),
+            converted: Vec::new(),
+        }
+    }
+}
+
+impl BatchConverter {
+    /// Convert one event; same as `pb::Event::try_from`, without allocating per custom payload.
+    pub fn convert(&mut self, event: Event) -> Result<pb::Event, serde_json::Error> {
+        encode_event(event, &mut self.scratch)
+    }
+
+    /// Convert `events` in order. Events whose custom payload fails to serialize are passed to
+    /// `on_error` and left out of the batch.
+    pub fn convert_batch(
+        &mut self,
+        events: impl IntoIterator<Item = Event>,
+        mut on_error: impl FnMut(serde_json::Error),
+    ) -> std::vec::Drain<'_, pb::Event> {
+        self.converted.clear();
+        for event in events {
+            match encode_event(event, &mut self.scratch) {
+                Ok(proto) => self.converted.push(proto),
+                Err(error) => on_error(error),
+            }
+        }
+        self.converted.drain(..)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use levents_model::{EventKind, EventPayload, PhaseEvent};
+    use serde_json::json;
+
+    #[test]
+    fn batches_match_single_conversions() {
+        let custom = |value: u32| {
+            let payload = [("value".to_string(), json!(value))].into_iter().collect();
+            Event::new(EventKind::PhaseChange, 1_000, EventPayload::Custom(payload))
+        };
+        let events = vec![
+            custom(1),
+            Event::new(
+                EventKind::PhaseChange,
+                2_000,
+                EventPayload::Phase(PhaseEvent {
+                    phase: "GameStart".to_string(),
+                }),
+            ),
+            custom(2),
+        ];
+
+        let mut converter = BatchConverter::default();
+        let batch: Vec<_> = converter
+            .convert_batch(events.clone(), |error| panic!("{error}"))
+            .collect();
+        let single: Vec<_> = events
+            .into_iter()
+            .map(|event| pb::Event::try_from(event).unwrap())
+            .collect();
+        assert_eq!(batch, single);
+        assert!(matches!(
+            &batch[2].payload,
+            Some(pb::event::Payload::Custom(custom)) if custom.json.as_ref() == br#"{"value":2}"#
+        ));
+    }
+}
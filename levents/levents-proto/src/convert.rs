@@ -0,0 +1,1384 @@
+//! Conversions between the model types and their protobuf counterparts.
+
+use anyhow::{bail, Context, Result};
+use bytes::{BufMut, BytesMut};
+use levents_model::{
+    AbilitySlot, ActiveRunesEvent, BountyEvent, DiagnosticEvent, DragonEvent, EconomyEvent, Event,
+    EventKind, EventPayload, EventSource, FarmEvent, FriendPresenceEvent, GameMetadataEvent,
+    GameResultEvent, GameflowSessionEvent, GoldEvent, HeartbeatEvent, ItemEvent, KillDetailEvent,
+    KillType, Lane, LaneAssignmentEvent, LevelEvent, LobbyChatEvent, Objective, ObjectiveKillEvent,
+    PhaseEvent, PickTimerWarningEvent, PlayerEvent, PlayerIdentity, PlayerRef, QueueEvent,
+    ResourceChangedEvent, ResourceEvent, RunePageEvent, ScoreAdjustmentEvent, ScoreEvent,
+    SeriesInfo, SessionMember, SkillLevelEvent, SkinSelectedEvent, SpecialKillEvent,
+    StructureEvent, StructureType, SurrenderVoteEvent, Team, TeamInfoEvent, TeamMetadata,
+    TeamWipeEvent, TurretTier, WinProbabilityEvent,
+};
+
+use crate::pb::{
+    self, event::Payload as EventPayloadProto, Event as EventProto, EventKind as EventKindProto,
+    Team as TeamProto,
+};
+
+/// Fails only when a custom payload cannot be serialized to JSON.
+impl TryFrom<Event> for EventProto {
+    type Error = serde_json::Error;
+
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        encode_event(event, &mut BytesMut::new())
+    }
+}
+
+/// Convert `event`, writing a custom payload's JSON into `scratch`.
+///
+/// The JSON is split off `scratch` rather than copied, so a buffer reused across events only
+/// allocates again once its spare capacity runs out.
+pub(crate) fn encode_event(
+    event: Event,
+    scratch: &mut BytesMut,
+) -> Result<EventProto, serde_json::Error> {
+    let payload = match event.payload {
+        EventPayload::Player(inner) => Some(EventPayloadProto::Player(pb::PlayerEvent {
+            player: Some(convert_player_ref(inner.player)),
+            bounty: inner.bounty,
+        })),
+        EventPayload::PlayerItem(inner) => Some(EventPayloadProto::PlayerItem(pb::ItemEvent {
+            player: Some(convert_player_ref(inner.player)),
+            item_id: inner.item_id,
+            item_name: inner.item_name,
+            slot: inner.slot.map(u32::from),
+            count: inner.count,
+            previous_slot: inner.previous_slot.map(u32::from),
+            price: inner.price,
+        })),
+        EventPayload::PlayerLevel(inner) => Some(EventPayloadProto::PlayerLevel(pb::LevelEvent {
+            player: Some(convert_player_ref(inner.player)),
+            level: inner.level as u32,
+        })),
+        EventPayload::PlayerSkillLevel(inner) => {
+            Some(EventPayloadProto::PlayerSkillLevel(pb::SkillLevelEvent {
+                player: Some(convert_player_ref(inner.player)),
+                ability: map_ability(inner.ability) as i32,
+                level: inner.level as u32,
+            }))
+        }
+        EventPayload::PlayerGold(inner) => Some(EventPayloadProto::PlayerGold(pb::GoldEvent {
+            player: Some(convert_player_ref(inner.player)),
+            delta: inner.delta,
+            total: inner.total,
+        })),
+        EventPayload::PlayerScore(inner) => Some(EventPayloadProto::PlayerScore(pb::ScoreEvent {
+            player: Some(convert_player_ref(inner.player)),
+            delta: inner.delta,
+            total: inner.total,
+        })),
+        EventPayload::ActiveRunes(inner) => {
+            Some(EventPayloadProto::ActiveRunes(pb::ActiveRunesEvent {
+                player: Some(convert_player_ref(inner.player)),
+                primary_style_id: inner.primary_style_id,
+                sub_style_id: inner.sub_style_id,
+                perk_ids: inner.perk_ids,
+            }))
+        }
+        EventPayload::Resource(inner) => Some(EventPayloadProto::Resource(pb::ResourceEvent {
+            player: Some(convert_player_ref(inner.player)),
+            resource: inner.resource,
+        })),
+        EventPayload::ResourceChanged(inner) => Some(EventPayloadProto::ResourceChanged(
+            pb::ResourceChangedEvent {
+                player: Some(convert_player_ref(inner.player)),
+                health: inner.health,
+                max_health: inner.max_health,
+                resource: inner.resource,
+                max_resource: inner.max_resource,
+                low_health: inner.low_health,
+            },
+        )),
+        EventPayload::GameMetadata(inner) => {
+            Some(EventPayloadProto::GameMetadata(pb::GameMetadataEvent {
+                game_mode: inner.game_mode,
+                map_number: inner.map_number,
+                map_name: inner.map_name,
+                map_terrain: inner.map_terrain.unwrap_or_default(),
+                game_time_ms: inner.game_time_ms,
+            }))
+        }
+        EventPayload::PlayerEconomy(inner) => {
+            Some(EventPayloadProto::PlayerEconomy(pb::EconomyEvent {
+                player: Some(convert_player_ref(inner.player)),
+                earned: inner.earned,
+                spent: inner.spent,
+                current: inner.current,
+            }))
+        }
+        EventPayload::WinProbability(inner) => {
+            Some(EventPayloadProto::WinProbability(pb::WinProbabilityEvent {
+                order: inner.order,
+                chaos: inner.chaos,
+            }))
+        }
+        EventPayload::LaneAssignment(inner) => {
+            Some(EventPayloadProto::LaneAssignment(pb::LaneAssignmentEvent {
+                players: inner.players.into_iter().map(convert_player_ref).collect(),
+            }))
+        }
+        EventPayload::PlayerBounty(inner) => {
+            Some(EventPayloadProto::PlayerBounty(pb::BountyEvent {
+                player: Some(convert_player_ref(inner.player)),
+                bounty: inner.bounty,
+                kill_streak: inner.kill_streak,
+                death_streak: inner.death_streak,
+            }))
+        }
+        EventPayload::Dragon(inner) => Some(EventPayloadProto::Dragon(pb::DragonEvent {
+            team: map_team(inner.team) as i32,
+            dragon_type: inner.dragon_type,
+            soul_points: inner.soul_points,
+            soul_type: inner.soul_type,
+            expires_at: inner.expires_at,
+        })),
+        EventPayload::TeamWipe(inner) => Some(EventPayloadProto::TeamWipe(pb::TeamWipeEvent {
+            team: map_team(inner.team) as i32,
+            duration_estimate_ms: inner.duration_estimate_ms,
+        })),
+        EventPayload::PlayerFarm(inner) => Some(EventPayloadProto::PlayerFarm(pb::FarmEvent {
+            player: Some(convert_player_ref(inner.player)),
+            creep_score: inner.creep_score,
+            cs_per_minute: inner.cs_per_minute,
+            cs_last_minute: inner.cs_last_minute,
+            jungle_efficiency: inner.jungle_efficiency,
+        })),
+        EventPayload::TeamInfo(inner) => Some(EventPayloadProto::TeamInfo(pb::TeamInfoEvent {
+            order: inner.order.map(convert_team_metadata),
+            chaos: inner.chaos.map(convert_team_metadata),
+            series: inner.series.map(convert_series),
+        })),
+        EventPayload::GameResult(inner) => {
+            Some(EventPayloadProto::GameResult(pb::GameResultEvent {
+                winner: inner.winner.map(map_team).unwrap_or(TeamProto::Unspecified) as i32,
+                series: inner.series.map(convert_series),
+            }))
+        }
+        EventPayload::ScoreAdjustment(inner) => Some(EventPayloadProto::ScoreAdjustment(
+            pb::ScoreAdjustmentEvent {
+                team: map_team(inner.team) as i32,
+                kills: inner.kills,
+            },
+        )),
+        EventPayload::Phase(inner) => Some(EventPayloadProto::Phase(pb::PhaseEvent {
+            phase: inner.phase,
+        })),
+        EventPayload::Heartbeat(inner) => Some(EventPayloadProto::Heartbeat(pb::HeartbeatEvent {
+            seq: inner.seq,
+            uptime_ms: inner.uptime_ms,
+            sources_connected: inner.sources_connected,
+            sources_disabled: inner.sources_disabled,
+            last_poll_latency_ms: inner.last_poll_latency_ms,
+            dropped_events: inner.dropped_events,
+            over_budget_polls: inner.over_budget_polls,
+            version: inner.version,
+        })),
+        EventPayload::Diagnostic(inner) => {
+            Some(EventPayloadProto::Diagnostic(pb::DiagnosticEvent {
+                source: inner.source,
+                message: inner.message,
+            }))
+        }
+        EventPayload::SurrenderVote(inner) => {
+            Some(EventPayloadProto::SurrenderVote(pb::SurrenderVoteEvent {
+                team: inner.team.map(map_team).unwrap_or(TeamProto::Unspecified) as i32,
+                remake: inner.remake,
+                votes_for: inner.votes_for,
+                votes_against: inner.votes_against,
+            }))
+        }
+        EventPayload::LobbyChat(inner) => Some(EventPayloadProto::LobbyChat(pb::LobbyChatEvent {
+            channel: inner.channel,
+            sender: inner.sender,
+            body: inner.body,
+        })),
+        EventPayload::RunePage(inner) => Some(EventPayloadProto::RunePage(pb::RunePageEvent {
+            name: inner.name,
+            primary_style_id: inner.primary_style_id,
+            sub_style_id: inner.sub_style_id,
+            perk_ids: inner.perk_ids,
+        })),
+        EventPayload::SkinSelected(inner) => {
+            Some(EventPayloadProto::SkinSelected(pb::SkinSelectedEvent {
+                cell_id: inner.cell_id,
+                riot_id: inner.riot_id.unwrap_or_default(),
+                champion_id: inner.champion_id,
+                skin_id: inner.skin_id,
+            }))
+        }
+        EventPayload::Queue(inner) => Some(EventPayloadProto::Queue(pb::QueueEvent {
+            time_in_queue_secs: inner.time_in_queue_secs,
+            estimated_queue_secs: inner.estimated_queue_secs,
+        })),
+        EventPayload::FriendPresence(inner) => {
+            Some(EventPayloadProto::FriendPresence(pb::FriendPresenceEvent {
+                riot_id: inner.riot_id,
+                puuid: inner.puuid,
+                availability: inner.availability,
+                game_status: inner.game_status,
+                queue_type: inner.queue_type,
+                champion_id: inner.champion_id,
+            }))
+        }
+        EventPayload::GameflowSession(inner) => Some(EventPayloadProto::GameflowSession(
+            pb::GameflowSessionEvent {
+                phase: inner.phase,
+                game_id: inner.game_id,
+                queue_id: inner.queue_id,
+                queue_type: inner.queue_type,
+                map_id: inner.map_id,
+                map_name: inner.map_name,
+                members: inner
+                    .members
+                    .into_iter()
+                    .map(|member| pb::SessionMember {
+                        puuid: member.puuid,
+                        riot_id: member.riot_id,
+                        team: map_team(member.team) as i32,
+                        champion_id: member.champion_id,
+                    })
+                    .collect(),
+            },
+        )),
+        EventPayload::PickTimerWarning(inner) => Some(EventPayloadProto::PickTimerWarning(
+            pb::PickTimerWarningEvent {
+                action: inner.action,
+                seconds_left: inner.seconds_left,
+                champion_id: inner.champion_id,
+            },
+        )),
+        EventPayload::KillDetail(inner) => {
+            Some(EventPayloadProto::KillDetail(pb::KillDetailEvent {
+                killer: inner.killer.map(convert_player_ref),
+                victim: Some(convert_player_ref(inner.victim)),
+                assisters: inner
+                    .assisters
+                    .into_iter()
+                    .map(convert_player_ref)
+                    .collect(),
+                bounty: inner.bounty,
+            }))
+        }
+        EventPayload::SpecialKill(inner) => {
+            Some(EventPayloadProto::SpecialKill(pb::SpecialKillEvent {
+                killer: Some(convert_player_ref(inner.killer)),
+                victim: inner.victim.map(convert_player_ref),
+                kill_type: map_kill_type(inner.kill_type) as i32,
+                streak: inner.streak,
+            }))
+        }
+        EventPayload::ObjectiveKill(inner) => {
+            Some(EventPayloadProto::ObjectiveKill(pb::ObjectiveKillEvent {
+                objective: map_objective(inner.objective) as i32,
+                dragon_type: inner.dragon_type,
+                stolen: inner.stolen,
+                steal_suspected: inner.steal_suspected,
+                team: inner.team.map(map_team).unwrap_or(TeamProto::Unspecified) as i32,
+                killer: inner.killer.map(convert_player_ref),
+            }))
+        }
+        EventPayload::Structure(inner) => Some(EventPayloadProto::Structure(pb::StructureEvent {
+            structure: match inner.structure {
+                StructureType::Turret => pb::StructureType::Turret,
+                StructureType::Inhibitor => pb::StructureType::Inhibitor,
+            } as i32,
+            id: inner.id,
+            team: map_team(inner.team) as i32,
+            lane: inner.lane.map(map_lane).unwrap_or(pb::Lane::Unspecified) as i32,
+            tier: inner
+                .tier
+                .map(map_turret_tier)
+                .unwrap_or(pb::TurretTier::Unspecified) as i32,
+            killer: inner.killer.map(convert_player_ref),
+        })),
+        EventPayload::Custom(inner) => {
+            serde_json::to_writer((&mut *scratch).writer(), &inner)?;
+            Some(EventPayloadProto::Custom(pb::CustomEvent {
+                json: scratch.split().freeze(),
+            }))
+        }
+        // Payloads added to the model before the proto catches up are sent without a body.
+        _ => None,
+    };
+
+    Ok(EventProto {
+        id: event.id.to_string(),
+        seq: event.seq,
+        signature: Vec::new(),
+        manual: event.manual,
+        stale: event.stale,
+        source: map_source(event.source) as i32,
+        game_id: event.game_id.map(|id| id.to_string()).unwrap_or_default(),
+        kind: EventKindProto::from(&event.kind) as i32,
+        ts: event.ts,
+        payload,
+    })
+}
+
+/// Fails on payloads or enum values this build does not know, and on events without a payload.
+impl TryFrom<EventProto> for Event {
+    type Error = anyhow::Error;
+
+    fn try_from(event: EventProto) -> Result<Self> {
+        let kind = match event.kind() {
+            EventKindProto::Kill => EventKind::Kill,
+            EventKindProto::Death => EventKind::Death,
+            EventKindProto::Assist => EventKind::Assist,
+            EventKindProto::LevelUp => EventKind::LevelUp,
+            EventKindProto::SkillLevelUp => EventKind::SkillLevelUp,
+            EventKindProto::ItemAdded => EventKind::ItemAdded,
+            EventKindProto::ItemRemoved => EventKind::ItemRemoved,
+            EventKindProto::ItemMoved => EventKind::ItemMoved,
+            EventKindProto::ItemStackChanged => EventKind::ItemStackChanged,
+            EventKindProto::GoldDelta => EventKind::GoldDelta,
+            EventKindProto::EconomySnapshot => EventKind::EconomySnapshot,
+            EventKindProto::WinProbability => EventKind::WinProbability,
+            EventKindProto::LaneAssignment => EventKind::LaneAssignment,
+            EventKindProto::BountyUpdated => EventKind::BountyUpdated,
+            EventKindProto::SoulPoint => EventKind::SoulPoint,
+            EventKindProto::SoulTaken => EventKind::SoulTaken,
+            EventKindProto::ElderBuff => EventKind::ElderBuff,
+            EventKindProto::TeamWiped => EventKind::TeamWiped,
+            EventKindProto::FarmRate => EventKind::FarmRate,
+            EventKindProto::TeamInfo => EventKind::TeamInfo,
+            EventKindProto::GameEnded => EventKind::GameEnded,
+            EventKindProto::ScoreAdjusted => EventKind::ScoreAdjusted,
+            EventKindProto::Respawn => EventKind::Respawn,
+            EventKindProto::PhaseChange => EventKind::PhaseChange,
+            EventKindProto::Heartbeat => EventKind::Heartbeat,
+            EventKindProto::Diagnostic => EventKind::Diagnostic,
+            EventKindProto::SurrenderVoteStarted => EventKind::SurrenderVoteStarted,
+            EventKindProto::SurrenderVotePassed => EventKind::SurrenderVotePassed,
+            EventKindProto::SurrenderVoteFailed => EventKind::SurrenderVoteFailed,
+            EventKindProto::Remake => EventKind::Remake,
+            EventKindProto::LobbyChat => EventKind::LobbyChat,
+            EventKindProto::RunePageLocked => EventKind::RunePageLocked,
+            EventKindProto::SkinSelected => EventKind::SkinSelected,
+            EventKindProto::QueueEntered => EventKind::QueueEntered,
+            EventKindProto::QueueTimeUpdate => EventKind::QueueTimeUpdate,
+            EventKindProto::MatchFound => EventKind::MatchFound,
+            EventKindProto::FriendPresence => EventKind::FriendPresence,
+            EventKindProto::GameflowSession => EventKind::GameflowSession,
+            EventKindProto::PickTimerWarning => EventKind::PickTimerWarning,
+            EventKindProto::ChampionKill => EventKind::ChampionKill,
+            EventKindProto::Multikill => EventKind::Multikill,
+            EventKindProto::FirstBlood => EventKind::FirstBlood,
+            EventKindProto::Shutdown => EventKind::Shutdown,
+            EventKindProto::ObjectiveKill => EventKind::ObjectiveKill,
+            EventKindProto::StructureDestroyed => EventKind::StructureDestroyed,
+            EventKindProto::CsDelta => EventKind::CsDelta,
+            EventKindProto::VisionScoreDelta => EventKind::VisionScoreDelta,
+            EventKindProto::ActivePlayerRunes => EventKind::ActivePlayerRunes,
+            EventKindProto::ActivePlayerResource => EventKind::ActivePlayerResource,
+            EventKindProto::GameMetadata => EventKind::GameMetadata,
+            EventKindProto::ResourceChanged => EventKind::ResourceChanged,
+            EventKindProto::Unspecified => bail!("unknown event kind {}", event.kind),
+        };
+
+        let payload = match event.payload.context("event has no payload")? {
+            EventPayloadProto::Player(inner) => EventPayload::Player(PlayerEvent {
+                player: player_ref_from_proto(inner.player)?,
+                bounty: inner.bounty,
+            }),
+            EventPayloadProto::PlayerItem(inner) => EventPayload::PlayerItem(ItemEvent {
+                player: player_ref_from_proto(inner.player)?,
+                item_id: inner.item_id,
+                item_name: inner.item_name,
+                slot: inner.slot.map(u8::try_from).transpose()?,
+                count: inner.count,
+                previous_slot: inner.previous_slot.map(u8::try_from).transpose()?,
+                price: inner.price,
+            }),
+            EventPayloadProto::PlayerLevel(inner) => EventPayload::PlayerLevel(LevelEvent {
+                player: player_ref_from_proto(inner.player)?,
+                level: u8::try_from(inner.level)?,
+            }),
+            EventPayloadProto::PlayerSkillLevel(inner) => {
+                let ability = match inner.ability() {
+                    pb::AbilitySlot::Q => AbilitySlot::Q,
+                    pb::AbilitySlot::W => AbilitySlot::W,
+                    pb::AbilitySlot::E => AbilitySlot::E,
+                    pb::AbilitySlot::R => AbilitySlot::R,
+                    pb::AbilitySlot::Unspecified => bail!("unknown ability slot {}", inner.ability),
+                };
+                EventPayload::PlayerSkillLevel(SkillLevelEvent {
+                    player: player_ref_from_proto(inner.player)?,
+                    ability,
+                    level: u8::try_from(inner.level)?,
+                })
+            }
+            EventPayloadProto::PlayerGold(inner) => EventPayload::PlayerGold(GoldEvent {
+                player: player_ref_from_proto(inner.player)?,
+                delta: inner.delta,
+                total: inner.total,
+            }),
+            EventPayloadProto::PlayerScore(inner) => EventPayload::PlayerScore(ScoreEvent {
+                player: player_ref_from_proto(inner.player)?,
+                delta: inner.delta,
+                total: inner.total,
+            }),
+            EventPayloadProto::ActiveRunes(inner) => EventPayload::ActiveRunes(ActiveRunesEvent {
+                player: player_ref_from_proto(inner.player)?,
+                primary_style_id: inner.primary_style_id,
+                sub_style_id: inner.sub_style_id,
+                perk_ids: inner.perk_ids,
+            }),
+            EventPayloadProto::Resource(inner) => EventPayload::Resource(ResourceEvent {
+                player: player_ref_from_proto(inner.player)?,
+                resource: inner.resource,
+            }),
+            EventPayloadProto::ResourceChanged(inner) => {
+                EventPayload::ResourceChanged(ResourceChangedEvent {
+                    player: player_ref_from_proto(inner.player)?,
+                    health: inner.health,
+                    max_health: inner.max_health,
+                    resource: inner.resource,
+                    max_resource: inner.max_resource,
+                    low_health: inner.low_health,
+                })
+            }
+            EventPayloadProto::GameMetadata(inner) => {
+                EventPayload::GameMetadata(GameMetadataEvent {
+                    game_mode: inner.game_mode,
+                    map_number: inner.map_number,
+                    map_name: inner.map_name,
+                    map_terrain: Some(inner.map_terrain).filter(|terrain| !terrain.is_empty()),
+                    game_time_ms: inner.game_time_ms,
+                })
+            }
+            EventPayloadProto::PlayerEconomy(inner) => EventPayload::PlayerEconomy(EconomyEvent {
+                player: player_ref_from_proto(inner.player)?,
+                earned: inner.earned,
+                spent: inner.spent,
+                current: inner.current,
+            }),
+            EventPayloadProto::WinProbability(inner) => {
+                EventPayload::WinProbability(WinProbabilityEvent {
+                    order: inner.order,
+                    chaos: inner.chaos,
+                })
+            }
+            EventPayloadProto::LaneAssignment(inner) => {
+                EventPayload::LaneAssignment(LaneAssignmentEvent {
+                    players: inner
+                        .players
+                        .into_iter()
+                        .map(|player| player_ref_from_proto(Some(player)))
+                        .collect::<Result<_>>()?,
+                })
+            }
+            EventPayloadProto::PlayerBounty(inner) => EventPayload::PlayerBounty(BountyEvent {
+                player: player_ref_from_proto(inner.player)?,
+                bounty: inner.bounty,
+                kill_streak: inner.kill_streak,
+                death_streak: inner.death_streak,
+            }),
+            EventPayloadProto::Dragon(inner) => EventPayload::Dragon(DragonEvent {
+                team: team_from_proto(inner.team)?,
+                dragon_type: inner.dragon_type,
+                soul_points: inner.soul_points,
+                soul_type: inner.soul_type,
+                expires_at: inner.expires_at,
+            }),
+            EventPayloadProto::TeamWipe(inner) => EventPayload::TeamWipe(TeamWipeEvent {
+                team: team_from_proto(inner.team)?,
+                duration_estimate_ms: inner.duration_estimate_ms,
+            }),
+            EventPayloadProto::PlayerFarm(inner) => EventPayload::PlayerFarm(FarmEvent {
+                player: player_ref_from_proto(inner.player)?,
+                creep_score: inner.creep_score,
+                cs_per_minute: inner.cs_per_minute,
+                cs_last_minute: inner.cs_last_minute,
+                jungle_efficiency: inner.jungle_efficiency,
+            }),
+            EventPayloadProto::TeamInfo(inner) => EventPayload::TeamInfo(TeamInfoEvent {
+                order: inner.order.map(team_metadata_from_proto),
+                chaos: inner.chaos.map(team_metadata_from_proto),
+                series: inner.series.map(series_from_proto),
+            }),
+            EventPayloadProto::GameResult(inner) => EventPayload::GameResult(GameResultEvent {
+                winner: match inner.winner() {
+                    TeamProto::Unspecified => None,
+                    _ => Some(team_from_proto(inner.winner)?),
+                },
+                series: inner.series.map(series_from_proto),
+            }),
+            EventPayloadProto::ScoreAdjustment(inner) => {
+                EventPayload::ScoreAdjustment(ScoreAdjustmentEvent {
+                    team: team_from_proto(inner.team)?,
+                    kills: inner.kills,
+                })
+            }
+            EventPayloadProto::Phase(inner) => {
+                EventPayload::Phase(PhaseEvent { phase: inner.phase })
+            }
+            EventPayloadProto::Heartbeat(inner) => EventPayload::Heartbeat(HeartbeatEvent {
+                seq: inner.seq,
+                uptime_ms: inner.uptime_ms,
+                sources_connected: inner.sources_connected,
+                sources_disabled: inner.sources_disabled,
+                last_poll_latency_ms: inner.last_poll_latency_ms,
+                dropped_events: inner.dropped_events,
+                over_budget_polls: inner.over_budget_polls,
+                version: inner.version,
+            }),
+            EventPayloadProto::Diagnostic(inner) => EventPayload::Diagnostic(DiagnosticEvent {
+                source: inner.source,
+                message: inner.message,
+            }),
+            EventPayloadProto::SurrenderVote(inner) => {
+                EventPayload::SurrenderVote(SurrenderVoteEvent {
+                    team: match inner.team() {
+                        TeamProto::Unspecified => None,
+                        _ => Some(team_from_proto(inner.team)?),
+                    },
+                    remake: inner.remake,
+                    votes_for: inner.votes_for,
+                    votes_against: inner.votes_against,
+                })
+            }
+            EventPayloadProto::LobbyChat(inner) => EventPayload::LobbyChat(LobbyChatEvent {
+                channel: inner.channel,
+                sender: inner.sender,
+                body: inner.body,
+            }),
+            EventPayloadProto::RunePage(inner) => EventPayload::RunePage(RunePageEvent {
+                name: inner.name,
+                primary_style_id: inner.primary_style_id,
+                sub_style_id: inner.sub_style_id,
+                perk_ids: inner.perk_ids,
+            }),
+            EventPayloadProto::SkinSelected(inner) => {
+                EventPayload::SkinSelected(SkinSelectedEvent {
+                    cell_id: inner.cell_id,
+                    riot_id: Some(inner.riot_id).filter(|riot_id| !riot_id.is_empty()),
+                    champion_id: inner.champion_id,
+                    skin_id: inner.skin_id,
+                })
+            }
+            EventPayloadProto::Queue(inner) => EventPayload::Queue(QueueEvent {
+                time_in_queue_secs: inner.time_in_queue_secs,
+                estimated_queue_secs: inner.estimated_queue_secs,
+            }),
+            EventPayloadProto::FriendPresence(inner) => {
+                EventPayload::FriendPresence(FriendPresenceEvent {
+                    riot_id: inner.riot_id,
+                    puuid: inner.puuid,
+                    availability: inner.availability,
+                    game_status: inner.game_status,
+                    queue_type: inner.queue_type,
+                    champion_id: inner.champion_id,
+                })
+            }
+            EventPayloadProto::GameflowSession(inner) => {
+                EventPayload::GameflowSession(GameflowSessionEvent {
+                    phase: inner.phase,
+                    game_id: inner.game_id,
+                    queue_id: inner.queue_id,
+                    queue_type: inner.queue_type,
+                    map_id: inner.map_id,
+                    map_name: inner.map_name,
+                    members: inner
+                        .members
+                        .into_iter()
+                        .map(|member| {
+                            Ok(SessionMember {
+                                puuid: member.puuid,
+                                riot_id: member.riot_id,
+                                team: team_from_proto(member.team)?,
+                                champion_id: member.champion_id,
+                            })
+                        })
+                        .collect::<Result<_>>()?,
+                })
+            }
+            EventPayloadProto::PickTimerWarning(inner) => {
+                EventPayload::PickTimerWarning(PickTimerWarningEvent {
+                    action: inner.action,
+                    seconds_left: inner.seconds_left,
+                    champion_id: inner.champion_id,
+                })
+            }
+            EventPayloadProto::KillDetail(inner) => EventPayload::KillDetail(KillDetailEvent {
+                killer: inner
+                    .killer
+                    .map(|killer| player_ref_from_proto(Some(killer)))
+                    .transpose()?,
+                victim: player_ref_from_proto(inner.victim)?,
+                assisters: inner
+                    .assisters
+                    .into_iter()
+                    .map(|player| player_ref_from_proto(Some(player)))
+                    .collect::<Result<_>>()?,
+                bounty: inner.bounty,
+            }),
+            EventPayloadProto::SpecialKill(inner) => {
+                let kill_type = match inner.kill_type() {
+                    pb::KillType::Double => KillType::Double,
+                    pb::KillType::Triple => KillType::Triple,
+                    pb::KillType::Quadra => KillType::Quadra,
+                    pb::KillType::Penta => KillType::Penta,
+                    pb::KillType::FirstBlood => KillType::FirstBlood,
+                    pb::KillType::Shutdown => KillType::Shutdown,
+                    pb::KillType::Unspecified => bail!("unknown kill type {}", inner.kill_type),
+                };
+                EventPayload::SpecialKill(SpecialKillEvent {
+                    killer: player_ref_from_proto(inner.killer)?,
+                    victim: inner
+                        .victim
+                        .map(|victim| player_ref_from_proto(Some(victim)))
+                        .transpose()?,
+                    kill_type,
+                    streak: inner.streak,
+                })
+            }
+            EventPayloadProto::ObjectiveKill(inner) => {
+                let objective = match inner.objective() {
+                    pb::Objective::Dragon => Objective::Dragon,
+                    pb::Objective::Herald => Objective::Herald,
+                    pb::Objective::Baron => Objective::Baron,
+                    pb::Objective::Grubs => Objective::Grubs,
+                    pb::Objective::Unspecified => bail!("unknown objective {}", inner.objective),
+                };
+                let team = match inner.team() {
+                    TeamProto::Unspecified => None,
+                    _ => Some(team_from_proto(inner.team)?),
+                };
+                EventPayload::ObjectiveKill(ObjectiveKillEvent {
+                    objective,
+                    dragon_type: inner.dragon_type,
+                    stolen: inner.stolen,
+                    steal_suspected: inner.steal_suspected,
+                    team,
+                    killer: inner
+                        .killer
+                        .map(|killer| player_ref_from_proto(Some(killer)))
+                        .transpose()?,
+                })
+            }
+            EventPayloadProto::Structure(inner) => {
+                let structure = match inner.structure() {
+                    pb::StructureType::Turret => StructureType::Turret,
+                    pb::StructureType::Inhibitor => StructureType::Inhibitor,
+                    pb::StructureType::Unspecified => {
+                        bail!("unknown structure type {}", inner.structure)
+                    }
+                };
+                let lane = lane_from_proto(inner.lane());
+                let tier = match inner.tier() {
+                    pb::TurretTier::Unspecified => None,
+                    pb::TurretTier::Outer => Some(TurretTier::Outer),
+                    pb::TurretTier::Inner => Some(TurretTier::Inner),
+                    pb::TurretTier::Base => Some(TurretTier::Base),
+                    pb::TurretTier::Nexus => Some(TurretTier::Nexus),
+                };
+                EventPayload::Structure(StructureEvent {
+                    structure,
+                    id: inner.id,
+                    team: team_from_proto(inner.team)?,
+                    lane,
+                    tier,
+                    killer: inner
+                        .killer
+                        .map(|killer| player_ref_from_proto(Some(killer)))
+                        .transpose()?,
+                })
+            }
+            EventPayloadProto::Custom(inner) => EventPayload::Custom(
+                serde_json::from_slice(&inner.json).context("invalid custom event JSON")?,
+            ),
+        };
+
+        Ok(Event {
+            // The proto evolves compatibly on its own, so decoded events are always current.
+            schema_version: levents_model::SCHEMA_VERSION,
+            id: event.id.parse().context("invalid event id")?,
+            seq: event.seq,
+            kind,
+            ts: event.ts,
+            payload,
+            source: source_from_proto(event.source),
+            game_id: Some(event.game_id.as_str())
+                .filter(|id| !id.is_empty())
+                .map(str::parse)
+                .transpose()
+                .context("invalid game id")?,
+            manual: event.manual,
+            stale: event.stale,
+        })
+    }
+}
+
+/// Kinds the proto does not know yet map to `Unspecified`.
+impl From<&EventKind> for EventKindProto {
+    fn from(kind: &EventKind) -> Self {
+        match kind {
+            EventKind::Kill => EventKindProto::Kill,
+            EventKind::Death => EventKindProto::Death,
+            EventKind::Assist => EventKindProto::Assist,
+            EventKind::LevelUp => EventKindProto::LevelUp,
+            EventKind::SkillLevelUp => EventKindProto::SkillLevelUp,
+            EventKind::ItemAdded => EventKindProto::ItemAdded,
+            EventKind::ItemRemoved => EventKindProto::ItemRemoved,
+            EventKind::ItemMoved => EventKindProto::ItemMoved,
+            EventKind::ItemStackChanged => EventKindProto::ItemStackChanged,
+            EventKind::GoldDelta => EventKindProto::GoldDelta,
+            EventKind::EconomySnapshot => EventKindProto::EconomySnapshot,
+            EventKind::WinProbability => EventKindProto::WinProbability,
+            EventKind::LaneAssignment => EventKindProto::LaneAssignment,
+            EventKind::BountyUpdated => EventKindProto::BountyUpdated,
+            EventKind::SoulPoint => EventKindProto::SoulPoint,
+            EventKind::SoulTaken => EventKindProto::SoulTaken,
+            EventKind::ElderBuff => EventKindProto::ElderBuff,
+            EventKind::TeamWiped => EventKindProto::TeamWiped,
+            EventKind::FarmRate => EventKindProto::FarmRate,
+            EventKind::TeamInfo => EventKindProto::TeamInfo,
+            EventKind::GameEnded => EventKindProto::GameEnded,
+            EventKind::ScoreAdjusted => EventKindProto::ScoreAdjusted,
+            EventKind::Respawn => EventKindProto::Respawn,
+            EventKind::PhaseChange => EventKindProto::PhaseChange,
+            EventKind::Heartbeat => EventKindProto::Heartbeat,
+            EventKind::Diagnostic => EventKindProto::Diagnostic,
+            EventKind::SurrenderVoteStarted => EventKindProto::SurrenderVoteStarted,
+            EventKind::SurrenderVotePassed => EventKindProto::SurrenderVotePassed,
+            EventKind::SurrenderVoteFailed => EventKindProto::SurrenderVoteFailed,
+            EventKind::Remake => EventKindProto::Remake,
+            EventKind::LobbyChat => EventKindProto::LobbyChat,
+            EventKind::RunePageLocked => EventKindProto::RunePageLocked,
+            EventKind::SkinSelected => EventKindProto::SkinSelected,
+            EventKind::QueueEntered => EventKindProto::QueueEntered,
+            EventKind::QueueTimeUpdate => EventKindProto::QueueTimeUpdate,
+            EventKind::MatchFound => EventKindProto::MatchFound,
+            EventKind::FriendPresence => EventKindProto::FriendPresence,
+            EventKind::GameflowSession => EventKindProto::GameflowSession,
+            EventKind::PickTimerWarning => EventKindProto::PickTimerWarning,
+            EventKind::ChampionKill => EventKindProto::ChampionKill,
+            EventKind::Multikill => EventKindProto::Multikill,
+            EventKind::FirstBlood => EventKindProto::FirstBlood,
+            EventKind::Shutdown => EventKindProto::Shutdown,
+            EventKind::ObjectiveKill => EventKindProto::ObjectiveKill,
+            EventKind::StructureDestroyed => EventKindProto::StructureDestroyed,
+            EventKind::CsDelta => EventKindProto::CsDelta,
+            EventKind::VisionScoreDelta => EventKindProto::VisionScoreDelta,
+            EventKind::ActivePlayerRunes => EventKindProto::ActivePlayerRunes,
+            EventKind::ActivePlayerResource => EventKindProto::ActivePlayerResource,
+            EventKind::GameMetadata => EventKindProto::GameMetadata,
+            EventKind::ResourceChanged => EventKindProto::ResourceChanged,
+            _ => EventKindProto::Unspecified,
+        }
+    }
+}
+
+fn convert_player_ref(reference: PlayerRef) -> pb::PlayerRef {
+    pb::PlayerRef {
+        summoner_name: reference.summoner_name,
+        team: map_team(reference.team) as i32,
+        slot: reference.slot as u32,
+        lane: reference
+            .lane
+            .map(map_lane)
+            .unwrap_or(pb::Lane::Unspecified) as i32,
+        identity: reference.identity.map(|identity| pb::PlayerIdentity {
+            display_name: identity.display_name,
+            team_tag: identity.team_tag,
+            portrait_url: identity.portrait_url,
+            socials: identity.socials.into_iter().collect(),
+        }),
+        active_player: reference.active_player,
+        riot_id: reference.riot_id,
+        champion_name: reference.champion_name,
+        skin_id: reference.skin_id,
+    }
+}
+
+fn convert_team_metadata(metadata: TeamMetadata) -> pb::TeamMetadata {
+    pb::TeamMetadata {
+        name: metadata.name,
+        tag: metadata.tag,
+        color: metadata.color,
+        logo_url: metadata.logo_url,
+    }
+}
+
+fn convert_series(series: SeriesInfo) -> pb::SeriesInfo {
+    pb::SeriesInfo {
+        best_of: series.best_of,
+        game_number: series.game_number,
+        order_wins: series.order_wins,
+        chaos_wins: series.chaos_wins,
+        sides_swapped: series.sides_swapped,
+    }
+}
+
+fn map_team(team: Team) -> TeamProto {
+    match team {
+        Team::Order => TeamProto::Order,
+        Team::Chaos => TeamProto::Chaos,
+        Team::Neutral => TeamProto::Neutral,
+    }
+}
+
+/// Sources the proto does not know yet map to `Unspecified`.
+fn map_source(source: EventSource) -> pb::EventSource {
+    match source {
+        EventSource::Daemon => pb::EventSource::Daemon,
+        EventSource::LiveClient => pb::EventSource::LiveClient,
+        EventSource::Lcu => pb::EventSource::Lcu,
+        EventSource::Synthetic => pb::EventSource::Synthetic,
+        EventSource::Replay => pb::EventSource::Replay,
+        _ => pb::EventSource::Unspecified,
+    }
+}
+
+fn map_lane(lane: Lane) -> pb::Lane {
+    match lane {
+        Lane::Top => pb::Lane::Top,
+        Lane::Jungle => pb::Lane::Jungle,
+        Lane::Mid => pb::Lane::Mid,
+        Lane::Bottom => pb::Lane::Bottom,
+        Lane::Support => pb::Lane::Support,
+    }
+}
+
+fn map_turret_tier(tier: TurretTier) -> pb::TurretTier {
+    match tier {
+        TurretTier::Outer => pb::TurretTier::Outer,
+        TurretTier::Inner => pb::TurretTier::Inner,
+        TurretTier::Base => pb::TurretTier::Base,
+        TurretTier::Nexus => pb::TurretTier::Nexus,
+    }
+}
+
+fn map_objective(objective: Objective) -> pb::Objective {
+    match objective {
+        Objective::Dragon => pb::Objective::Dragon,
+        Objective::Herald => pb::Objective::Herald,
+        Objective::Baron => pb::Objective::Baron,
+        Objective::Grubs => pb::Objective::Grubs,
+    }
+}
+
+fn map_kill_type(kill_type: KillType) -> pb::KillType {
+    match kill_type {
+        KillType::Double => pb::KillType::Double,
+        KillType::Triple => pb::KillType::Triple,
+        KillType::Quadra => pb::KillType::Quadra,
+        KillType::Penta => pb::KillType::Penta,
+        KillType::FirstBlood => pb::KillType::FirstBlood,
+        KillType::Shutdown => pb::KillType::Shutdown,
+    }
+}
+
+fn map_ability(slot: AbilitySlot) -> pb::AbilitySlot {
+    match slot {
+        AbilitySlot::Q => pb::AbilitySlot::Q,
+        AbilitySlot::W => pb::AbilitySlot::W,
+        AbilitySlot::E => pb::AbilitySlot::E,
+        AbilitySlot::R => pb::AbilitySlot::R,
+    }
+}
+
+fn player_ref_from_proto(reference: Option<pb::PlayerRef>) -> Result<PlayerRef> {
+    let reference = reference.context("event has no player")?;
+    let lane = lane_from_proto(reference.lane());
+    let mut player = PlayerRef::new(
+        reference.summoner_name,
+        team_from_proto(reference.team)?,
+        u8::try_from(reference.slot)?,
+    );
+    player.lane = lane;
+    player.identity = reference.identity.map(|identity| {
+        let mut converted = PlayerIdentity::new(identity.display_name);
+        converted.team_tag = identity.team_tag;
+        converted.portrait_url = identity.portrait_url;
+        converted.socials = identity.socials.into_iter().collect();
+        converted
+    });
+    player.active_player = reference.active_player;
+    player.riot_id = reference.riot_id;
+    player.champion_name = reference.champion_name;
+    player.skin_id = reference.skin_id;
+    Ok(player)
+}
+
+/// Events from daemons that predate sources count as the daemon's own.
+fn source_from_proto(value: i32) -> EventSource {
+    match pb::EventSource::try_from(value) {
+        Ok(pb::EventSource::LiveClient) => EventSource::LiveClient,
+        Ok(pb::EventSource::Lcu) => EventSource::Lcu,
+        Ok(pb::EventSource::Synthetic) => EventSource::Synthetic,
+        Ok(pb::EventSource::Replay) => EventSource::Replay,
+        _ => EventSource::Daemon,
+    }
+}
+
+fn lane_from_proto(lane: pb::Lane) -> Option<Lane> {
+    match lane {
+        pb::Lane::Unspecified => None,
+        pb::Lane::Top => Some(Lane::Top),
+        pb::Lane::Jungle => Some(Lane::Jungle),
+        pb::Lane::Mid => Some(Lane::Mid),
+        pb::Lane::Bottom => Some(Lane::Bottom),
+        pb::Lane::Support => Some(Lane::Support),
+    }
+}
+
+fn team_metadata_from_proto(metadata: pb::TeamMetadata) -> TeamMetadata {
+    TeamMetadata {
+        name: metadata.name,
+        tag: metadata.tag,
+        color: metadata.color,
+        logo_url: metadata.logo_url,
+    }
+}
+
+fn series_from_proto(series: pb::SeriesInfo) -> SeriesInfo {
+    SeriesInfo {
+        best_of: series.best_of,
+        game_number: series.game_number,
+        order_wins: series.order_wins,
+        chaos_wins: series.chaos_wins,
+        sides_swapped: series.sides_swapped,
+    }
+}
+
+fn team_from_proto(value: i32) -> Result<Team> {
+    match TeamProto::try_from(value) {
+        Ok(TeamProto::Order) => Ok(Team::Order),
+        Ok(TeamProto::Chaos) => Ok(Team::Chaos),
+        Ok(TeamProto::Neutral) => Ok(Team::Neutral),
+        _ => bail!("unknown team {value}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message;
+
+    fn player(slot: u8) -> PlayerRef {
+        let team = if slot < 5 { Team::Order } else { Team::Chaos };
+        let mut identity = PlayerIdentity::new(format!("Player {slot}"));
+        identity.team_tag = Some(if slot < 5 { "T1" } else { "GEN" }.to_string());
+        identity.portrait_url = Some(format!("https://cdn.example.com/portraits/{slot}.png"));
+        let mut player = PlayerRef::new(format!("Summoner number {slot}"), team, slot);
+        player.lane = Some(Lane::Mid);
+        player.identity = Some(identity);
+        player.active_player = slot == 0;
+        player.riot_id = Some(format!("Summoner number {slot}#EUW"));
+        player.champion_name = Some("Wukong".to_string());
+        player.skin_id = Some(u32::from(slot));
+        player
+    }
+
+    /// One event per payload variant, with every optional field populated.
+    fn every_payload() -> Vec<Event> {
+        let series = SeriesInfo {
+            best_of: 5,
+            game_number: 3,
+            order_wins: 1,
+            chaos_wins: 1,
+            sides_swapped: true,
+        };
+        let metadata = |name: &str| TeamMetadata {
+            name: name.to_string(),
+            tag: Some(name[..1].to_string()),
+            color: Some("#c89b3c".to_string()),
+            logo_url: Some(format!("https://cdn.example.com/{name}.png")),
+        };
+        let mut identity = player(7);
+        identity.identity.as_mut().unwrap().socials = [
+            ("twitch".to_string(), "player7".to_string()),
+            ("x".to_string(), "@player7".to_string()),
+        ]
+        .into();
+        let custom = serde_json::json!({"note": "pause", "remaining": 90, "nested": {"ok": true}});
+
+        [
+            (
+                EventKind::Kill,
+                EventPayload::Player(PlayerEvent {
+                    player: identity,
+                    bounty: Some(450),
+                }),
+            ),
+            (
+                EventKind::ItemMoved,
+                EventPayload::PlayerItem(ItemEvent {
+                    player: player(1),
+                    item_id: 3031,
+                    item_name: Some("Infinity Edge".to_string()),
+                    slot: Some(4),
+                    count: Some(1),
+                    previous_slot: Some(2),
+                    price: Some(3_400),
+                }),
+            ),
+            (
+                EventKind::LevelUp,
+                EventPayload::PlayerLevel(LevelEvent {
+                    player: player(2),
+                    level: 18,
+                }),
+            ),
+            (
+                EventKind::SkillLevelUp,
+                EventPayload::PlayerSkillLevel(SkillLevelEvent {
+                    player: player(3),
+                    ability: AbilitySlot::R,
+                    level: 3,
+                }),
+            ),
+            (
+                EventKind::GoldDelta,
+                EventPayload::PlayerGold(GoldEvent {
+                    player: player(4),
+                    delta: -3400,
+                    total: 120,
+                }),
+            ),
+            (
+                EventKind::VisionScoreDelta,
+                EventPayload::PlayerScore(ScoreEvent {
+                    player: player(4),
+                    delta: 5.5,
+                    total: 21.25,
+                }),
+            ),
+            (
+                EventKind::ActivePlayerRunes,
+                EventPayload::ActiveRunes(ActiveRunesEvent {
+                    player: player(0),
+                    primary_style_id: 8100,
+                    sub_style_id: 8300,
+                    perk_ids: vec![8112, 8139, 8138, 8135, 8345, 8347, 5008, 5008, 5001],
+                }),
+            ),
+            (
+                EventKind::ActivePlayerResource,
+                EventPayload::Resource(ResourceEvent {
+                    player: player(0),
+                    resource: "ENERGY".to_string(),
+                }),
+            ),
+            (
+                EventKind::ResourceChanged,
+                EventPayload::ResourceChanged(ResourceChangedEvent {
+                    player: player(6),
+                    health: 412.5,
+                    max_health: 1_850.0,
+                    resource: 300.0,
+                    max_resource: 900.0,
+                    low_health: true,
+                }),
+            ),
+            (
+                EventKind::GameMetadata,
+                EventPayload::GameMetadata(GameMetadataEvent {
+                    game_mode: "CLASSIC".to_string(),
+                    map_number: 11,
+                    map_name: "Map11".to_string(),
+                    map_terrain: Some("Infernal".to_string()),
+                    game_time_ms: 845_000,
+                }),
+            ),
+            (
+                EventKind::EconomySnapshot,
+                EventPayload::PlayerEconomy(EconomyEvent {
+                    player: player(5),
+                    earned: 14_000,
+                    spent: 13_200,
+                    current: 800,
+                }),
+            ),
+            (
+                EventKind::WinProbability,
+                EventPayload::WinProbability(WinProbabilityEvent {
+                    order: 0.625,
+                    chaos: 0.375,
+                }),
+            ),
+            (
+                EventKind::LaneAssignment,
+                EventPayload::LaneAssignment(LaneAssignmentEvent {
+                    players: (0..10).map(player).collect(),
+                }),
+            ),
+            (
+                EventKind::BountyUpdated,
+                EventPayload::PlayerBounty(BountyEvent {
+                    player: player(6),
+                    bounty: 700,
+                    kill_streak: 5,
+                    death_streak: 0,
+                }),
+            ),
+            (
+                EventKind::SoulTaken,
+                EventPayload::Dragon(DragonEvent {
+                    team: Team::Chaos,
+                    dragon_type: "Elder".to_string(),
+                    soul_points: 4,
+                    soul_type: Some("Infernal".to_string()),
+                    expires_at: Some(1_950_000),
+                }),
+            ),
+            (
+                EventKind::TeamWiped,
+                EventPayload::TeamWipe(TeamWipeEvent {
+                    team: Team::Order,
+                    duration_estimate_ms: 42_000,
+                }),
+            ),
+            (
+                EventKind::FarmRate,
+                EventPayload::PlayerFarm(FarmEvent {
+                    player: player(8),
+                    creep_score: 212,
+                    cs_per_minute: 8.5,
+                    cs_last_minute: 11,
+                    jungle_efficiency: Some(0.75),
+                }),
+            ),
+            (
+                EventKind::TeamInfo,
+                EventPayload::TeamInfo(TeamInfoEvent {
+                    order: Some(metadata("Order")),
+                    chaos: Some(metadata("Chaos")),
+                    series: Some(series.clone()),
+                }),
+            ),
+            (
+                EventKind::GameEnded,
+                EventPayload::GameResult(GameResultEvent {
+                    winner: Some(Team::Chaos),
+                    series: Some(series),
+                }),
+            ),
+            (
+                EventKind::GameEnded,
+                EventPayload::GameResult(GameResultEvent {
+                    winner: None,
+                    series: None,
+                }),
+            ),
+            (
+                EventKind::ScoreAdjusted,
+                EventPayload::ScoreAdjustment(ScoreAdjustmentEvent {
+                    team: Team::Order,
+                    kills: -1,
+                }),
+            ),
+            (
+                EventKind::PhaseChange,
+                EventPayload::Phase(PhaseEvent {
+                    phase: "BaronKill".to_string(),
+                }),
+            ),
+            (
+                EventKind::Heartbeat,
+                EventPayload::Heartbeat(HeartbeatEvent {
+                    seq: 99,
+                    uptime_ms: Some(12_000),
+                    sources_connected: vec!["liveClient".to_string()],
+                    sources_disabled: vec!["lcu".to_string()],
+                    last_poll_latency_ms: Some(4),
+                    dropped_events: Some(0),
+                    over_budget_polls: Some(2),
+                    version: Some("0.1.0+3f2a9c1d0b4e".to_string()),
+                }),
+            ),
+            (
+                EventKind::Diagnostic,
+                EventPayload::Diagnostic(DiagnosticEvent {
+                    source: "lcu".to_string(),
+                    message: "source stalled; restarted".to_string(),
+                }),
+            ),
+            (
+                EventKind::SurrenderVotePassed,
+                EventPayload::SurrenderVote(SurrenderVoteEvent {
+                    team: Some(Team::Chaos),
+                    remake: true,
+                    votes_for: 4,
+                    votes_against: 1,
+                }),
+            ),
+            (
+                EventKind::SurrenderVoteStarted,
+                EventPayload::SurrenderVote(SurrenderVoteEvent {
+                    team: None,
+                    remake: false,
+                    votes_for: 1,
+                    votes_against: 0,
+                }),
+            ),
+            (
+                EventKind::LobbyChat,
+                EventPayload::LobbyChat(LobbyChatEvent {
+                    channel: "championSelect".to_string(),
+                    sender: "3f2a9c1d".to_string(),
+                    body: "ready up".to_string(),
+                }),
+            ),
+            (
+                EventKind::RunePageLocked,
+                EventPayload::RunePage(RunePageEvent {
+                    name: "Conqueror top".to_string(),
+                    primary_style_id: 8000,
+                    sub_style_id: 8400,
+                    perk_ids: vec![8010, 9111, 9104, 8299, 8444, 8242, 5005, 5008, 5001],
+                }),
+            ),
+            (
+                EventKind::SkinSelected,
+                EventPayload::SkinSelected(SkinSelectedEvent {
+                    cell_id: 2,
+                    riot_id: Some("Faker#KR1".to_string()),
+                    champion_id: 103,
+                    skin_id: 103015,
+                }),
+            ),
+            (
+                EventKind::QueueTimeUpdate,
+                EventPayload::Queue(QueueEvent {
+                    time_in_queue_secs: 42,
+                    estimated_queue_secs: 95,
+                }),
+            ),
+            (
+                EventKind::FriendPresence,
+                EventPayload::FriendPresence(FriendPresenceEvent {
+                    riot_id: "Caps#EUW".to_string(),
+                    puuid: "3f2a9c1d".to_string(),
+                    availability: "dnd".to_string(),
+                    game_status: Some("inGame".to_string()),
+                    queue_type: Some("RANKED_SOLO_5x5".to_string()),
+                    champion_id: Some(7),
+                }),
+            ),
+            (
+                EventKind::GameflowSession,
+                EventPayload::GameflowSession(GameflowSessionEvent {
+                    phase: "ChampSelect".to_string(),
+                    game_id: Some(6543210987),
+                    queue_id: Some(420),
+                    queue_type: Some("RANKED_SOLO_5x5".to_string()),
+                    map_id: Some(11),
+                    map_name: Some("Summoner's Rift".to_string()),
+                    members: vec![SessionMember {
+                        puuid: "a1".to_string(),
+                        riot_id: Some("Faker#KR1".to_string()),
+                        team: Team::Order,
+                        champion_id: Some(7),
+                    }],
+                }),
+            ),
+            (
+                EventKind::PickTimerWarning,
+                EventPayload::PickTimerWarning(PickTimerWarningEvent {
+                    action: "pick".to_string(),
+                    seconds_left: 10,
+                    champion_id: Some(103),
+                }),
+            ),
+            (
+                EventKind::ChampionKill,
+                EventPayload::KillDetail(KillDetailEvent {
+                    killer: Some(player(2)),
+                    victim: player(7),
+                    assisters: vec![player(1), player(4)],
+                    bounty: Some(450),
+                }),
+            ),
+            (
+                EventKind::Multikill,
+                EventPayload::SpecialKill(SpecialKillEvent {
+                    killer: player(3),
+                    victim: None,
+                    kill_type: KillType::Penta,
+                    streak: 5,
+                }),
+            ),
+            (
+                EventKind::Shutdown,
+                EventPayload::SpecialKill(SpecialKillEvent {
+                    killer: player(8),
+                    victim: Some(player(2)),
+                    kill_type: KillType::Shutdown,
+                    streak: 1,
+                }),
+            ),
+            (
+                EventKind::ObjectiveKill,
+                EventPayload::ObjectiveKill(ObjectiveKillEvent {
+                    objective: Objective::Dragon,
+                    dragon_type: Some("Chemtech".to_string()),
+                    stolen: true,
+                    steal_suspected: true,
+                    team: Some(Team::Chaos),
+                    killer: Some(player(6)),
+                }),
+            ),
+            (
+                EventKind::StructureDestroyed,
+                EventPayload::Structure(StructureEvent {
+                    structure: StructureType::Turret,
+                    id: "Turret_T2_L_03_A".to_string(),
+                    team: Team::Chaos,
+                    lane: Some(Lane::Top),
+                    tier: Some(TurretTier::Outer),
+                    killer: Some(player(0)),
+                }),
+            ),
+            (
+                EventKind::PhaseChange,
+                EventPayload::Custom(serde_json::from_value(custom).unwrap()),
+            ),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(index, (kind, payload))| {
+            let mut event = Event::new(kind, 1_000 + index as u64, payload);
+            event.id = levents_model::Uuid::new_v4();
+            event.seq = index as u64 + 1;
+            event.manual = index % 2 == 0;
+            event.stale = index % 3 == 0;
+            event.game_id = (index % 2 == 1).then(levents_model::Uuid::new_v4);
+            event.source = [
+                EventSource::LiveClient,
+                EventSource::Lcu,
+                EventSource::Synthetic,
+            ][index % 3];
+            event
+        })
+        .collect()
+    }
+
+    #[test]
+    fn proto_conversion_round_trips_every_payload() {
+        for event in every_payload() {
+            let bytes = EventProto::try_from(event.clone())
+                .expect("convert")
+                .encode_to_vec();
+            let decoded = EventProto::decode(bytes.as_slice()).expect("decode");
+            let back = Event::try_from(decoded).expect("from proto");
+            assert_eq!(back, event);
+            // JSON consumers see the same event whichever transport it came through.
+            assert_eq!(
+                serde_json::to_value(&back).unwrap(),
+                serde_json::to_value(&event).unwrap()
+            );
+        }
+
+        let missing = EventProto {
+            kind: EventKindProto::Kill as i32,
+            ..EventProto::default()
+        };
+        assert!(Event::try_from(missing).is_err());
+    }
+}
@@ -0,0 +1,17 @@
+//! Protobuf types for the `levents.v1` gRPC API and their mappings to the model.
+//!
+//! The daemon and Rust clients share these conversions so an event survives the trip through
+//! gRPC unchanged: `pb::Event::try_from(event)` on the way out and `Event::try_from(proto)` on
+//! the way back. [`prost`] is re-exported so consumers encode and decode with the same version.
+//! Streams converting many events use a [`BatchConverter`] instead.
+
+mod batch;
+mod convert;
+
+pub use batch::BatchConverter;
+pub use prost;
+
+/// Generated messages, the `EventService` client and its server trait.
+pub mod pb {
+    tonic::include_proto!("levents.v1");
+}
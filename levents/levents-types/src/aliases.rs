@@ -0,0 +1,128 @@
+//! Former names of renamed or split event kinds.
+//!
+//! Recordings, spilled backlogs and older consumers keep using the name a kind had when they
+//! were written, so every rename adds an entry to [`KIND_ALIASES`] instead of breaking them:
+//! the old name keeps decoding, and JSON outputs can write it back for a subscriber that asks
+//! for an older [`SCHEMA_VERSION`].
+
+use crate::EventKind;
+
+/// Version of the event schema produced by this crate; bumped whenever a kind is renamed or
+/// split.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Version of data written before events carried their `schemaVersion`.
+#[cfg(feature = "serde")]
+pub(crate) fn first_schema_version() -> u32 {
+    1
+}
+
+/// A name an event kind had in earlier schema versions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KindAlias {
+    /// The old camelCase name.
+    pub name: &'static str,
+    /// Kind the old name decodes to; for a split kind, the part that kept most of its meaning.
+    pub kind: EventKind,
+    /// Last schema version that used `name`.
+    pub until_schema: u32,
+}
+
+/// Every retired kind name, oldest first. Empty until the first rename.
+pub const KIND_ALIASES: &[KindAlias] = &[];
+
+impl EventKind {
+    /// Kind an old name decodes to, if `name` is a retired alias.
+    pub fn from_alias(name: &str) -> Option<EventKind> {
+        resolve(KIND_ALIASES, name)
+    }
+
+    /// Name a subscriber on schema version `schema` knows this kind by, if it differs from the
+    /// current one.
+    pub fn legacy_name(&self, schema: u32) -> Option<&'static str> {
+        legacy_name(KIND_ALIASES, self, schema)
+    }
+}
+
+fn resolve(aliases: &[KindAlias], name: &str) -> Option<EventKind> {
+    aliases
+        .iter()
+        .find(|alias| alias.name == name)
+        .map(|alias| alias.kind.clone())
+}
+
+fn legacy_name(aliases: &[KindAlias], kind: &EventKind, schema: u32) -> Option<&'static str> {
+    aliases
+        .iter()
+        .filter(|alias| alias.kind == *kind && alias.until_schema >= schema)
+        .min_by_key(|alias| alias.until_schema)
+        .map(|alias| alias.name)
+}
+
+/// Deserialize an [`EventKind`], accepting the retired names in [`KIND_ALIASES`].
+///
+/// [`crate::Event`] uses it for its `kind`; use it with `#[serde(deserialize_with)]` on other
+/// fields that read kinds from old data.
+#[cfg(feature = "serde")]
+pub fn deserialize_kind<'de, D>(deserializer: D) -> Result<EventKind, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    deserialize_with_aliases(KIND_ALIASES, deserializer)
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_with_aliases<'de, D>(
+    aliases: &[KindAlias],
+    deserializer: D,
+) -> Result<EventKind, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::IntoDeserializer;
+    use serde::Deserialize;
+
+    let name = alloc::string::String::deserialize(deserializer)?;
+    match resolve(aliases, &name) {
+        Some(kind) => Ok(kind),
+        None => EventKind::deserialize(name.as_str().into_deserializer()),
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    const ALIASES: &[KindAlias] = &[
+        KindAlias {
+            name: "objectiveTaken",
+            kind: EventKind::ObjectiveKill,
+            until_schema: 1,
+        },
+        KindAlias {
+            name: "objectiveSecured",
+            kind: EventKind::ObjectiveKill,
+            until_schema: 2,
+        },
+    ];
+
+    #[test]
+    fn old_names_decode_and_are_written_for_old_schemas() {
+        let decode = |json: &str| {
+            let mut deserializer = serde_json::Deserializer::from_str(json);
+            deserialize_with_aliases(ALIASES, &mut deserializer)
+        };
+        assert_eq!(
+            decode("\"objectiveTaken\"").unwrap(),
+            EventKind::ObjectiveKill
+        );
+        assert_eq!(decode("\"kill\"").unwrap(), EventKind::Kill);
+        assert!(decode("\"dragonKill\"").is_err());
+
+        let kind = EventKind::ObjectiveKill;
+        assert_eq!(legacy_name(ALIASES, &kind, 1), Some("objectiveTaken"));
+        assert_eq!(legacy_name(ALIASES, &kind, 2), Some("objectiveSecured"));
+        assert_eq!(legacy_name(ALIASES, &kind, 3), None);
+        assert_eq!(legacy_name(ALIASES, &EventKind::Kill, 1), None);
+    }
+}
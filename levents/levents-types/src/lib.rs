@@ -0,0 +1,1149 @@
+//! Plain event data types shared by every levents producer and consumer.
+//!
+//! The crate is `no_std` + `alloc` unless the `std` feature is enabled, so firmware and other
+//! constrained consumers can decode the event feed with the same definitions as the daemon.
+//!
+//! Features:
+//! - `serde` (default): `Serialize`/`Deserialize` derives and the JSON-backed custom payload.
+//! - `std`: link the standard library (implied by `schemars`).
+//! - `schemars`: `JsonSchema` derives used by `levents-model`'s schema generator.
+//! - `strict`: reject unknown fields when deserializing.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+mod aliases;
+mod taxonomy;
+
+#[cfg(feature = "serde")]
+pub use aliases::deserialize_kind;
+pub use aliases::{KindAlias, KIND_ALIASES, SCHEMA_VERSION};
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+pub use taxonomy::{KindCategory, KindInfo, KindPriority, TAXONOMY};
+pub use uuid::Uuid;
+
+/// Millisecond timestamp sourced from the game client.
+pub type TimestampMs = u64;
+
+/// Identifies a specific player within the current game session.
+///
+/// Fields are added as the clients report more about players; build one with
+/// [`PlayerRef::new`] and set the rest.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct PlayerRef {
+    pub summoner_name: String,
+    pub team: Team,
+    /// Slot index [0, 4] for teammates, [5, 9] for opponents.
+    pub slot: u8,
+    /// Inferred lane, available once lanes have been assigned a few minutes into the game.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub lane: Option<Lane>,
+    /// Branded identity from the configured roster, if the player is listed there.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub identity: Option<PlayerIdentity>,
+    /// Whether this is the player the Live Client runs for (the local player, or the one
+    /// being spectated).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub active_player: bool,
+    /// Riot ID (`gameName#tagLine`), which replaces the summoner name; `None` on clients that
+    /// do not report it yet.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub riot_id: Option<String>,
+    /// Champion played, as the client names it in its language, e.g. `Wukong`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub champion_name: Option<String>,
+    /// Skin equipped; 0 is the base skin.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub skin_id: Option<u32>,
+}
+
+impl PlayerRef {
+    /// A player known by summoner name, team and slot only.
+    pub fn new(summoner_name: impl Into<String>, team: Team, slot: u8) -> Self {
+        Self {
+            summoner_name: summoner_name.into(),
+            team,
+            slot,
+            lane: None,
+            identity: None,
+            active_player: false,
+            riot_id: None,
+            champion_name: None,
+            skin_id: None,
+        }
+    }
+
+    /// Whether `name` identifies this player: the Riot ID, its game name or the summoner name.
+    pub fn answers_to(&self, name: &str) -> bool {
+        self.summoner_name == name
+            || self.riot_id.as_deref().is_some_and(|riot_id| {
+                riot_id == name
+                    || riot_id
+                        .split_once('#')
+                        .is_some_and(|(game, _)| game == name)
+            })
+    }
+}
+
+/// Display identity for a player, supplied by the operator's roster rather than the game.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct PlayerIdentity {
+    pub display_name: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub team_tag: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub portrait_url: Option<String>,
+    /// Social handles keyed by network, e.g. `twitter` or `twitch`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub socials: BTreeMap<String, String>,
+}
+
+impl PlayerIdentity {
+    /// An identity with only a display name.
+    pub fn new(display_name: impl Into<String>) -> Self {
+        Self {
+            display_name: display_name.into(),
+            ..Self::default()
+        }
+    }
+}
+
+/// Teams recognised by the League of Legends client.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Team {
+    Order,
+    Chaos,
+    Neutral,
+}
+
+/// Lane a player was inferred to be playing.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Lane {
+    Top,
+    Jungle,
+    Mid,
+    Bottom,
+    Support,
+}
+
+/// Where an event came from, for subscribers of merged streams.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[non_exhaustive]
+pub enum EventSource {
+    /// Issued by the daemon itself: heartbeats, diagnostics and manual corrections.
+    #[default]
+    Daemon,
+    /// Polled from the Live Client Data API.
+    LiveClient,
+    /// Received from the League Client (LCU) websocket.
+    Lcu,
+    /// Made up for smoke tests and self-checks.
+    Synthetic,
+    /// Read back from a recording.
+    Replay,
+}
+
+/// Top-level event emitted by the daemon.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct Event {
+    /// [`SCHEMA_VERSION`] the event was written with; events recorded before the field existed
+    /// read as version 1. `levents_model::migrate` upgrades older ones.
+    #[cfg_attr(feature = "serde", serde(default = "aliases::first_schema_version"))]
+    pub schema_version: u32,
+    /// Globally unique id, assigned when the event is published. Nil until then.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub id: Uuid,
+    /// Position in the publishing bus's sequence, assigned with the id. Zero until then.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub seq: u64,
+    /// Retired names from [`KIND_ALIASES`] are accepted when deserializing.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_kind"))]
+    pub kind: EventKind,
+    pub ts: TimestampMs,
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub payload: EventPayload,
+    /// Source that produced the event; [`EventSource::Daemon`] unless the source set it.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub source: EventSource,
+    /// Game the event belongs to, assigned when the event is published. A new id is drawn for
+    /// every game the daemon detects; `None` before the first one.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub game_id: Option<Uuid>,
+    /// Issued by an operator to correct the stream rather than observed in the game.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub manual: bool,
+    /// Delivered well after it was due, e.g. from backfill or to a subscriber that fell
+    /// behind; real-time consumers may skip animating it. Set per subscription on delivery.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub stale: bool,
+}
+
+impl Event {
+    /// Build an event that has not been published yet (its `id` is nil).
+    pub fn new(kind: EventKind, ts: TimestampMs, payload: EventPayload) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            id: Uuid::nil(),
+            seq: 0,
+            kind,
+            ts,
+            payload,
+            source: EventSource::Daemon,
+            game_id: None,
+            manual: false,
+            stale: false,
+        }
+    }
+
+    /// The event, tagged as coming from `source`.
+    pub fn with_source(mut self, source: EventSource) -> Self {
+        self.source = source;
+        self
+    }
+}
+
+/// Accepted event kinds.
+///
+/// New kinds are added regularly, so matches outside this crate need a wildcard arm.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[non_exhaustive]
+pub enum EventKind {
+    Kill,
+    Death,
+    Assist,
+    LevelUp,
+    SkillLevelUp,
+    ItemAdded,
+    ItemRemoved,
+    ItemMoved,
+    ItemStackChanged,
+    GoldDelta,
+    EconomySnapshot,
+    WinProbability,
+    LaneAssignment,
+    BountyUpdated,
+    SoulPoint,
+    SoulTaken,
+    ElderBuff,
+    TeamWiped,
+    FarmRate,
+    TeamInfo,
+    GameEnded,
+    ScoreAdjusted,
+    Respawn,
+    PhaseChange,
+    Heartbeat,
+    Diagnostic,
+    SurrenderVoteStarted,
+    SurrenderVotePassed,
+    SurrenderVoteFailed,
+    Remake,
+    LobbyChat,
+    RunePageLocked,
+    SkinSelected,
+    QueueEntered,
+    QueueTimeUpdate,
+    MatchFound,
+    FriendPresence,
+    GameflowSession,
+    PickTimerWarning,
+    ChampionKill,
+    Multikill,
+    FirstBlood,
+    Shutdown,
+    ObjectiveKill,
+    StructureDestroyed,
+    CsDelta,
+    VisionScoreDelta,
+    ActivePlayerRunes,
+    ActivePlayerResource,
+    GameMetadata,
+    ResourceChanged,
+}
+
+/// Event payload variants.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "payloadKind", content = "data", rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub enum EventPayload {
+    Player(PlayerEvent),
+    PlayerItem(ItemEvent),
+    PlayerLevel(LevelEvent),
+    PlayerSkillLevel(SkillLevelEvent),
+    PlayerGold(GoldEvent),
+    PlayerEconomy(EconomyEvent),
+    WinProbability(WinProbabilityEvent),
+    LaneAssignment(LaneAssignmentEvent),
+    PlayerBounty(BountyEvent),
+    Dragon(DragonEvent),
+    TeamWipe(TeamWipeEvent),
+    PlayerFarm(FarmEvent),
+    TeamInfo(TeamInfoEvent),
+    GameResult(GameResultEvent),
+    ScoreAdjustment(ScoreAdjustmentEvent),
+    Phase(PhaseEvent),
+    Heartbeat(HeartbeatEvent),
+    Diagnostic(DiagnosticEvent),
+    SurrenderVote(SurrenderVoteEvent),
+    LobbyChat(LobbyChatEvent),
+    RunePage(RunePageEvent),
+    SkinSelected(SkinSelectedEvent),
+    Queue(QueueEvent),
+    FriendPresence(FriendPresenceEvent),
+    GameflowSession(GameflowSessionEvent),
+    PickTimerWarning(PickTimerWarningEvent),
+    KillDetail(KillDetailEvent),
+    SpecialKill(SpecialKillEvent),
+    ObjectiveKill(ObjectiveKillEvent),
+    Structure(StructureEvent),
+    PlayerScore(ScoreEvent),
+    ActiveRunes(ActiveRunesEvent),
+    Resource(ResourceEvent),
+    GameMetadata(GameMetadataEvent),
+    ResourceChanged(ResourceChangedEvent),
+    /// Free-form JSON payload; only available with the `serde` feature.
+    #[cfg(feature = "serde")]
+    Custom(BTreeMap<String, serde_json::Value>),
+}
+
+impl EventPayload {
+    /// Every player referenced by the payload, for enrichment passes.
+    pub fn players_mut(&mut self) -> Vec<&mut PlayerRef> {
+        match self {
+            EventPayload::Player(inner) => vec![&mut inner.player],
+            EventPayload::PlayerItem(inner) => vec![&mut inner.player],
+            EventPayload::PlayerLevel(inner) => vec![&mut inner.player],
+            EventPayload::PlayerSkillLevel(inner) => vec![&mut inner.player],
+            EventPayload::PlayerGold(inner) => vec![&mut inner.player],
+            EventPayload::PlayerEconomy(inner) => vec![&mut inner.player],
+            EventPayload::LaneAssignment(inner) => inner.players.iter_mut().collect(),
+            EventPayload::PlayerBounty(inner) => vec![&mut inner.player],
+            EventPayload::PlayerFarm(inner) => vec![&mut inner.player],
+            EventPayload::KillDetail(inner) => inner
+                .killer
+                .iter_mut()
+                .chain([&mut inner.victim])
+                .chain(inner.assisters.iter_mut())
+                .collect(),
+            EventPayload::ObjectiveKill(inner) => inner.killer.iter_mut().collect(),
+            EventPayload::Structure(inner) => inner.killer.iter_mut().collect(),
+            EventPayload::PlayerScore(inner) => vec![&mut inner.player],
+            EventPayload::ActiveRunes(inner) => vec![&mut inner.player],
+            EventPayload::Resource(inner) => vec![&mut inner.player],
+            EventPayload::ResourceChanged(inner) => vec![&mut inner.player],
+            EventPayload::SpecialKill(inner) => core::iter::once(&mut inner.killer)
+                .chain(&mut inner.victim)
+                .collect(),
+            EventPayload::WinProbability(_)
+            | EventPayload::Dragon(_)
+            | EventPayload::TeamWipe(_)
+            | EventPayload::TeamInfo(_)
+            | EventPayload::GameResult(_)
+            | EventPayload::ScoreAdjustment(_)
+            | EventPayload::Phase(_)
+            | EventPayload::Heartbeat(_)
+            | EventPayload::Diagnostic(_)
+            | EventPayload::SurrenderVote(_)
+            | EventPayload::LobbyChat(_)
+            | EventPayload::RunePage(_)
+            | EventPayload::SkinSelected(_)
+            | EventPayload::Queue(_)
+            | EventPayload::FriendPresence(_)
+            | EventPayload::GameflowSession(_)
+            | EventPayload::PickTimerWarning(_)
+            | EventPayload::GameMetadata(_) => Vec::new(),
+            #[cfg(feature = "serde")]
+            EventPayload::Custom(_) => Vec::new(),
+        }
+    }
+
+    /// Every player referenced by the payload.
+    pub fn players(&self) -> Vec<&PlayerRef> {
+        match self {
+            EventPayload::Player(inner) => vec![&inner.player],
+            EventPayload::PlayerItem(inner) => vec![&inner.player],
+            EventPayload::PlayerLevel(inner) => vec![&inner.player],
+            EventPayload::PlayerSkillLevel(inner) => vec![&inner.player],
+            EventPayload::PlayerGold(inner) => vec![&inner.player],
+            EventPayload::PlayerEconomy(inner) => vec![&inner.player],
+            EventPayload::LaneAssignment(inner) => inner.players.iter().collect(),
+            EventPayload::PlayerBounty(inner) => vec![&inner.player],
+            EventPayload::PlayerFarm(inner) => vec![&inner.player],
+            EventPayload::KillDetail(inner) => inner
+                .killer
+                .iter()
+                .chain([&inner.victim])
+                .chain(inner.assisters.iter())
+                .collect(),
+            EventPayload::ObjectiveKill(inner) => inner.killer.iter().collect(),
+            EventPayload::Structure(inner) => inner.killer.iter().collect(),
+            EventPayload::PlayerScore(inner) => vec![&inner.player],
+            EventPayload::ActiveRunes(inner) => vec![&inner.player],
+            EventPayload::Resource(inner) => vec![&inner.player],
+            EventPayload::ResourceChanged(inner) => vec![&inner.player],
+            EventPayload::SpecialKill(inner) => core::iter::once(&inner.killer)
+                .chain(&inner.victim)
+                .collect(),
+            EventPayload::WinProbability(_)
+            | EventPayload::Dragon(_)
+            | EventPayload::TeamWipe(_)
+            | EventPayload::TeamInfo(_)
+            | EventPayload::GameResult(_)
+            | EventPayload::ScoreAdjustment(_)
+            | EventPayload::Phase(_)
+            | EventPayload::Heartbeat(_)
+            | EventPayload::Diagnostic(_)
+            | EventPayload::SurrenderVote(_)
+            | EventPayload::LobbyChat(_)
+            | EventPayload::RunePage(_)
+            | EventPayload::SkinSelected(_)
+            | EventPayload::Queue(_)
+            | EventPayload::FriendPresence(_)
+            | EventPayload::GameflowSession(_)
+            | EventPayload::PickTimerWarning(_)
+            | EventPayload::GameMetadata(_) => Vec::new(),
+            #[cfg(feature = "serde")]
+            EventPayload::Custom(_) => Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PlayerEvent {
+    pub player: PlayerRef,
+    /// Estimated gold awarded for the kill (the victim's bounty); only set on kill events.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub bounty: Option<u32>,
+}
+
+/// A champion kill with everyone involved, where `kill`, `death` and `assist` events each
+/// carry one of them.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct KillDetailEvent {
+    /// Unset when the client names no killer; turrets and minions resolve to a neutral player.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub killer: Option<PlayerRef>,
+    pub victim: PlayerRef,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub assisters: Vec<PlayerRef>,
+    /// Estimated gold awarded for the kill (the victim's bounty).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub bounty: Option<u32>,
+}
+
+/// What made a kill special.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum KillType {
+    Double,
+    Triple,
+    Quadra,
+    Penta,
+    FirstBlood,
+    Shutdown,
+}
+
+/// A multikill, first blood or shutdown, reported on top of the kill itself.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SpecialKillEvent {
+    pub killer: PlayerRef,
+    /// Unset for multikills, which span several victims.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub victim: Option<PlayerRef>,
+    pub kill_type: KillType,
+    /// Kills in the streak: 2 to 5 for multikills and 1 for first blood; for shutdowns, the
+    /// streak the victim lost when the client reports it, 0 otherwise.
+    pub streak: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ItemEvent {
+    pub player: PlayerRef,
+    pub item_id: u32,
+    pub item_name: Option<String>,
+    /// Inventory slot the item occupies, as reported by the playerlist.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub slot: Option<u8>,
+    /// Stack size in that slot; consumables and wards stack instead of taking new slots. One
+    /// on every event when the daemon sends item events per unit.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub count: Option<u32>,
+    /// Slot the item occupied before an [`EventKind::ItemMoved`] event.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub previous_slot: Option<u8>,
+    /// Gold price of one unit as reported by the playerlist; times `count`, the gold the stack
+    /// is worth. `None` for free items and clients that do not report prices.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub price: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct LevelEvent {
+    pub player: PlayerRef,
+    pub level: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum AbilitySlot {
+    Q,
+    W,
+    E,
+    R,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SkillLevelEvent {
+    pub player: PlayerRef,
+    pub ability: AbilitySlot,
+    pub level: u8,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GoldEvent {
+    pub player: PlayerRef,
+    pub delta: i32,
+    pub total: i32,
+}
+
+/// Growth of a player's creep score (`CsDelta`) or vision score (`VisionScoreDelta`) since it
+/// was last reported. Creep scores are always whole numbers.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ScoreEvent {
+    pub player: PlayerRef,
+    pub delta: f32,
+    pub total: f32,
+}
+
+/// Cumulative gold accounting for one player.
+///
+/// The Live Client only reports current gold, so `earned` is derived from current gold deltas
+/// plus the value of items bought in between. Sell refunds therefore count as earned gold.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct EconomyEvent {
+    pub player: PlayerRef,
+    pub earned: u32,
+    pub spent: u32,
+    pub current: i32,
+}
+
+/// Estimated chance of each team winning; the two values sum to 1.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct WinProbabilityEvent {
+    pub order: f32,
+    pub chaos: f32,
+}
+
+/// Lanes inferred for every player, carried on each [`PlayerRef::lane`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct LaneAssignmentEvent {
+    pub players: Vec<PlayerRef>,
+}
+
+/// Estimated gold a player is worth when killed, derived from their kill and death streaks.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BountyEvent {
+    pub player: PlayerRef,
+    pub bounty: u32,
+    pub kill_streak: u32,
+    pub death_streak: u32,
+}
+
+/// Dragon soul progress or an elder buff for one team.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct DragonEvent {
+    pub team: Team,
+    /// Dragon type as reported by the client (`Fire`, `Hextech`, `Elder`, ...).
+    pub dragon_type: String,
+    /// Elemental dragons the team has taken so far.
+    pub soul_points: u32,
+    /// Soul being contested, known once the rift has transformed.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub soul_type: Option<String>,
+    /// Game time (ms) at which the elder buff expires; set on elder buff events only.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub expires_at: Option<TimestampMs>,
+}
+
+/// Epic monster taken by a team.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Objective {
+    Dragon,
+    Herald,
+    Baron,
+    /// Void grubs, reported once per grub.
+    Grubs,
+}
+
+/// An epic monster kill.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ObjectiveKillEvent {
+    pub objective: Objective,
+    /// Dragon type as reported by the client (`Fire`, `Hextech`, `Elder`, ...); dragons only.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub dragon_type: Option<String>,
+    /// Taken by the team that did not fight for it, as reported by the client.
+    pub stolen: bool,
+    /// Likely stolen: reported so by the client, or taken by a team that had fewer players
+    /// than the other in the champion kills just before. A heuristic without positions.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub steal_suspected: bool,
+    /// Team credited; `None` when the last hit was not a known player's.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub team: Option<Team>,
+    /// Player with the last hit, resolved through the playerlist.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub killer: Option<PlayerRef>,
+}
+
+/// Kind of structure destroyed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum StructureType {
+    Turret,
+    Inhibitor,
+}
+
+/// Position of a turret, from the river towards the nexus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum TurretTier {
+    Outer,
+    Inner,
+    /// The turret guarding an inhibitor.
+    Base,
+    Nexus,
+}
+
+/// A turret or inhibitor destroyed.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct StructureEvent {
+    pub structure: StructureType,
+    /// Structure id as reported by the client, e.g. `Turret_T2_L_03_A` or `Barracks_T1_R1`.
+    pub id: String,
+    /// Team the structure belonged to.
+    pub team: Team,
+    /// Lane the structure stands in; `None` for nexus turrets.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub lane: Option<Lane>,
+    /// Turrets only; `None` for turrets outside the Summoner's Rift layout.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub tier: Option<TurretTier>,
+    /// Player credited, when a champion took the last hit.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub killer: Option<PlayerRef>,
+}
+
+/// Every member of `team` was dead at once (an Ace for the opposing team).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TeamWipeEvent {
+    pub team: Team,
+    /// Estimated time until the first member respawns, measured from the ace.
+    pub duration_estimate_ms: u64,
+}
+
+/// Farming rate sampled once per game minute.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct FarmEvent {
+    pub player: PlayerRef,
+    pub creep_score: u32,
+    /// Average over the whole game so far.
+    pub cs_per_minute: f32,
+    /// Creep score gained since the previous sample (normally one minute).
+    pub cs_last_minute: u32,
+    /// Recent farm relative to an efficient full clear; only set for junglers.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub jungle_efficiency: Option<f32>,
+}
+
+/// Operator-configured branding for both sides, emitted once per game session.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TeamInfoEvent {
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub order: Option<TeamMetadata>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub chaos: Option<TeamMetadata>,
+    /// Best-of series context, when the operator has configured one.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub series: Option<SeriesInfo>,
+}
+
+/// Position of the current game within a best-of series, as seen from the current sides.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SeriesInfo {
+    pub best_of: u32,
+    /// 1-based number of the current game.
+    pub game_number: u32,
+    /// Series wins of the team currently playing on the order side.
+    pub order_wins: u32,
+    /// Series wins of the team currently playing on the chaos side.
+    pub chaos_wins: u32,
+    /// Whether the teams switched sides compared with the previous game.
+    pub sides_swapped: bool,
+}
+
+/// The game finished.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GameResultEvent {
+    /// Winning side; unknown when the local player could not be resolved (e.g. spectating).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub winner: Option<Team>,
+    /// Series context after the result was counted.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub series: Option<SeriesInfo>,
+}
+
+/// Operator correction to a team's kill score, e.g. for a kill the Live Client feed missed.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ScoreAdjustmentEvent {
+    pub team: Team,
+    /// Kills to add (or, when negative, remove) from the team's score.
+    pub kills: i32,
+}
+
+/// Something went wrong inside the daemon that consumers may want to surface, e.g. a source
+/// that stalled and was restarted.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct DiagnosticEvent {
+    /// Component the diagnostic is about, e.g. `liveClient` or `lcu`.
+    pub source: String,
+    pub message: String,
+}
+
+/// A surrender vote, or the remake an early one led to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SurrenderVoteEvent {
+    /// Team voting; `None` when the client did not say.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub team: Option<Team>,
+    /// Early-surrender vote, which remakes the game when it passes.
+    pub remake: bool,
+    pub votes_for: u32,
+    pub votes_against: u32,
+}
+
+/// A chat message sent in a lobby or champ select.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct LobbyChatEvent {
+    /// `lobby` or `championSelect`.
+    pub channel: String,
+    /// Chat id of the sender as reported by the client.
+    pub sender: String,
+    pub body: String,
+}
+
+/// The rune page the local player took into the game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct RunePageEvent {
+    pub name: String,
+    /// Style id of the keystone tree, e.g. 8000 for Precision.
+    pub primary_style_id: u32,
+    pub sub_style_id: u32,
+    /// Selected perks in client order: keystone first, stat shards last.
+    pub perk_ids: Vec<u32>,
+}
+
+/// Runes of the active player, as the Live Client reports them in game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ActiveRunesEvent {
+    pub player: PlayerRef,
+    /// Style id of the keystone tree, e.g. 8000 for Precision.
+    pub primary_style_id: u32,
+    pub sub_style_id: u32,
+    /// Selected perks: keystone first, stat shards last.
+    pub perk_ids: Vec<u32>,
+}
+
+/// Resource bar of the active player's champion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ResourceEvent {
+    pub player: PlayerRef,
+    /// As reported by the client, e.g. `MANA`, `ENERGY`, `FURY` or `NONE`.
+    pub resource: String,
+}
+
+/// Health and resource bar of a champion, sent when either moved past the configured
+/// thresholds since it was last reported.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ResourceChangedEvent {
+    pub player: PlayerRef,
+    pub health: f32,
+    pub max_health: f32,
+    /// Mana, energy or fury; zero for champions without a resource bar.
+    pub resource: f32,
+    pub max_resource: f32,
+    /// Whether health is below the low-health threshold. Set when health drops under it and
+    /// only cleared once it recovers past the clear threshold, so it does not flicker.
+    pub low_health: bool,
+}
+
+/// Mode and map of the running game, from the Live Client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GameMetadataEvent {
+    /// As reported by the client, e.g. `CLASSIC`, `ARAM` or `CHERRY` (Arena).
+    pub game_mode: String,
+    /// 11 for Summoner's Rift, 12 for the Howling Abyss, 30 for Arena.
+    pub map_number: u32,
+    /// As reported by the client, e.g. `Map11`.
+    pub map_name: String,
+    /// Terrain of the transformed rift, e.g. `Infernal`; `None` until the rift transforms.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub map_terrain: Option<String>,
+    /// Game clock when the metadata was read.
+    pub game_time_ms: u64,
+}
+
+/// A skin picked by a teammate in champ select.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SkinSelectedEvent {
+    /// Champ select cell of the player.
+    pub cell_id: u32,
+    /// `name#tag` of the player, when the client reports it.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub riot_id: Option<String>,
+    pub champion_id: u32,
+    /// Skin id, or the chroma id when a chroma is picked; both are `champion_id * 1000 + n`.
+    pub skin_id: u32,
+}
+
+/// Matchmaking progress of the local player.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct QueueEvent {
+    /// Whole seconds spent in queue so far.
+    pub time_in_queue_secs: u32,
+    /// The client's estimate of the total queue time, in whole seconds.
+    pub estimated_queue_secs: u32,
+}
+
+/// A friend's availability and current game, without their status message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct FriendPresenceEvent {
+    /// `name#tag` of the friend.
+    pub riot_id: String,
+    pub puuid: String,
+    /// `chat`, `away`, `dnd`, `mobile` or `offline`.
+    pub availability: String,
+    /// e.g. `inGame`, `championSelect`, `inQueue` or `outOfGame`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub game_status: Option<String>,
+    /// Queue of the current game, e.g. `RANKED_SOLO_5x5`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub queue_type: Option<String>,
+    /// Champion being played, once picked.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub champion_id: Option<u32>,
+}
+
+/// The client's gameflow session at a phase change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GameflowSessionEvent {
+    pub phase: String,
+    /// Id of the game, once matchmaking has placed the player in one.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub game_id: Option<u64>,
+    /// e.g. 420 for ranked solo/duo.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub queue_id: Option<u32>,
+    /// e.g. `RANKED_SOLO_5x5`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub queue_type: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub map_id: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub map_name: Option<String>,
+    /// Players of the game, as far as the client knows them yet.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub members: Vec<SessionMember>,
+}
+
+/// One player listed in a gameflow session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SessionMember {
+    pub puuid: String,
+    /// `name#tag`, when the client reports it.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub riot_id: Option<String>,
+    pub team: Team,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub champion_id: Option<u32>,
+}
+
+/// The local player's champ select turn is about to time out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PickTimerWarningEvent {
+    /// `pick` or `ban`.
+    pub action: String,
+    pub seconds_left: u32,
+    /// Champion hovered so far, if any.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub champion_id: Option<u32>,
+}
+
+/// Display metadata for one team.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TeamMetadata {
+    pub name: String,
+    /// Short tag, matched against players' roster `team_tag` to follow a team across sides.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub tag: Option<String>,
+    /// CSS-style colour, e.g. `#0a84ff`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub color: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub logo_url: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PhaseEvent {
+    pub phase: String,
+}
+
+/// Periodic liveness signal. The vitals are set by the daemon's heartbeat task so thin
+/// consumers can monitor it without scraping metrics.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct HeartbeatEvent {
+    /// Sequence number of the heartbeat event itself, set when it is published.
+    pub seq: u64,
+    /// Milliseconds since the daemon started.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub uptime_ms: Option<u64>,
+    /// Sources currently connected to the game client (`liveClient`, `lcu`).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub sources_connected: Vec<String>,
+    /// Sources that never run, being turned off in the daemon's configuration or not built in.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub sources_disabled: Vec<String>,
+    /// Duration of the latest Live Client poll.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub last_poll_latency_ms: Option<u64>,
+    /// Events skipped by subscribers that fell behind, since the daemon started.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub dropped_events: Option<u64>,
+    /// Live Client polls that took longer than their interval, since the daemon started; the
+    /// poller skips optional endpoints after each one.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub over_budget_polls: Option<u64>,
+    /// Daemon version and git revision, e.g. `0.1.0+3f2a9c1d0b4e`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub version: Option<String>,
+}
+
+/// Batch of events emitted in a single poll cycle.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct EventBatch {
+    /// [`SCHEMA_VERSION`] the batch was written with; see [`Event::schema_version`].
+    #[cfg_attr(feature = "serde", serde(default = "aliases::first_schema_version"))]
+    pub schema_version: u32,
+    pub events: Vec<Event>,
+}
+
+impl Default for EventBatch {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl EventBatch {
+    /// A batch of `events` in the current schema.
+    pub fn new(events: Vec<Event>) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            events,
+        }
+    }
+
+    /// The batch, with every event tagged as coming from `source`.
+    pub fn with_source(mut self, source: EventSource) -> Self {
+        for event in &mut self.events {
+            event.source = source;
+        }
+        self
+    }
+}
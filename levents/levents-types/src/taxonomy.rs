@@ -0,0 +1,143 @@
+//! Machine-readable overview of every event kind.
+//!
+//! Client bindings in other languages check their kind lists and payload unions against
+//! [`TAXONOMY`] (exported as JSON by `levents-model`'s `event-taxonomy` binary), so filters
+//! and switch statements there stay exhaustive as kinds are added.
+
+use crate::EventKind;
+
+/// Broad area of the game an event kind belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum KindCategory {
+    /// Kills, deaths and fights.
+    Combat,
+    /// Epic monsters, dragon souls and structures.
+    Objective,
+    /// Gold, items and farm.
+    Economy,
+    /// A single player's progression: levels, skills, scores and resources.
+    Player,
+    /// The game as a whole: phases, teams, results and votes.
+    Match,
+    /// League Client activity outside the game: lobby, queue and champ select.
+    Client,
+    /// The daemon's own heartbeats and diagnostics.
+    System,
+}
+
+/// How much an event kind matters to a live broadcast.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum KindPriority {
+    /// Frequent updates that are fine to sample or drop.
+    Low,
+    Normal,
+    /// Moments a broadcast should never miss.
+    High,
+}
+
+/// What every event of one kind looks like.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct KindInfo {
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub kind: EventKind,
+    /// Name in JSON and the bindings.
+    pub name: &'static str,
+    /// `payloadKind` of the payload events of this kind carry.
+    pub payload: &'static str,
+    pub category: KindCategory,
+    pub priority: KindPriority,
+    /// First [`crate::SCHEMA_VERSION`] that has the kind.
+    pub since_schema: u32,
+}
+
+const fn kind(
+    kind: EventKind,
+    name: &'static str,
+    payload: &'static str,
+    category: KindCategory,
+    priority: KindPriority,
+) -> KindInfo {
+    KindInfo {
+        kind,
+        name,
+        payload,
+        category,
+        priority,
+        since_schema: 1,
+    }
+}
+
+use KindCategory::*;
+use KindPriority::*;
+
+/// Every event kind, in declaration order.
+#[rustfmt::skip]
+pub const TAXONOMY: &[KindInfo] = &[
+    kind(EventKind::Kill, "kill", "player", Combat, Normal),
+    kind(EventKind::Death, "death", "player", Combat, Normal),
+    kind(EventKind::Assist, "assist", "player", Combat, Normal),
+    kind(EventKind::LevelUp, "levelUp", "playerLevel", Player, Normal),
+    kind(EventKind::SkillLevelUp, "skillLevelUp", "playerSkillLevel", Player, Normal),
+    kind(EventKind::ItemAdded, "itemAdded", "playerItem", Economy, Normal),
+    kind(EventKind::ItemRemoved, "itemRemoved", "playerItem", Economy, Normal),
+    kind(EventKind::ItemMoved, "itemMoved", "playerItem", Economy, Low),
+    kind(EventKind::ItemStackChanged, "itemStackChanged", "playerItem", Economy, Low),
+    kind(EventKind::GoldDelta, "goldDelta", "playerGold", Economy, Low),
+    kind(EventKind::EconomySnapshot, "economySnapshot", "playerEconomy", Economy, Low),
+    kind(EventKind::WinProbability, "winProbability", "winProbability", Match, Low),
+    kind(EventKind::LaneAssignment, "laneAssignment", "laneAssignment", Player, Normal),
+    kind(EventKind::BountyUpdated, "bountyUpdated", "playerBounty", Combat, Low),
+    kind(EventKind::SoulPoint, "soulPoint", "dragon", Objective, Normal),
+    kind(EventKind::SoulTaken, "soulTaken", "dragon", Objective, High),
+    kind(EventKind::ElderBuff, "elderBuff", "dragon", Objective, High),
+    kind(EventKind::TeamWiped, "teamWiped", "teamWipe", Combat, High),
+    kind(EventKind::FarmRate, "farmRate", "playerFarm", Economy, Low),
+    kind(EventKind::TeamInfo, "teamInfo", "teamInfo", Match, Normal),
+    kind(EventKind::GameEnded, "gameEnded", "gameResult", Match, High),
+    kind(EventKind::ScoreAdjusted, "scoreAdjusted", "scoreAdjustment", Match, High),
+    kind(EventKind::Respawn, "respawn", "player", Combat, Normal),
+    kind(EventKind::PhaseChange, "phaseChange", "phase", Match, Normal),
+    kind(EventKind::Heartbeat, "heartbeat", "heartbeat", System, Low),
+    kind(EventKind::Diagnostic, "diagnostic", "diagnostic", System, Normal),
+    kind(EventKind::SurrenderVoteStarted, "surrenderVoteStarted", "surrenderVote", Match, Normal),
+    kind(EventKind::SurrenderVotePassed, "surrenderVotePassed", "surrenderVote", Match, High),
+    kind(EventKind::SurrenderVoteFailed, "surrenderVoteFailed", "surrenderVote", Match, Normal),
+    kind(EventKind::Remake, "remake", "surrenderVote", Match, High),
+    kind(EventKind::LobbyChat, "lobbyChat", "lobbyChat", Client, Low),
+    kind(EventKind::RunePageLocked, "runePageLocked", "runePage", Client, Normal),
+    kind(EventKind::SkinSelected, "skinSelected", "skinSelected", Client, Normal),
+    kind(EventKind::QueueEntered, "queueEntered", "queue", Client, Normal),
+    kind(EventKind::QueueTimeUpdate, "queueTimeUpdate", "queue", Client, Low),
+    kind(EventKind::MatchFound, "matchFound", "queue", Client, High),
+    kind(EventKind::FriendPresence, "friendPresence", "friendPresence", Client, Low),
+    kind(EventKind::GameflowSession, "gameflowSession", "gameflowSession", Client, Normal),
+    kind(EventKind::PickTimerWarning, "pickTimerWarning", "pickTimerWarning", Client, High),
+    kind(EventKind::ChampionKill, "championKill", "killDetail", Combat, High),
+    kind(EventKind::Multikill, "multikill", "specialKill", Combat, High),
+    kind(EventKind::FirstBlood, "firstBlood", "specialKill", Combat, High),
+    kind(EventKind::Shutdown, "shutdown", "specialKill", Combat, High),
+    kind(EventKind::ObjectiveKill, "objectiveKill", "objectiveKill", Objective, High),
+    kind(EventKind::StructureDestroyed, "structureDestroyed", "structure", Objective, High),
+    kind(EventKind::CsDelta, "csDelta", "playerScore", Player, Low),
+    kind(EventKind::VisionScoreDelta, "visionScoreDelta", "playerScore", Player, Low),
+    kind(EventKind::ActivePlayerRunes, "activePlayerRunes", "activeRunes", Player, Normal),
+    kind(EventKind::ActivePlayerResource, "activePlayerResource", "resource", Player, Normal),
+    kind(EventKind::GameMetadata, "gameMetadata", "gameMetadata", Match, Normal),
+    kind(EventKind::ResourceChanged, "resourceChanged", "resourceChanged", Player, Low),
+];
+
+impl EventKind {
+    /// Name, payload, category and priority of this kind.
+    pub fn info(&self) -> &'static KindInfo {
+        TAXONOMY
+            .iter()
+            .find(|info| info.kind == *self)
+            .expect("every event kind is listed in TAXONOMY")
+    }
+}